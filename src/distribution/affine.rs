@@ -0,0 +1,351 @@
+use distribution::{Continuous, Univariate};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the distribution of the affine transformation `Y = a + bX`
+/// of a univariate continuous random variable `X`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Affine, Continuous, Exponential};
+/// use statrs::statistics::Mean;
+///
+/// let n = Affine::new(Exponential::new(1.0).unwrap(), 1.0, 2.0).unwrap();
+/// assert_eq!(n.mean(), 3.0);
+/// assert_eq!(n.pdf(3.0), 0.5 * (-1f64).exp());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Affine<D> {
+    inner: D,
+    loc: f64,
+    scale: f64,
+}
+
+impl<D> Affine<D> {
+    /// Constructs the distribution of `loc + scale * X` where `X` is
+    /// distributed according to `inner`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `loc` or `scale` are `NaN` or infinite, or if
+    /// `scale == 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Affine, Normal};
+    ///
+    /// let mut result = Affine::new(Normal::new(0.0, 1.0).unwrap(), 1.0, -2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Affine::new(Normal::new(0.0, 1.0).unwrap(), 1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(inner: D, loc: f64, scale: f64) -> Result<Affine<D>> {
+        if !loc.is_finite() || !scale.is_finite() || scale == 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Affine {
+                inner: inner,
+                loc: loc,
+                scale: scale,
+            })
+        }
+    }
+
+    /// Returns the untransformed distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Affine, Normal};
+    ///
+    /// let n = Affine::new(Normal::new(0.0, 1.0).unwrap(), 1.0, 2.0).unwrap();
+    /// assert_eq!(*n.inner(), Normal::new(0.0, 1.0).unwrap());
+    /// ```
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    /// Returns the location `a` of the transformation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Affine, Normal};
+    ///
+    /// let n = Affine::new(Normal::new(0.0, 1.0).unwrap(), 1.0, 2.0).unwrap();
+    /// assert_eq!(n.loc(), 1.0);
+    /// ```
+    pub fn loc(&self) -> f64 {
+        self.loc
+    }
+
+    /// Returns the scale `b` of the transformation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Affine, Normal};
+    ///
+    /// let n = Affine::new(Normal::new(0.0, 1.0).unwrap(), 1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    // maps a value of the transformed variable back to the inner variable
+    fn untransform(&self, y: f64) -> f64 {
+        (y - self.loc) / self.scale
+    }
+}
+
+impl<D> Distribution<f64> for Affine<D>
+where
+    D: Distribution<f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.loc + self.scale * self.inner.sample(r)
+    }
+}
+
+impl<D> Univariate<f64, f64> for Affine<D>
+where
+    D: Univariate<f64, f64>,
+{
+    /// Calculates the cumulative distribution function for the affine
+    /// transformation at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F((x - a) / b)      if b > 0
+    /// 1 - F((x - a) / b)  if b < 0
+    /// ```
+    ///
+    /// where `F` is the cdf of the inner distribution, `a` the location
+    /// and `b` the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if self.scale > 0.0 {
+            self.inner.cdf(self.untransform(x))
+        } else {
+            1.0 - self.inner.cdf(self.untransform(x))
+        }
+    }
+}
+
+impl<D> Min<f64> for Affine<D>
+where
+    D: Min<f64> + Max<f64>,
+{
+    /// Returns the minimum value in the domain of the affine
+    /// transformation representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a + b * min(X)  if b > 0
+    /// a + b * max(X)  if b < 0
+    /// ```
+    fn min(&self) -> f64 {
+        if self.scale > 0.0 {
+            self.loc + self.scale * self.inner.min()
+        } else {
+            self.loc + self.scale * self.inner.max()
+        }
+    }
+}
+
+impl<D> Max<f64> for Affine<D>
+where
+    D: Min<f64> + Max<f64>,
+{
+    /// Returns the maximum value in the domain of the affine
+    /// transformation representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a + b * max(X)  if b > 0
+    /// a + b * min(X)  if b < 0
+    /// ```
+    fn max(&self) -> f64 {
+        if self.scale > 0.0 {
+            self.loc + self.scale * self.inner.max()
+        } else {
+            self.loc + self.scale * self.inner.min()
+        }
+    }
+}
+
+impl<D> Mean<f64> for Affine<D>
+where
+    D: Mean<f64>,
+{
+    /// Returns the mean of the affine transformation
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a + b * E[X]
+    /// ```
+    fn mean(&self) -> f64 {
+        self.loc + self.scale * self.inner.mean()
+    }
+}
+
+impl<D> Variance<f64> for Affine<D>
+where
+    D: Variance<f64>,
+{
+    /// Returns the variance of the affine transformation
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// b^2 * Var[X]
+    /// ```
+    fn variance(&self) -> f64 {
+        self.scale * self.scale * self.inner.variance()
+    }
+
+    /// Returns the standard deviation of the affine transformation
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// |b| * sqrt(Var[X])
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.scale.abs() * self.inner.std_dev()
+    }
+}
+
+impl<D> Dispersion for Affine<D> where D: Variance<f64> {}
+
+impl<D> Median<f64> for Affine<D>
+where
+    D: Median<f64>,
+{
+    /// Returns the median of the affine transformation
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a + b * median(X)
+    /// ```
+    fn median(&self) -> f64 {
+        self.loc + self.scale * self.inner.median()
+    }
+}
+
+impl<D> Continuous<f64, f64> for Affine<D>
+where
+    D: Continuous<f64, f64>,
+{
+    /// Calculates the probability density function for the affine
+    /// transformation at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// f((x - a) / b) / |b|
+    /// ```
+    ///
+    /// where `f` is the pdf of the inner distribution, `a` the location
+    /// and `b` the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.inner.pdf(self.untransform(x)) / self.scale.abs()
+    }
+
+    /// Calculates the log probability density function for the affine
+    /// transformation at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f((x - a) / b)) - ln(|b|)
+    /// ```
+    ///
+    /// where `f` is the pdf of the inner distribution, `a` the location
+    /// and `b` the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.inner.ln_pdf(self.untransform(x)) - self.scale.abs().ln()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{Affine, Continuous, Gamma, Normal, Univariate};
+    use function::integration::adaptive_quadrature_infinite;
+
+    #[test]
+    fn test_create() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(Affine::new(n, 0.0, 1.0).is_ok());
+        assert!(Affine::new(n, -3.0, -0.5).is_ok());
+        assert!(Affine::new(n, 0.0, 0.0).is_err());
+        assert!(Affine::new(n, f64::NAN, 1.0).is_err());
+        assert!(Affine::new(n, 0.0, f64::NAN).is_err());
+        assert!(Affine::new(n, 0.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_scaled_gamma() {
+        let g = Gamma::new(3.0, 1.5).unwrap();
+        let n = Affine::new(g, 0.0, 2.0).unwrap();
+        assert_eq!(n.mean(), 2.0 * g.mean());
+        assert_eq!(n.variance(), 4.0 * g.variance());
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), f64::INFINITY);
+        // scaling the rate of a gamma by 1/2 is the same as doubling it
+        let h = Gamma::new(3.0, 0.75).unwrap();
+        for &x in &[0.1f64, 1.0, 4.0, 10.0] {
+            assert_almost_eq!(n.pdf(x), h.pdf(x), 1e-15);
+            assert_almost_eq!(n.ln_pdf(x), h.ln_pdf(x), 1e-14);
+            assert_almost_eq!(n.cdf(x), h.cdf(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_density_integrates_to_one() {
+        let g = Gamma::new(3.0, 1.5).unwrap();
+        for &(loc, scale) in &[(0.0, 2.0), (-1.0, 0.5), (4.0, -3.0)] {
+            let n = Affine::new(g, loc, scale).unwrap();
+            let total = adaptive_quadrature_infinite(|x| n.pdf(x), n.min(), n.max(), 1e-12);
+            assert_almost_eq!(total, 1.0, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_negative_scale() {
+        let g = Gamma::new(3.0, 1.5).unwrap();
+        let n = Affine::new(g, 1.0, -2.0).unwrap();
+        assert_eq!(n.min(), f64::NEG_INFINITY);
+        assert_eq!(n.max(), 1.0);
+        assert_eq!(n.mean(), 1.0 - 2.0 * g.mean());
+        assert_eq!(n.std_dev(), 2.0 * g.std_dev());
+        assert_eq!(n.cdf(1.0), 1.0);
+        assert_almost_eq!(n.cdf(-3.0), 1.0 - g.cdf(2.0), 1e-15);
+        assert_almost_eq!(n.pdf(-3.0), g.pdf(2.0) / 2.0, 1e-15);
+    }
+
+    #[test]
+    fn test_normal() {
+        let n = Affine::new(Normal::new(1.0, 2.0).unwrap(), -1.0, 3.0).unwrap();
+        let m = Normal::new(2.0, 6.0).unwrap();
+        for &x in &[-10.0f64, 0.0, 2.0, 7.5] {
+            assert_almost_eq!(n.pdf(x), m.pdf(x), 1e-15);
+            assert_almost_eq!(n.cdf(x), m.cdf(x), 1e-15);
+        }
+        assert_eq!(n.median(), 2.0);
+    }
+}
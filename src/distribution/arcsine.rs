@@ -0,0 +1,507 @@
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Parametric, Support, Univariate,
+};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [arcsine](https://en.wikipedia.org/wiki/Arcsine_distribution)
+/// distribution on `[min, max]`, the beta distribution with both shapes
+/// `1/2` scaled to the interval
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Arcsine, Continuous};
+/// use statrs::statistics::Mean;
+///
+/// let n = Arcsine::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mean(), 0.5);
+/// assert_eq!(n.pdf(0.5), 2.0 / std::f64::consts::PI);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Arcsine {
+    min: f64,
+    max: f64,
+}
+
+impl Arcsine {
+    /// Constructs a new arcsine distribution on `[min, max]`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min` or `max` are `NaN` or infinite, or if
+    /// `min >= max`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Arcsine;
+    ///
+    /// let mut result = Arcsine::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Arcsine::new(1.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(min: f64, max: f64) -> Result<Arcsine> {
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Arcsine { min: min, max: max })
+        }
+    }
+}
+
+impl Parametric for Arcsine {
+    /// Returns the `min` and `max` parameters of the arcsine distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("min", self.min), ("max", self.max)]
+    }
+
+    /// Constructs an arcsine distribution from its `min` and `max`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Arcsine> {
+        let p = internal::parameter_values(params, &["min", "max"])?;
+        Arcsine::new(p[0], p[1])
+    }
+}
+
+impl Distribution<f64> for Arcsine {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let s = (f64::consts::FRAC_PI_2 * r.gen::<f64>()).sin();
+        self.min + (self.max - self.min) * s * s
+    }
+}
+
+impl Univariate<f64, f64> for Arcsine {
+    /// Calculates the cumulative distribution function for the arcsine
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / π) * arcsin(sqrt((x - min) / (max - min)))
+    /// ```
+    ///
+    /// which is evaluated by symmetry about the midpoint as
+    /// `1 - (2 / π) * arcsin(sqrt((max - x) / (max - min)))` in the upper
+    /// half of the support
+    fn cdf(&self, x: f64) -> f64 {
+        let width = self.max - self.min;
+        if x <= self.min {
+            0.0
+        } else if x >= self.max {
+            1.0
+        } else if x - self.min <= self.max - x {
+            f64::consts::FRAC_2_PI * ((x - self.min) / width).sqrt().asin()
+        } else {
+            1.0 - f64::consts::FRAC_2_PI * ((self.max - x) / width).sqrt().asin()
+        }
+    }
+}
+
+impl InverseCDF<f64> for Arcsine {
+    /// Calculates the inverse cumulative distribution function for the
+    /// arcsine distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + (max - min) * sin^2(πx / 2)
+    /// ```
+    ///
+    /// which is evaluated as `max - (max - min) * sin^2(π(1 - x) / 2)` for
+    /// `x > 0.5`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Arcsine {
+    /// Calculates the inverse cumulative distribution function for the
+    /// arcsine distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + (max - min) * sin^2(πx / 2)
+    /// ```
+    ///
+    /// which is evaluated as `max - (max - min) * sin^2(π(1 - x) / 2)` for
+    /// `x > 0.5`
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else if x <= 0.5 {
+            let s = (f64::consts::FRAC_PI_2 * x).sin();
+            Ok(self.min + (self.max - self.min) * s * s)
+        } else {
+            // measured from the upper bound to keep the upper tail accurate
+            let c = (f64::consts::FRAC_PI_2 * (1.0 - x)).sin();
+            Ok(self.max - (self.max - self.min) * c * c)
+        }
+    }
+}
+
+impl Min<f64> for Arcsine {
+    /// Returns the minimum value in the domain of the arcsine distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min
+    /// ```
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for Arcsine {
+    /// Returns the maximum value in the domain of the arcsine distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// max
+    /// ```
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Domain for Arcsine {
+    /// Returns the support of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [min, max]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.min,
+            hi: self.max,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
+impl Mean<f64> for Arcsine {
+    /// Returns the mean of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (min + max) / 2
+    /// ```
+    fn mean(&self) -> f64 {
+        (self.min + self.max) / 2.0
+    }
+}
+
+impl Variance<f64> for Arcsine {
+    /// Returns the variance of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - min)^2 / 8
+    /// ```
+    fn variance(&self) -> f64 {
+        let width = self.max - self.min;
+        width * width / 8.0
+    }
+
+    /// Returns the standard deviation of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - min) / sqrt(8)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for Arcsine {}
+
+impl Entropy<f64> for Arcsine {
+    /// Returns the entropy of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(π * (max - min) / 4)
+    /// ```
+    fn entropy(&self) -> f64 {
+        (f64::consts::FRAC_PI_4 * (self.max - self.min)).ln()
+    }
+}
+
+impl Skewness<f64> for Arcsine {
+    /// Returns the skewness of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Median<f64> for Arcsine {
+    /// Returns the median of the arcsine distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (min + max) / 2
+    /// ```
+    fn median(&self) -> f64 {
+        (self.min + self.max) / 2.0
+    }
+}
+
+impl Mode<f64> for Arcsine {
+    /// Returns the smaller of the two modes of the arcsine distribution,
+    /// where the density is unbounded
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min
+    /// ```
+    fn mode(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Modes<f64> for Arcsine {
+    /// Returns both modes of the arcsine distribution, its endpoints
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [min, max]
+    /// ```
+    fn modes(&self) -> Vec<f64> {
+        vec![self.min, self.max]
+    }
+}
+
+impl Continuous<f64, f64> for Arcsine {
+    /// Calculates the probability density function for the arcsine
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is not in `[min, max]` and `INF` at the
+    /// endpoints, where the density is unbounded
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (π * sqrt((x - min) * (max - x)))
+    /// ```
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            0.0
+        } else {
+            1.0 / (f64::consts::PI * ((x - self.min) * (self.max - x)).sqrt())
+        }
+    }
+
+    /// Calculates the log probability density function for the arcsine
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NEG_INFINITY` if `x` is not in `[min, max]` and `INF`
+    /// at the endpoints
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(π) - ln((x - min) * (max - x)) / 2
+    /// ```
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            f64::NEG_INFINITY
+        } else {
+            -f64::consts::PI.ln() - 0.5 * ((x - self.min) * (self.max - x)).ln()
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{Arcsine, Beta, CheckedInverseCDF, Continuous, InverseCDF, Parametric, Univariate};
+
+    fn try_create(min: f64, max: f64) -> Arcsine {
+        let n = Arcsine::new(min, max);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_case<F>(min: f64, max: f64, expected: f64, eval: F)
+        where F: Fn(Arcsine) -> f64
+    {
+        let x = eval(try_create(min, max));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(min: f64, max: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Arcsine) -> f64
+    {
+        let x = eval(try_create(min, max));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(0.0, 1.0);
+        try_create(-3.0, 5.0);
+        assert!(Arcsine::new(1.0, 1.0).is_err());
+        assert!(Arcsine::new(2.0, 1.0).is_err());
+        assert!(Arcsine::new(f64::NAN, 1.0).is_err());
+        assert!(Arcsine::new(0.0, f64::NAN).is_err());
+        assert!(Arcsine::new(0.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_case(0.0, 1.0, 0.5, |x| x.mean());
+        test_case(-3.0, 5.0, 1.0, |x| x.mean());
+        test_case(0.0, 1.0, 0.125, |x| x.variance());
+        test_case(-3.0, 5.0, 8.0, |x| x.variance());
+        test_almost(-3.0, 5.0, 8.0f64.sqrt(), 1e-15, |x| x.std_dev());
+        test_case(-3.0, 5.0, 0.0, |x| x.skewness());
+        test_case(-3.0, 5.0, 1.0, |x| x.median());
+        test_almost(0.0, 1.0, -0.24156447527049044, 1e-15, |x| x.entropy());
+        test_almost(-3.0, 5.0, 1.8378770664093456, 1e-15, |x| x.entropy());
+    }
+
+    #[test]
+    fn test_modes() {
+        test_case(-3.0, 5.0, -3.0, |x| x.mode());
+        assert_eq!(try_create(-3.0, 5.0).modes(), vec![-3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(-3.0, 5.0, -3.0, |x| x.min());
+        test_case(-3.0, 5.0, 5.0, |x| x.max());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(0.0, 1.0, f64::consts::FRAC_2_PI, 1e-15, |x| x.pdf(0.5));
+        test_almost(0.0, 1.0, 0.7957747154594766, 1e-15, |x| x.pdf(0.2));
+        test_almost(0.0, 1.0, 0.7957747154594766, 1e-15, |x| x.pdf(0.8));
+        test_almost(-3.0, 5.0, 0.08218725920819997, 1e-16, |x| x.pdf(0.0));
+        test_case(0.0, 1.0, 0.0, |x| x.pdf(-0.1));
+        test_case(0.0, 1.0, 0.0, |x| x.pdf(1.1));
+        // the density is U-shaped and unbounded at the endpoints
+        test_case(0.0, 1.0, f64::INFINITY, |x| x.pdf(0.0));
+        test_case(0.0, 1.0, f64::INFINITY, |x| x.pdf(1.0));
+        let n = try_create(0.0, 1.0);
+        assert!(n.pdf(1e-3) > n.pdf(0.1));
+        assert!(n.pdf(0.1) > n.pdf(0.5));
+        assert!(n.pdf(0.5) < n.pdf(0.9));
+        assert!(n.pdf(0.9) < n.pdf(1.0 - 1e-3));
+        assert_almost_eq!(n.pdf(1e-10), 31830.988619970616, 1e-8);
+        // the beta distribution with both shapes 1/2
+        let b = Beta::new(0.5, 0.5).unwrap();
+        for &x in &[0.01f64, 0.3, 0.5, 0.77] {
+            assert_almost_eq!(n.pdf(x), b.pdf(x), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(0.0, 1.0, -0.4515827052894549, 1e-15, |x| x.ln_pdf(0.5));
+        test_almost(-3.0, 5.0, -2.498754986400505, 1e-15, |x| x.ln_pdf(0.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, |x| x.ln_pdf(-0.1));
+        test_case(0.0, 1.0, f64::INFINITY, |x| x.ln_pdf(0.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_case(0.0, 1.0, 0.0, |x| x.cdf(0.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, |x| x.cdf(0.5));
+        test_almost(0.0, 1.0, 0.2951672353008665, 1e-15, |x| x.cdf(0.2));
+        test_almost(-3.0, 5.0, 0.3333333333333333, 1e-15, |x| x.cdf(-1.0));
+        test_case(0.0, 1.0, 0.0, |x| x.cdf(-1.0));
+        test_case(0.0, 1.0, 1.0, |x| x.cdf(1.0));
+        test_case(0.0, 1.0, 1.0, |x| x.cdf(2.0));
+    }
+
+    #[test]
+    fn test_cdf_symmetry() {
+        let n = try_create(-3.0, 5.0);
+        for &d in &[0.0f64, 0.01, 0.5, 2.0, 3.9, 4.0] {
+            assert_almost_eq!(n.cdf(1.0 - d) + n.cdf(1.0 + d), 1.0, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(0.0, 1.0);
+        for &p in &[1e-6f64, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+        }
+        let n = try_create(-3.0, 5.0);
+        for &p in &[0.01f64, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+        }
+        assert_eq!(n.inverse_cdf(0.0), -3.0);
+        assert_eq!(n.inverse_cdf(1.0), 5.0);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.0, 1e-15);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_parameters() {
+        let n = try_create(-3.0, 5.0);
+        assert_eq!(n.parameters(), vec![("min", -3.0), ("max", 5.0)]);
+        assert_eq!(Arcsine::from_parameters(&n.parameters()).unwrap(), n);
+        assert!(Arcsine::from_parameters(&[("min", 1.0), ("max", 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_pdf_is_cdf_derivative() {
+        // the density is unbounded at the endpoints, so compare it to the
+        // derivative of the cdf in the interior instead of integrating it
+        let n = try_create(-3.0, 5.0);
+        let h = 1e-6;
+        for &x in &[-2.9f64, -1.0, 0.0, 1.0, 2.5, 4.99] {
+            let derivative = (n.cdf(x + h) - n.cdf(x - h)) / (2.0 * h);
+            assert_almost_eq!(derivative, n.pdf(x), 1e-7 * n.pdf(x));
+        }
+        assert_eq!(n.pdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(n.pdf(f64::INFINITY), 0.0);
+        assert_eq!(n.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+    }
+}
@@ -0,0 +1,393 @@
+use distribution::{internal, Continuous, Domain, Parametric, Support, Univariate};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [Bates](https://en.wikipedia.org/wiki/Bates_distribution)
+/// distribution, the distribution of the mean of `n` independent uniform
+/// random variables on `[0, 1]`, i.e. the Irwin-Hall distribution scaled by
+/// `1 / n`
+///
+/// # Remarks
+///
+/// The density and distribution function are those of `IrwinHall` scaled
+/// by `1 / n`, and so share its exact evaluation for `n <= 500` and its
+/// Edgeworth approximation beyond.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Bates, Continuous};
+/// use statrs::statistics::Variance;
+///
+/// let n = Bates::new(3).unwrap();
+/// assert_eq!(n.variance(), 1.0 / 36.0);
+/// assert_eq!(n.pdf(0.5), 2.25);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bates {
+    n: u64,
+}
+
+impl Bates {
+    /// Constructs a new Bates distribution of the mean of `n` uniform
+    /// random variables
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n == 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bates;
+    ///
+    /// let mut result = Bates::new(3);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Bates::new(0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(n: u64) -> Result<Bates> {
+        if n == 0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Bates { n: n })
+        }
+    }
+
+    /// Returns the number of uniform random variables averaged by the Bates
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Bates;
+    ///
+    /// let n = Bates::new(3).unwrap();
+    /// assert_eq!(n.n(), 3);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl Parametric for Bates {
+    /// Returns the `n` parameter of the Bates distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("n", self.n as f64)]
+    }
+
+    /// Constructs a Bates distribution from its `n` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Bates> {
+        let p = internal::parameter_values(params, &["n"])?;
+        Bates::new(internal::u64_parameter(p[0])?)
+    }
+}
+
+impl Distribution<f64> for Bates {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let sum: f64 = (0..self.n).map(|_| r.gen::<f64>()).sum();
+        sum / self.n as f64
+    }
+}
+
+impl Univariate<f64, f64> for Bates {
+    /// Calculates the cumulative distribution function for the Bates
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / n!) * Σ_(k = 0)^(floor(nx)) (-1)^k * (n choose k) * (nx - k)^n
+    /// ```
+    fn cdf(&self, x: f64) -> f64 {
+        super::irwin_hall::cdf_unchecked(self.n, self.n as f64 * x)
+    }
+}
+
+impl Min<f64> for Bates {
+    /// Returns the minimum value in the domain of the Bates distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Bates {
+    /// Returns the maximum value in the domain of the Bates distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn max(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Domain for Bates {
+    /// Returns the support of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, 1]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: 1.0,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
+impl Mean<f64> for Bates {
+    /// Returns the mean of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2
+    /// ```
+    fn mean(&self) -> f64 {
+        0.5
+    }
+}
+
+impl Variance<f64> for Bates {
+    /// Returns the variance of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (12n)
+    /// ```
+    fn variance(&self) -> f64 {
+        1.0 / (12.0 * self.n as f64)
+    }
+
+    /// Returns the standard deviation of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(1 / (12n))
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for Bates {}
+
+impl Skewness<f64> for Bates {
+    /// Returns the skewness of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Median<f64> for Bates {
+    /// Returns the median of the Bates distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2
+    /// ```
+    fn median(&self) -> f64 {
+        0.5
+    }
+}
+
+impl Mode<f64> for Bates {
+    /// Returns the mode of the Bates distribution
+    ///
+    /// # Remarks
+    ///
+    /// For `n == 1` every point of `[0, 1]` is a mode and the middle one is
+    /// returned
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2
+    /// ```
+    fn mode(&self) -> f64 {
+        0.5
+    }
+}
+
+impl Continuous<f64, f64> for Bates {
+    /// Calculates the probability density function for the Bates
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (n / (n - 1)!) * Σ_(k = 0)^(floor(nx)) (-1)^k * (n choose k) * (nx - k)^(n - 1)
+    /// ```
+    fn pdf(&self, x: f64) -> f64 {
+        let n = self.n as f64;
+        n * super::irwin_hall::pdf_unchecked(self.n, n * x)
+    }
+
+    /// Calculates the log probability density function for the Bates
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((n / (n - 1)!) * Σ_(k = 0)^(floor(nx)) (-1)^k * (n choose k) * (nx - k)^(n - 1))
+    /// ```
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Bates, Continuous, Parametric, Uniform, Univariate};
+    use distribution::internal::*;
+    use rng;
+
+    fn try_create(n: u64) -> Bates {
+        let d = Bates::new(n);
+        assert!(d.is_ok());
+        d.unwrap()
+    }
+
+    fn test_case<F>(n: u64, expected: f64, eval: F)
+        where F: Fn(Bates) -> f64
+    {
+        let x = eval(try_create(n));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(n: u64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Bates) -> f64
+    {
+        let x = eval(try_create(n));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(1).n(), 1);
+        assert_eq!(try_create(10).n(), 10);
+        assert!(Bates::new(0).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_case(1, 0.5, |x| x.mean());
+        test_case(5, 0.5, |x| x.median());
+        test_case(5, 0.5, |x| x.mode());
+        test_case(5, 0.0, |x| x.skewness());
+        test_case(3, 1.0 / 36.0, |x| x.variance());
+        test_almost(3, 1.0 / 6.0, 1e-16, |x| x.std_dev());
+    }
+
+    #[test]
+    fn test_variance_shrinks() {
+        let mut r = rng::seeded(0);
+        for &n in &[1u64, 2, 5, 20] {
+            let d = try_create(n);
+            assert_almost_eq!(d.variance() * 12.0 * n as f64, 1.0, 1e-15);
+            let samples: Vec<f64> = (0..20000).map(|_| d.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x <= 1.0));
+            // the sample variance has a relative standard error of about 1%
+            assert_almost_eq!(samples.iter().variance() / d.variance(), 1.0, 0.05);
+            assert_almost_eq!(samples.iter().mean(), 0.5, 0.01);
+        }
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(4, 0.0, |x| x.min());
+        test_case(4, 1.0, |x| x.max());
+    }
+
+    #[test]
+    fn test_uniform() {
+        let d = try_create(1);
+        let u = Uniform::new(0.0, 1.0).unwrap();
+        for &x in &[-0.5f64, 0.0, 0.1, 0.5, 0.75, 1.0, 1.5] {
+            assert_eq!(d.pdf(x), u.pdf(x));
+            assert_almost_eq!(d.cdf(x), u.cdf(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        // a tent on [0, 1] for n = 2
+        test_case(2, 0.0, |x| x.pdf(0.0));
+        test_case(2, 1.0, |x| x.pdf(0.25));
+        test_case(2, 2.0, |x| x.pdf(0.5));
+        test_case(2, 1.0, |x| x.pdf(0.75));
+        test_case(3, 2.25, |x| x.pdf(0.5));
+        test_almost(3, 0.135, 1e-15, |x| x.pdf(0.1));
+        test_almost(3, 0.135, 1e-15, |x| x.pdf(0.9));
+        test_almost(10, 2.431492504409171, 1e-13, |x| x.pdf(0.4));
+        test_almost(100, 13.799020407550003, 1e-12, |x| x.pdf(0.5));
+        test_case(3, 0.0, |x| x.pdf(-0.1));
+        test_case(3, 0.0, |x| x.pdf(1.1));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(3, 2.25f64.ln(), 1e-15, |x| x.ln_pdf(0.5));
+        test_case(3, f64::NEG_INFINITY, |x| x.ln_pdf(-0.1));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_case(3, 0.0, |x| x.cdf(0.0));
+        test_almost(3, 1.0 / 6.0, 1e-15, |x| x.cdf(1.0 / 3.0));
+        test_almost(3, 0.5, 1e-15, |x| x.cdf(0.5));
+        test_almost(3, 5.0 / 6.0, 1e-15, |x| x.cdf(2.0 / 3.0));
+        test_almost(2, 0.125, 1e-15, |x| x.cdf(0.25));
+        test_almost(10, 0.1389015652557319, 1e-13, |x| x.cdf(0.4));
+        test_case(3, 1.0, |x| x.cdf(1.0));
+        test_case(3, 1.0, |x| x.cdf(2.0));
+    }
+
+    #[test]
+    fn test_parameters() {
+        let d = try_create(7);
+        assert_eq!(d.parameters(), vec![("n", 7.0)]);
+        assert_eq!(Bates::from_parameters(&d.parameters()).unwrap(), d);
+        assert!(Bates::from_parameters(&[("n", 0.0)]).is_err());
+        assert!(Bates::from_parameters(&[("n", 1.5)]).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1), 0.0, 1.0);
+        test::check_continuous_distribution(&try_create(3), 0.0, 1.0);
+        test::check_continuous_distribution(&try_create(12), 0.0, 1.0);
+    }
+}
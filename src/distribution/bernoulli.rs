@@ -1,4 +1,4 @@
-use distribution::{Binomial, Discrete, Univariate};
+use distribution::{internal, Binomial, Discrete, Domain, Parametric, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -81,6 +81,19 @@ impl Bernoulli {
     }
 }
 
+impl Parametric for Bernoulli {
+    /// Returns the `p` parameter of the bernoulli distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("p", self.p())]
+    }
+
+    /// Constructs a bernoulli distribution from its `p` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Bernoulli> {
+        let p = internal::parameter_values(params, &["p"])?;
+        Bernoulli::new(p[0])
+    }
+}
+
 impl Distribution<f64> for Bernoulli {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         r.gen_bool(self.p()) as u8 as f64
@@ -133,6 +146,19 @@ impl Max<u64> for Bernoulli {
     }
 }
 
+impl Domain for Bernoulli {
+    /// Returns the support of the bernoulli distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {0, 1}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange { lo: 0.0, hi: 1.0 }
+    }
+}
+
 impl Mean<f64> for Bernoulli {
     /// Returns the mean of the bernoulli
     /// distribution
@@ -173,6 +199,8 @@ impl Variance<f64> for Bernoulli {
     }
 }
 
+impl Dispersion for Bernoulli {}
+
 impl Entropy<f64> for Bernoulli {
     /// Returns the entropy of the bernoulli
     /// distribution
@@ -225,7 +253,7 @@ impl Mode<u64> for Bernoulli {
     /// # Formula
     ///
     /// ```ignore
-    /// if p < 0.5 { 0 }
+    /// if p <= 0.5 { 0 }
     /// else { 1 }
     /// ```
     fn mode(&self) -> u64 {
@@ -233,6 +261,21 @@ impl Mode<u64> for Bernoulli {
     }
 }
 
+impl Modes<u64> for Bernoulli {
+    /// Returns the modes of the bernoulli distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if p < 0.5 { [0] }
+    /// else if p > 0.5 { [1] }
+    /// else { [0, 1] }
+    /// ```
+    fn modes(&self) -> Vec<u64> {
+        self.b.modes()
+    }
+}
+
 impl Discrete<u64, f64> for Bernoulli {
     /// Calculates the probability mass function for the
     /// bernoulli distribution at `x`.
@@ -1,5 +1,8 @@
-use distribution::{Continuous, Univariate};
-use function::{beta, gamma};
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Moments, Parametric, Support,
+    Univariate,
+};
+use function::{beta, gamma, optimization};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -87,6 +90,20 @@ impl Beta {
     }
 }
 
+impl Parametric for Beta {
+    /// Returns the `shape_a` and `shape_b` parameters of the beta distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape_a", self.shape_a), ("shape_b", self.shape_b)]
+    }
+
+    /// Constructs a beta distribution from its `shape_a` and `shape_b`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Beta> {
+        let p = internal::parameter_values(params, &["shape_a", "shape_b"])?;
+        Beta::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for Beta {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         // Generated by sampling two gamma distributions and normalizing.
@@ -166,6 +183,24 @@ impl Max<f64> for Beta {
     }
 }
 
+impl Domain for Beta {
+    /// Returns the support of the beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, 1]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: 1.0,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
 impl Mean<f64> for Beta {
     /// Returns the mean of the beta distribution
     ///
@@ -230,6 +265,8 @@ impl Variance<f64> for Beta {
     }
 }
 
+impl Dispersion for Beta {}
+
 impl Entropy<f64> for Beta {
     /// Returns the entropy of the beta distribution
     ///
@@ -346,6 +383,12 @@ impl Continuous<f64, f64> for Beta {
     /// Calculates the probability density function for the beta distribution
     /// at `x`.
     ///
+    /// # Remarks
+    ///
+    /// The density is computed as the exponential of `ln_pdf`, so the
+    /// normalizing constant is never formed from the gamma functions
+    /// themselves, which overflow for shapes above about `171`
+    ///
     /// # Formula
     ///
     /// ```ignore
@@ -358,32 +401,10 @@ impl Continuous<f64, f64> for Beta {
     fn pdf(&self, x: f64) -> f64 {
         if x < 0.0 || x > 1.0 {
             0.0
-        } else if self.shape_a == f64::INFINITY && self.shape_b == f64::INFINITY {
-            if x == 0.5 {
-                f64::INFINITY
-            } else {
-                0.0
-            }
-        } else if self.shape_a == f64::INFINITY {
-            if x == 1.0 {
-                f64::INFINITY
-            } else {
-                0.0
-            }
-        } else if self.shape_b == f64::INFINITY {
-            if x == 0.0 {
-                f64::INFINITY
-            } else {
-                0.0
-            }
         } else if self.shape_a == 1.0 && self.shape_b == 1.0 {
             1.0
-        } else if self.shape_a > 80.0 || self.shape_b > 80.0 {
-            self.ln_pdf(x).exp()
         } else {
-            let bb = gamma::gamma(self.shape_a + self.shape_b)
-                / (gamma::gamma(self.shape_a) * gamma::gamma(self.shape_b));
-            bb * x.powf(self.shape_a - 1.0) * (1.0 - x).powf(self.shape_b - 1.0)
+            self.ln_pdf(x).exp()
         }
     }
 
@@ -395,7 +416,7 @@ impl Continuous<f64, f64> for Beta {
     /// ```ignore
     /// let B(α, β) = Γ(α)Γ(β)/Γ(α + β)
     ///
-    /// ln(x^(α - 1) * (1 - x)^(β - 1) / B(α, β))
+    /// ln(Γ(α + β)) - ln(Γ(α)) - ln(Γ(β)) + (α - 1) * ln(x) + (β - 1) * ln(1 - x)
     /// ```
     ///
     /// where `α` is shapeA, `β` is shapeB, and `Γ` is the gamma function
@@ -445,12 +466,57 @@ impl Continuous<f64, f64> for Beta {
     }
 }
 
+impl Moments for Beta {}
+
+impl InverseCDF<f64> for Beta {
+    /// Calculates the inverse cumulative distribution function for the beta
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution, the root of `cdf(y) - x` on
+    /// `[0, 1]` is found numerically with `function::optimization::find_root`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Beta {
+    /// Calculates the inverse cumulative distribution function for the beta
+    /// distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution, the root of `cdf(y) - x` on
+    /// `[0, 1]` is found numerically with `function::optimization::find_root`
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            return Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0));
+        }
+        if x == 0.0 {
+            return Ok(0.0);
+        }
+        if x == 1.0 {
+            return Ok(1.0);
+        }
+        optimization::checked_find_root(|y| self.cdf(y) - x, 0.0, 1.0, 0.0)
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Beta};
+    use distribution::{Univariate, Continuous, Beta, InverseCDF, CheckedInverseCDF};
     use distribution::internal::*;
 
     fn try_create(shape_a: f64, shape_b: f64) -> Beta {
@@ -616,7 +682,7 @@ mod test {
         test_case(1.0, 1.0, 1.0, |x| x.pdf(1.0));
         test_case(9.0, 1.0, 0.0, |x| x.pdf(0.0));
         test_almost(9.0, 1.0, 0.03515625, 1e-15, |x| x.pdf(0.5));
-        test_almost(9.0, 1.0, 9.0, 1e-13, |x| x.pdf(1.0));
+        test_almost(9.0, 1.0, 9.0, 1e-12, |x| x.pdf(1.0));
         test_case(5.0, 100.0, 0.0, |x| x.pdf(0.0));
         test_almost(5.0, 100.0, 4.534102298350337661e-23, 1e-35, |x| x.pdf(0.5));
         test_case(5.0, 100.0, 0.0, |x| x.pdf(1.0));
@@ -632,6 +698,33 @@ mod test {
         test_case(f64::INFINITY, f64::INFINITY, 0.0, |x| x.pdf(1.0));
     }
 
+    #[test]
+    fn test_pdf_large_shapes() {
+        // the gamma functions of these shapes overflow, so the density must
+        // be computed in log space
+        assert_eq!(::function::gamma::gamma(2000.0), f64::INFINITY);
+        test_almost(1000.0, 1000.0, 35.67802229170864, 1e-10, |x| x.pdf(0.5));
+        test_almost(1000.0, 1000.0, 7.205573267542096, 1e-11, |x| x.pdf(0.52));
+        test_almost(1000.0, 1000.0, 0.0015558229570489862, 1e-15, |x| x.pdf(0.45));
+        test_almost(1e6, 2e6, 1465.8073931997008, 1e-5, |x| x.pdf(1.0 / 3.0));
+        test_almost(1000.0, 1000.0, 35.67802229170864f64.ln(), 1e-12, |x| x.ln_pdf(0.5));
+    }
+
+    #[test]
+    fn test_pdf_matches_ln_pdf() {
+        test_almost(2.5, 3.5, 1.8297671231132142, 1e-13, |x| x.pdf(0.3));
+        test_almost(0.5, 0.5, 1.0610329539459689, 1e-13, |x| x.pdf(0.1));
+        test_almost(60.0, 90.0, 9.956026520282638, 1e-12, |x| x.pdf(0.4));
+        // tiny shapes overflow the gamma functions as well
+        test_almost(1e-200, 1e-200, 5e-201 / 0.16, 1e-212, |x| x.pdf(0.2));
+        for &(a, b) in &[(0.5f64, 0.5f64), (2.0, 5.0), (1.0, 3.0), (100.0, 20.0)] {
+            let n = try_create(a, b);
+            for &x in &[0.01f64, 0.2, 0.5, 0.9] {
+                assert_almost_eq!(n.pdf(x), n.ln_pdf(x).exp(), 1e-15 * n.pdf(x).max(1.0));
+            }
+        }
+    }
+
     #[test]
     fn test_pdf_input_lt_0() {
         test_case(1.0, 1.0, 0.0, |x| x.pdf(-1.0));
@@ -711,4 +804,48 @@ mod test {
         test::check_continuous_distribution(&try_create(1.2, 3.4), 0.0, 1.0);
         test::check_continuous_distribution(&try_create(4.5, 6.7), 0.0, 1.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(2.0, 3.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(2.0, 3.0, 1.0, |x| x.inverse_cdf(1.0));
+        for &p in &[1e-10f64, 0.01, 0.25, 0.5, 0.75, 0.99, 1.0 - 1e-10] {
+            // a shape b of 1 has the closed form quantile p^(1 / a)
+            test_almost(2.5, 1.0, p.powf(1.0 / 2.5), 1e-14, |x| x.inverse_cdf(p));
+        }
+        for &p in &[0.01, 0.25, 0.5, 0.75, 0.99] {
+            for &(a, b) in &[(0.5, 0.5), (2.0, 3.0), (10.0, 1.5)] {
+                test_almost(a, b, p, 1e-13, |x| x.cdf(x.inverse_cdf(p)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_inverse_cdf_bad_input() {
+        let n = try_create(2.0, 3.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_checked_mode_bimodal() {
+        assert!(try_create(0.5, 0.5).checked_mode().is_err());
+        assert_almost_eq!(try_create(2.0, 3.0).checked_mode().unwrap(), 1.0 / 3.0, 1e-15);
+    }
+
+    #[test]
+    fn test_checked_pdf_cdf_support() {
+        let n = try_create(2.0, 3.0);
+        assert_eq!(n.checked_pdf(-0.1), None);
+        assert_eq!(n.checked_pdf(1.1), None);
+        assert_eq!(n.checked_ln_pdf(-0.1), None);
+        assert_eq!(n.checked_pdf(f64::NAN), None);
+        assert_eq!(n.checked_pdf(0.3), Some(n.pdf(0.3)));
+        assert_eq!(n.checked_ln_pdf(0.3), Some(n.ln_pdf(0.3)));
+        assert_eq!(n.checked_pdf(0.0), Some(n.pdf(0.0)));
+        assert_eq!(n.checked_cdf(-0.1), Some(0.0));
+        assert_eq!(n.checked_cdf(1.1), Some(1.0));
+        assert_eq!(n.checked_cdf(0.3), Some(n.cdf(0.3)));
+        assert_eq!(n.checked_cdf(f64::NAN), None);
+    }
 }
@@ -1,6 +1,6 @@
-use distribution::{Discrete, Univariate};
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
 use function::{beta, factorial};
-use rand::distributions::Distribution;
+use rand::distributions::{Distribution, Open01};
 use rand::Rng;
 use statistics::*;
 use std::f64;
@@ -85,18 +85,84 @@ impl Binomial {
     pub fn n(&self) -> u64 {
         self.n
     }
+
+    /// Returns the Chernoff bound on the upper tail probability `P(X >= k)`
+    /// of the binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// The bound is conservative, i.e. never below the exact tail
+    /// probability, and is computed in constant time regardless of `n`.
+    /// It is only informative above the mean; for `k <= np` this returns
+    /// `1`. For `k > n` the tail probability is exactly `0`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-n * D(k / n || p))
+    /// ```
+    ///
+    /// where `D(a || p) = a * ln(a / p) + (1 - a) * ln((1 - a) / (1 - p))` is
+    /// the Kullback-Leibler divergence between Bernoulli distributions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Binomial;
+    ///
+    /// let n = Binomial::new(0.5, 100).unwrap();
+    /// assert_eq!(n.chernoff_upper_tail(40), 1.0);
+    /// assert!(n.chernoff_upper_tail(75) < 1e-5);
+    /// ```
+    pub fn chernoff_upper_tail(&self, k: u64) -> f64 {
+        let n = self.n as f64;
+        let k_f = k as f64;
+        if k > self.n {
+            0.0
+        } else if k_f <= n * self.p {
+            1.0
+        } else {
+            let a = k_f / n;
+            // terms with a coefficient of zero vanish
+            let upper = a * (a / self.p).ln();
+            let lower = if a == 1.0 {
+                0.0
+            } else {
+                (1.0 - a) * ((1.0 - a) / (1.0 - self.p)).ln()
+            };
+            (-n * (upper + lower)).exp()
+        }
+    }
+}
+
+impl Parametric for Binomial {
+    /// Returns the `p` and `n` parameters of the binomial distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("p", self.p), ("n", self.n as f64)]
+    }
+
+    /// Constructs a binomial distribution from its `p` and `n` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Binomial> {
+        let p = internal::parameter_values(params, &["p", "n"])?;
+        Binomial::new(p[0], internal::u64_parameter(p[1])?)
+    }
 }
 
 impl Distribution<f64> for Binomial {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
-        (0..self.n).fold(0.0, |acc, _| {
-            let n: f64 = r.gen();
-            if n < self.p {
-                acc + 1.0
-            } else {
-                acc
-            }
-        })
+        // sample with the smaller of p and 1 - p and reflect the result
+        let p = self.p.min(1.0 - self.p);
+        let n = self.n as f64;
+        let x = if n * p < 30.0 {
+            sample_inversion(r, n, p)
+        } else {
+            sample_btpe(r, n, p)
+        };
+        if self.p > 0.5 {
+            n - x
+        } else {
+            x
+        }
     }
 }
 
@@ -153,6 +219,22 @@ impl Max<u64> for Binomial {
     }
 }
 
+impl Domain for Binomial {
+    /// Returns the support of the binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {0, 1, ..., n}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: 0.0,
+            hi: self.n as f64,
+        }
+    }
+}
+
 impl Mean<f64> for Binomial {
     /// Returns the mean of the binomial distribution
     ///
@@ -190,6 +272,8 @@ impl Variance<f64> for Binomial {
     }
 }
 
+impl Dispersion for Binomial {}
+
 impl Entropy<f64> for Binomial {
     /// Returns the entropy of the binomial distribution
     ///
@@ -239,18 +323,47 @@ impl Median<f64> for Binomial {
 impl Mode<u64> for Binomial {
     /// Returns the mode for the binomial distribution
     ///
+    /// # Remarks
+    ///
+    /// If `(n + 1) * p` is an integer both `(n + 1) * p` and
+    /// `(n + 1) * p - 1` are modes and the smaller one is returned. Use
+    /// `Modes::modes` to get both.
+    ///
     /// # Formula
     ///
     /// ```ignore
-    /// floor((n + 1) * p)
+    /// ceil((n + 1) * p) - 1
     /// ```
     fn mode(&self) -> u64 {
+        self.modes()[0]
+    }
+}
+
+impl Modes<u64> for Binomial {
+    /// Returns the modes of the binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if (n + 1) * p is an integer in [1, n] {
+    ///     [(n + 1) * p - 1, (n + 1) * p]
+    /// } else {
+    ///     [floor((n + 1) * p)]
+    /// }
+    /// ```
+    fn modes(&self) -> Vec<u64> {
         if self.p == 0.0 {
-            0
+            vec![0]
         } else if self.p == 1.0 {
-            self.n
+            vec![self.n]
         } else {
-            ((self.n as f64 + 1.0) * self.p).floor() as u64
+            let m = (self.n as f64 + 1.0) * self.p;
+            let floor = m.floor() as u64;
+            if m == m.floor() && floor >= 1 {
+                vec![floor - 1, floor]
+            } else {
+                vec![floor]
+            }
         }
     }
 }
@@ -318,14 +431,158 @@ impl Discrete<u64, f64> for Binomial {
     }
 }
 
+/// Generates one sample from the binomial distribution by sequential
+/// search of the cumulative distribution function, restarting if the
+/// search runs past `np + 10 * sqrt(npq + 1)`. Expects `p <= 0.5` with
+/// `np` small
+fn sample_inversion<R: Rng + ?Sized>(r: &mut R, n: f64, p: f64) -> f64 {
+    let q = 1.0 - p;
+    let qn = (n * (-p).ln_1p()).exp();
+    let bound = n.min(n * p + 10.0 * (n * p * q + 1.0).sqrt());
+
+    let mut x = 0.0;
+    let mut px = qn;
+    let mut u: f64 = r.gen();
+    while u > px {
+        x += 1.0;
+        if x > bound {
+            x = 0.0;
+            px = qn;
+            u = r.gen();
+        } else {
+            u -= px;
+            px *= (n - x + 1.0) * p / (x * q);
+        }
+    }
+    x
+}
+
+/// Generates one sample from the binomial distribution by the triangle,
+/// parallelogram, exponential (BTPE) method by V. Kachitvichyanukul and
+/// B. W. Schmeiser from Communications of the ACM Vol. 31 No. 2 (1988)
+/// pp. 216 - 222. Expects `p <= 0.5` with `np >= 30`
+fn sample_btpe<R: Rng + ?Sized>(r: &mut R, n: f64, p: f64) -> f64 {
+    #[inline]
+    fn stirling_term(x: f64) -> f64 {
+        let x2 = x * x;
+        (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / x2) / x2) / x2) / x2) / x / 166320.0
+    }
+
+    let q = 1.0 - p;
+    let npq = n * p * q;
+    let fm = n * p + p;
+    let m = fm.floor();
+
+    // set up the regions of the majorizing function
+    let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+    let xm = m + 0.5;
+    let xl = xm - p1;
+    let xr = xm + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (fm - xl) / (fm - xl * p);
+    let lambda_l = a * (1.0 + a / 2.0);
+    let a = (xr - fm) / (xr * q);
+    let lambda_r = a * (1.0 + a / 2.0);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    loop {
+        let u = r.gen::<f64>() * p4;
+        let mut v: f64 = r.sample(Open01);
+
+        let y = if u <= p1 {
+            // triangular region, accept immediately
+            return (xm - p1 * v + u).floor();
+        } else if u <= p2 {
+            // parallelogram region
+            let x = xl + (u - p1) / c;
+            v = v * c + 1.0 - (m - x + 0.5).abs() / p1;
+            if v > 1.0 {
+                continue;
+            }
+            x.floor()
+        } else if u <= p3 {
+            // left exponential tail
+            let y = (xl + v.ln() / lambda_l).floor();
+            if y < 0.0 {
+                continue;
+            }
+            v *= (u - p2) * lambda_l;
+            y
+        } else {
+            // right exponential tail
+            let y = (xr - v.ln() / lambda_r).floor();
+            if y > n {
+                continue;
+            }
+            v *= (u - p3) * lambda_r;
+            y
+        };
+
+        let k = (y - m).abs();
+        if k <= 20.0 || k >= npq / 2.0 - 1.0 {
+            // evaluate the ratio f(y) / f(m) recursively
+            let s = p / q;
+            let a = s * (n + 1.0);
+            let mut f = 1.0;
+            if m < y {
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+            } else if m > y {
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+            }
+            if v <= f {
+                return y;
+            }
+            continue;
+        }
+
+        // squeeze using upper and lower bounds on ln(f(y) / f(m))
+        let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+        let t = -k * k / (2.0 * npq);
+        let ln_v = v.ln();
+        if ln_v < t - rho {
+            return y;
+        }
+        if ln_v > t + rho {
+            continue;
+        }
+
+        // final comparison using Stirling's approximation
+        let x1 = y + 1.0;
+        let f1 = m + 1.0;
+        let z = n + 1.0 - m;
+        let w = n - y + 1.0;
+        let bound = xm * (f1 / x1).ln()
+            + (n - m + 0.5) * (z / w).ln()
+            + (y - m) * (w * p / (x1 * q)).ln()
+            + stirling_term(f1)
+            + stirling_term(z)
+            + stirling_term(x1)
+            + stirling_term(w);
+        if ln_v <= bound {
+            return y;
+        }
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::fmt::Debug;
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Discrete, Binomial};
+    use distribution::{Univariate, Discrete, Binomial, Domain, Parametric, Support};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(p: f64, n: u64) -> Binomial {
         let n = Binomial::new(p, n);
@@ -428,6 +685,21 @@ mod test {
         test_case(0.0, 4, 0, |x| x.mode());
         test_case(0.3, 3, 1, |x| x.mode());
         test_case(1.0, 2, 2, |x| x.mode());
+        test_case(0.5, 3, 1, |x| x.mode());
+        test_case(0.5, 1, 0, |x| x.mode());
+    }
+
+    #[test]
+    fn test_modes() {
+        test_case(0.0, 4, vec![0], |x| x.modes());
+        test_case(1.0, 4, vec![4], |x| x.modes());
+        test_case(0.3, 3, vec![1], |x| x.modes());
+        test_case(0.5, 3, vec![1, 2], |x| x.modes());
+        test_case(0.25, 7, vec![1, 2], |x| x.modes());
+        test_case(0.5, 1, vec![0, 1], |x| x.modes());
+        test_case(0.75, 0, vec![0], |x| x.modes());
+        let n = try_create(0.25, 7);
+        assert_almost_eq!(n.pmf(1), n.pmf(2), 1e-15);
     }
 
     #[test]
@@ -520,6 +792,34 @@ mod test {
         test_case(1.0, 10, 1.0, |x| x.cdf(10.0));
     }
 
+    #[test]
+    fn test_cdf_matches_pmf_sum() {
+        for &n in &[1u64, 2, 5, 10, 20, 50] {
+            for &p in &[0.01f64, 0.3, 0.5, 0.9] {
+                let dist = try_create(p, n);
+                let mut sum = 0.0;
+                for k in 0..=n {
+                    sum += dist.pmf(k);
+                    assert_almost_eq!(dist.cdf(k as f64), sum, 1e-12);
+                    // the cdf is constant between the integers
+                    assert_eq!(dist.cdf(k as f64 + 0.5), dist.cdf(k as f64));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdf_large_n() {
+        // evaluated with the regularized incomplete beta function rather
+        // than by summing the pmf over several hundred thousand terms, with
+        // the precision limited by the log gamma terms of its prefactor
+        let dist = try_create(0.3, 1_000_000);
+        assert_almost_eq!(dist.cdf(297000.0) / 2.8467536466221168e-11, 1.0, 1e-8);
+        assert_almost_eq!(dist.cdf(298000.0) / 6.329968404771226e-6, 1.0, 1e-8);
+        assert_almost_eq!(dist.cdf(300000.0), 0.5004933190666696, 1e-8);
+        assert_almost_eq!(dist.cdf(302000.0), 0.999993580546952, 1e-12);
+    }
+
     #[test]
     fn test_cdf_lower_bound() {
         test_case(0.5, 3, 0.0, |x| x.cdf(-1.0));
@@ -535,4 +835,122 @@ mod test {
         test::check_discrete_distribution(&try_create(0.3, 5), 5);
         test::check_discrete_distribution(&try_create(0.7, 10), 10);
     }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        for &(p, n) in &[(0.3, 20), (0.3, 1000000), (0.9, 1000), (0.5, 100), (1e-4, 1000000)] {
+            let b = try_create(p, n);
+            let samples: Vec<f64> = (0..100000).map(|_| b.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x <= n as f64 && x == x.floor()));
+            assert_almost_eq!((&samples).mean() / b.mean(), 1.0, 0.01);
+            assert_almost_eq!((&samples).variance() / b.variance(), 1.0, 0.03);
+        }
+    }
+
+    #[test]
+    fn test_sample_frequencies() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let b = try_create(0.6, 200);
+        let count = 200000;
+        let mut freq = vec![0.0; 201];
+        for _ in 0..count {
+            freq[b.sample(&mut r) as usize] += 1.0;
+        }
+        for x in 90..150 {
+            let expected = b.pmf(x) * count as f64;
+            assert!((freq[x as usize] - expected).abs() < 5.0 * expected.sqrt());
+        }
+    }
+
+    #[test]
+    fn test_sample_degenerate() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        assert_eq!(try_create(0.0, 10).sample(&mut r), 0.0);
+        assert_eq!(try_create(1.0, 10).sample(&mut r), 10.0);
+        assert_eq!(try_create(0.5, 0).sample(&mut r), 0.0);
+    }
+
+    #[test]
+    fn test_support() {
+        let s = try_create(0.3, 10).support();
+        assert_eq!(s, Support::DiscreteRange { lo: 0.0, hi: 10.0 });
+        assert!(s.contains(0.0));
+        assert!(s.contains(10.0));
+        assert!(!s.contains(11.0));
+        assert!(!s.contains(2.5));
+        assert!(!s.contains(-1.0));
+    }
+
+    #[test]
+    fn test_expected_counts() {
+        // one bin per value from the edges halfway between the integers
+        let n = try_create(0.3, 10);
+        let edges: Vec<f64> = (0..12).map(|x| x as f64 - 0.5).collect();
+        let counts = n.expected_counts(&edges, 500);
+        assert_eq!(counts.len(), 11);
+        for (x, &c) in counts.iter().enumerate() {
+            assert_almost_eq!(c, 500.0 * n.pmf(x as u64), 1e-10);
+        }
+        assert_almost_eq!(counts.iter().sum::<f64>(), 500.0, 1e-10);
+    }
+
+    #[test]
+    fn test_checked_cdf_support() {
+        let n = try_create(0.3, 10);
+        assert_eq!(n.checked_cdf(-1.0), Some(0.0));
+        assert_eq!(n.checked_cdf(10.0), Some(1.0));
+        assert_eq!(n.checked_cdf(25.0), Some(1.0));
+        assert_eq!(n.checked_cdf(4.5), Some(n.cdf(4.5)));
+        assert_eq!(n.checked_cdf(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_chernoff_upper_tail() {
+        // n = 10, p = 0.5, k = 8: e^(-10 * (0.8 ln(1.6) + 0.2 ln(0.4)))
+        let expected = (-10.0 * (0.8 * 1.6f64.ln() + 0.2 * 0.4f64.ln())).exp();
+        assert_almost_eq!(try_create(0.5, 10).chernoff_upper_tail(8), expected, 1e-15);
+        assert_almost_eq!(try_create(0.5, 10).chernoff_upper_tail(8), 0.14551915228366838, 1e-15);
+        assert_almost_eq!(try_create(0.3, 10).chernoff_upper_tail(10), 0.3f64.powi(10), 1e-18);
+        assert_eq!(try_create(0.3, 10).chernoff_upper_tail(3), 1.0);
+        assert_eq!(try_create(0.3, 10).chernoff_upper_tail(0), 1.0);
+        assert_eq!(try_create(0.3, 10).chernoff_upper_tail(11), 0.0);
+        assert_eq!(try_create(0.0, 10).chernoff_upper_tail(1), 0.0);
+        assert_eq!(try_create(0.0, 10).chernoff_upper_tail(0), 1.0);
+        assert_eq!(try_create(1.0, 10).chernoff_upper_tail(10), 1.0);
+        assert_eq!(try_create(0.5, 0).chernoff_upper_tail(0), 1.0);
+    }
+
+    #[test]
+    fn test_chernoff_upper_tail_exceeds_exact() {
+        for &(p, n) in &[(0.5f64, 10u64), (0.1, 50), (0.7, 200), (0.02, 1000)] {
+            let dist = try_create(p, n);
+            for k in 0..n + 2 {
+                let exact = if k == 0 { 1.0 } else { 1.0 - dist.cdf(k as f64 - 1.0) };
+                let bound = dist.chernoff_upper_tail(k);
+                assert!(bound <= 1.0);
+                assert!(bound >= exact - 1e-14, "p = {}, n = {}, k = {}: {} < {}", p, n, k, bound, exact);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(try_create(0.3, 10).parameters(), vec![("p", 0.3), ("n", 10.0)]);
+    }
+
+    #[test]
+    fn test_from_parameters() {
+        let n = try_create(0.3, 10);
+        assert_eq!(Binomial::from_parameters(&n.parameters()).unwrap(), n);
+        assert!(Binomial::from_parameters(&[("p", 0.3), ("n", 10.5)]).is_err());
+        assert!(Binomial::from_parameters(&[("p", 0.3), ("n", -1.0)]).is_err());
+        assert!(Binomial::from_parameters(&[("p", 1.3), ("n", 10.0)]).is_err());
+    }
 }
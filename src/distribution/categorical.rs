@@ -1,4 +1,4 @@
-use distribution::{CheckedInverseCDF, Discrete, InverseCDF, Univariate};
+use distribution::{CheckedInverseCDF, Discrete, Domain, InverseCDF, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -75,6 +75,47 @@ impl Categorical {
         }
     }
 
+    /// Returns the Kullback-Leibler divergence `KL(self || other)` of the
+    /// categorical distribution `other` from `self`, treating categories
+    /// beyond the length of either distribution as having zero mass
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(p_j * ln(p_j / q_j))
+    /// ```
+    ///
+    /// where `p_j` and `q_j` are the `j`th probability masses of `self` and
+    /// `other`, and terms with `p_j = 0` are zero. The divergence is
+    /// infinite if `q_j = 0` for any `p_j > 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Categorical;
+    ///
+    /// let p = Categorical::new(&[1.0, 1.0]).unwrap();
+    /// let q = Categorical::new(&[1.0, 3.0]).unwrap();
+    /// assert_eq!(p.kl_divergence(&p), 0.0);
+    /// assert!(p.kl_divergence(&q) > 0.0);
+    /// assert_eq!(q.kl_divergence(&Categorical::new(&[1.0]).unwrap()), f64::INFINITY);
+    /// ```
+    pub fn kl_divergence(&self, other: &Categorical) -> f64 {
+        self.norm_pmf
+            .iter()
+            .enumerate()
+            .filter(|&(_, &p)| p > 0.0)
+            .map(|(i, &p)| {
+                let q = other.norm_pmf.get(i).cloned().unwrap_or(0.0);
+                if q == 0.0 {
+                    f64::INFINITY
+                } else {
+                    p * (p / q).ln()
+                }
+            })
+            .sum()
+    }
+
     fn cdf_max(&self) -> f64 {
         *unsafe { self.cdf.get_unchecked(self.cdf.len() - 1) }
     }
@@ -86,6 +127,14 @@ impl Distribution<f64> for Categorical {
     }
 }
 
+impl Distribution<u64> for Categorical {
+    /// Draws the index of a category in `0..k`, where `k` is the number of
+    /// probability masses the distribution was constructed with
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> u64 {
+        sample_unchecked(r, &self.cdf) as u64
+    }
+}
+
 impl Univariate<u64, f64> for Categorical {
     /// Calculates the cumulative distribution function for the categorical
     /// distribution at `x`
@@ -189,6 +238,24 @@ impl Max<u64> for Categorical {
     }
 }
 
+impl Domain for Categorical {
+    /// Returns the support of the categorical distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {i : p_i > 0}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteSet(
+            (0..self.norm_pmf.len())
+                .filter(|&i| self.norm_pmf[i] > 0.0)
+                .map(|i| i as f64)
+                .collect(),
+        )
+    }
+}
+
 impl Mean<f64> for Categorical {
     /// Returns the mean of the categorical distribution
     ///
@@ -248,6 +315,8 @@ impl Variance<f64> for Categorical {
     }
 }
 
+impl Dispersion for Categorical {}
+
 impl Entropy<f64> for Categorical {
     /// Returns the entropy of the categorical distribution
     ///
@@ -283,6 +352,33 @@ impl Median<f64> for Categorical {
     }
 }
 
+impl Mode<u64> for Categorical {
+    /// Returns the mode of the categorical distribution, the index of the
+    /// largest probability mass
+    ///
+    /// # Remarks
+    ///
+    /// If several categories share the largest probability mass the lowest
+    /// of their indices is returned
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// argmax_j(p_j)
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass
+    fn mode(&self) -> u64 {
+        let mut mode = 0;
+        for (i, &p) in self.norm_pmf.iter().enumerate() {
+            if p > self.norm_pmf[mode] {
+                mode = i;
+            }
+        }
+        mode as u64
+    }
+}
+
 impl Discrete<u64, f64> for Categorical {
     /// Calculates the probability mass function for the categorical
     /// distribution at `x`
@@ -307,6 +403,90 @@ impl Discrete<u64, f64> for Categorical {
     }
 }
 
+/// Returns the Kullback-Leibler divergence rate between the Markov chains
+/// with the row-stochastic transition matrices `p` and `q`, weighting the
+/// divergence of each row by the probability of its state under the
+/// distribution `stationary`
+///
+/// # Panics
+///
+/// If the arguments are invalid, see `checked_transition_kl`
+///
+/// # Formula
+///
+/// ```ignore
+/// Σ(π_i * KL(P_i || Q_i))
+/// ```
+///
+/// where `π_i` is the `i`th element of `stationary` and `P_i` and `Q_i` are
+/// the categorical distributions given by the `i`th rows of `p` and `q`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::transition_kl;
+///
+/// let p = [vec![0.9, 0.1], vec![0.2, 0.8]];
+/// let q = [vec![0.5, 0.5], vec![0.5, 0.5]];
+/// assert_eq!(transition_kl(&p, &p, &[2.0 / 3.0, 1.0 / 3.0]), 0.0);
+/// assert!(transition_kl(&p, &q, &[2.0 / 3.0, 1.0 / 3.0]) > 0.0);
+/// ```
+pub fn transition_kl(p: &[Vec<f64>], q: &[Vec<f64>], stationary: &[f64]) -> f64 {
+    checked_transition_kl(p, q, stationary).unwrap()
+}
+
+/// Returns the Kullback-Leibler divergence rate between the Markov chains
+/// with the row-stochastic transition matrices `p` and `q`, weighting the
+/// divergence of each row by the probability of its state under the
+/// distribution `stationary`
+///
+/// # Errors
+///
+/// If `p` is empty or not square, if `q` and `stationary` do not have the
+/// same dimensions as `p`, or if `stationary` or any row of `p` or `q`
+/// contains negative or `NaN` entries or does not sum to `1`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::checked_transition_kl;
+///
+/// let p = [vec![0.9, 0.1], vec![0.2, 0.8]];
+/// let q = [vec![0.5, 0.5], vec![0.6, 0.6]];
+/// assert!(checked_transition_kl(&p, &p, &[0.5, 0.5]).is_ok());
+/// assert!(checked_transition_kl(&p, &q, &[0.5, 0.5]).is_err());
+/// ```
+pub fn checked_transition_kl(p: &[Vec<f64>], q: &[Vec<f64>], stationary: &[f64]) -> Result<f64> {
+    let n = p.len();
+    if n == 0 || q.len() != n || stationary.len() != n {
+        return Err(StatsError::BadParams);
+    }
+    if !is_probability_vector(stationary) {
+        return Err(StatsError::BadParams);
+    }
+    let mut rate = 0.0;
+    for i in 0..n {
+        if p[i].len() != n || q[i].len() != n {
+            return Err(StatsError::BadParams);
+        }
+        if !is_probability_vector(&p[i]) || !is_probability_vector(&q[i]) {
+            return Err(StatsError::BadParams);
+        }
+        if stationary[i] > 0.0 {
+            let kl = Categorical::new(&p[i])?.kl_divergence(&Categorical::new(&q[i])?);
+            rate += stationary[i] * kl;
+        }
+    }
+    Ok(rate)
+}
+
+// Returns true if the elements of `arr` are non-negative and sum to one up to
+// rounding errors
+fn is_probability_vector(arr: &[f64]) -> bool {
+    super::internal::is_valid_multinomial(arr, true)
+        && (arr.iter().sum::<f64>() - 1.0).abs() <= 1e-12 * arr.len() as f64
+}
+
 /// Draws a sample from the categorical distribution described by `cdf`
 /// without doing any bounds checking
 pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, cdf: &[f64]) -> f64 {
@@ -391,9 +571,11 @@ fn test_binary_index() {
 mod test {
     use std::f64;
     use std::fmt::Debug;
+    use rand::distributions::Distribution;
     use statistics::*;
-    use distribution::{Categorical, CheckedInverseCDF, Discrete, InverseCDF, Univariate};
+    use distribution::{checked_transition_kl, transition_kl, Categorical, CheckedInverseCDF, Discrete, InverseCDF, Univariate, Domain, Support};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(prob_mass: &[f64]) -> Categorical {
         let n = Categorical::new(prob_mass);
@@ -486,6 +668,36 @@ mod test {
         test_case(&[4.0, 2.5, 2.5, 1.0], 1.0, |x| x.median());
     }
 
+    #[test]
+    fn test_mode() {
+        test_case(&[0.0, 3.0, 1.0, 1.0], 1, |x| x.mode());
+        test_case(&[0.5, 0.1, 0.1, 2.0], 3, |x| x.mode());
+        test_case(&[7.0], 0, |x| x.mode());
+        // ties go to the lowest index
+        test_case(&[1.0, 2.5, 2.5, 1.0], 1, |x| x.mode());
+        test_case(&[1.0, 1.0, 1.0], 0, |x| x.mode());
+        test_case(&[0.0, 0.0, 4.0, 1.0, 4.0], 2, |x| x.mode());
+    }
+
+    #[test]
+    fn test_sample_indices() {
+        let mut r = rng::seeded(0);
+        let n = try_create(&[1.0, 0.0, 3.0, 4.0]);
+        let mut counts = [0u64; 4];
+        for _ in 0..20000 {
+            let i: u64 = n.sample(&mut r);
+            assert!(i < 4);
+            counts[i as usize] += 1;
+        }
+        // zero-probability categories are never drawn
+        assert_eq!(counts[1], 0);
+        assert_almost_eq!(counts[0] as f64 / 20000.0, 0.125, 0.01);
+        assert_almost_eq!(counts[2] as f64 / 20000.0, 0.375, 0.01);
+        assert_almost_eq!(counts[3] as f64 / 20000.0, 0.5, 0.01);
+        let x: f64 = n.sample(&mut r);
+        assert!(x == 0.0 || x == 2.0 || x == 3.0);
+    }
+
     #[test]
     fn test_min_max() {
         test_case(&[4.0, 2.5, 2.5, 1.0], 0, |x| x.min());
@@ -574,4 +786,68 @@ mod test {
         test::check_discrete_distribution(&try_create(&[1.0, 2.0, 3.0, 4.0]), 4);
         test::check_discrete_distribution(&try_create(&[0.0, 1.0, 2.0, 3.0, 4.0]), 5);
     }
+
+    #[test]
+    fn test_support() {
+        let s = try_create(&[0.0, 0.25, 0.0, 0.75]).support();
+        assert_eq!(s, Support::DiscreteSet(vec![1.0, 3.0]));
+        assert!(s.contains(3.0));
+        assert!(!s.contains(2.0));
+        assert_eq!(s.bounds(), (1.0, 3.0));
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        let p = try_create(&[0.0, 1.0, 3.0]);
+        let q = try_create(&[1.0, 1.0, 2.0]);
+        assert_eq!(p.kl_divergence(&p), 0.0);
+        assert_almost_eq!(p.kl_divergence(&q), 0.25 * 1f64.ln() + 0.75 * 1.5f64.ln(), 1e-15);
+        assert_eq!(q.kl_divergence(&p), f64::INFINITY);
+        assert_eq!(try_create(&[1.0, 1.0]).kl_divergence(&try_create(&[1.0])), f64::INFINITY);
+        assert_eq!(try_create(&[1.0, 0.0]).kl_divergence(&try_create(&[1.0])), 0.0);
+    }
+
+    #[test]
+    fn test_transition_kl() {
+        let p = [vec![0.9, 0.1], vec![0.2, 0.8]];
+        let q = [vec![0.5, 0.5], vec![0.7, 0.3]];
+        // the stationary distribution of p
+        let pi = [2.0 / 3.0, 1.0 / 3.0];
+        assert_eq!(transition_kl(&p, &p, &pi), 0.0);
+        assert_eq!(transition_kl(&q, &q, &[0.5, 0.5]), 0.0);
+        let kl0 = 0.9 * (0.9f64 / 0.5).ln() + 0.1 * (0.1f64 / 0.5).ln();
+        let kl1 = 0.2 * (0.2f64 / 0.7).ln() + 0.8 * (0.8f64 / 0.3).ln();
+        assert_almost_eq!(transition_kl(&p, &q, &pi), 2.0 / 3.0 * kl0 + 1.0 / 3.0 * kl1, 1e-15);
+        // states with zero stationary probability do not contribute
+        let r = [vec![0.5, 0.5], vec![1.0, 0.0]];
+        assert_eq!(transition_kl(&r, &q, &[1.0, 0.0]), kl_row(&r[0], &q[0]));
+        assert_eq!(transition_kl(&q, &r, &[0.0, 1.0]), f64::INFINITY);
+    }
+
+    fn kl_row(p: &[f64], q: &[f64]) -> f64 {
+        try_create(p).kl_divergence(&try_create(q))
+    }
+
+    #[test]
+    fn test_checked_transition_kl_bad_input() {
+        let p = [vec![0.9, 0.1], vec![0.2, 0.8]];
+        let pi = [0.5, 0.5];
+        assert!(checked_transition_kl(&[], &[], &[]).is_err());
+        assert!(checked_transition_kl(&p, &p[..1], &pi).is_err());
+        assert!(checked_transition_kl(&p, &p, &[1.0]).is_err());
+        assert!(checked_transition_kl(&p, &p, &[0.6, 0.6]).is_err());
+        assert!(checked_transition_kl(&p, &p, &[1.5, -0.5]).is_err());
+        assert!(checked_transition_kl(&p, &[vec![0.9, 0.1], vec![0.2, 0.7]], &pi).is_err());
+        assert!(checked_transition_kl(&p, &[vec![0.9, 0.1], vec![1.0]], &pi).is_err());
+        assert!(checked_transition_kl(&p, &[vec![1.1, -0.1], vec![0.2, 0.8]], &pi).is_err());
+        assert!(checked_transition_kl(&p, &[vec![f64::NAN, 0.1], vec![0.2, 0.8]], &pi).is_err());
+        assert!(checked_transition_kl(&[vec![1.0, 0.0, 0.0], vec![1.0, 0.0, 0.0]], &p, &pi).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transition_kl_bad_input() {
+        let p = [vec![0.9, 0.1], vec![0.2, 0.8]];
+        transition_kl(&p, &p, &[1.0]);
+    }
 }
@@ -1,4 +1,7 @@
-use distribution::{Continuous, Univariate};
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, LocationScale, Parametric,
+    Support, Univariate,
+};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -83,12 +86,45 @@ impl Cauchy {
     }
 }
 
+impl Parametric for Cauchy {
+    /// Returns the `location` and `scale` parameters of the cauchy distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("location", self.location), ("scale", self.scale)]
+    }
+
+    /// Constructs a cauchy distribution from its `location` and `scale`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Cauchy> {
+        let p = internal::parameter_values(params, &["location", "scale"])?;
+        Cauchy::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for Cauchy {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         self.location + self.scale * (f64::consts::PI * (r.gen::<f64>() - 0.5)).tan()
     }
 }
 
+impl LocationScale for Cauchy {
+    /// Returns the cauchy distribution of `loc + scale * X`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Cauchy(a + bx_0, bγ)
+    /// ```
+    ///
+    /// where `a` is `loc`, `b` is `scale`, `x_0` is the location and `γ`
+    /// the scale of the distribution
+    fn transform(&self, loc: f64, scale: f64) -> Result<Cauchy> {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Cauchy::new(loc + scale * self.location, scale * self.scale)
+    }
+}
+
 impl Univariate<f64, f64> for Cauchy {
     /// Calculates the cumulative distribution function for the
     /// cauchy distribution at `x`
@@ -105,6 +141,54 @@ impl Univariate<f64, f64> for Cauchy {
     }
 }
 
+impl InverseCDF<f64> for Cauchy {
+    /// Calculates the inverse cumulative distribution function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0 + γ * tan(π * (x - 0.5))
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Cauchy {
+    /// Calculates the inverse cumulative distribution function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0 + γ * tan(π * (x - 0.5))
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else if x == 0.0 {
+            Ok(f64::NEG_INFINITY)
+        } else if x == 1.0 {
+            Ok(f64::INFINITY)
+        } else {
+            Ok(self.location + self.scale * (f64::consts::PI * (x - 0.5)).tan())
+        }
+    }
+}
+
 impl Min<f64> for Cauchy {
     /// Returns the minimum value in the domain of the cauchy
     /// distribution representable by a double precision float
@@ -133,6 +217,59 @@ impl Max<f64> for Cauchy {
     }
 }
 
+impl Domain for Cauchy {
+    /// Returns the support of the cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (-∞, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+            lo_closed: false,
+            hi_closed: false,
+        }
+    }
+}
+
+impl CheckedMean<f64> for Cauchy {
+    /// Returns the mean of the cauchy distribution
+    ///
+    /// # Errors
+    ///
+    /// Always, since the mean of the cauchy distribution is undefined
+    fn checked_mean(&self) -> Result<f64> {
+        Err(StatsError::SpecialCase(
+            "The mean of the cauchy distribution is undefined",
+        ))
+    }
+}
+
+impl CheckedVariance<f64> for Cauchy {
+    /// Returns the variance of the cauchy distribution
+    ///
+    /// # Errors
+    ///
+    /// Always, since the variance of the cauchy distribution is undefined
+    fn checked_variance(&self) -> Result<f64> {
+        Err(StatsError::SpecialCase(
+            "The variance of the cauchy distribution is undefined",
+        ))
+    }
+
+    /// Returns the standard deviation of the cauchy distribution
+    ///
+    /// # Errors
+    ///
+    /// Always, since the variance of the cauchy distribution is undefined
+    fn checked_std_dev(&self) -> Result<f64> {
+        self.checked_variance()
+    }
+}
+
 impl Entropy<f64> for Cauchy {
     /// Returns the entropy of the cauchy distribution
     ///
@@ -218,8 +355,10 @@ impl Continuous<f64, f64> for Cauchy {
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Cauchy};
+    use rand::distributions::Distribution;
+    use distribution::{Univariate, Continuous, Cauchy, CheckedInverseCDF, InverseCDF, LocationScale};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(location: f64, scale: f64) -> Cauchy {
         let n = Cauchy::new(location, scale);
@@ -411,4 +550,55 @@ mod test {
         test::check_continuous_distribution(&try_create(-1.2, 3.4), -1500.0, 1500.0);
         test::check_continuous_distribution(&try_create(-4.5, 6.7), -5000.0, 5000.0);
     }
+
+    #[test]
+    fn test_location_scale() {
+        let n = try_create(1.5, 2.0);
+        let t = n.transform(-1.0, 3.0).unwrap();
+        assert_eq!(t.location(), -1.0 + 3.0 * 1.5);
+        assert_eq!(t.scale(), 3.0 * 2.0);
+        assert_eq!(n.transform(0.0, 1.0).unwrap(), n);
+        assert!(n.transform(0.0, 0.0).is_err());
+        assert!(n.transform(0.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_checked_moments() {
+        let n = try_create(1.0, 2.0);
+        assert!(n.checked_mean().is_err());
+        assert!(n.checked_variance().is_err());
+        assert!(n.checked_std_dev().is_err());
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(0.0, 1.0, 1.0, 1e-15, |x| x.inverse_cdf(0.75));
+        test_almost(0.0, 1.0, -1.0, 1e-15, |x| x.inverse_cdf(0.25));
+        test_almost(2.0, 3.0, 2.0, 1e-15, |x| x.inverse_cdf(0.5));
+        test_almost(2.0, 3.0, 2.0 + 3.0 * 3f64.sqrt(), 1e-14, |x| x.inverse_cdf(5.0 / 6.0));
+        let n = try_create(-1.0, 0.5);
+        assert_eq!(n.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+        for &p in &[1e-10f64, 0.01, 0.3, 0.5, 0.7, 0.99] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+        }
+        for &x in &[-100.0f64, -3.0, -1.0, 0.0, 2.5, 50.0] {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-11 * x.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_sample_quantiles() {
+        // the mean is undefined, so check the sample median and
+        // interquartile range instead
+        let mut r = rng::seeded(0);
+        let n = try_create(3.0, 2.0);
+        let mut samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert_almost_eq!(samples.median(), 3.0, 0.05);
+        let iqr = samples.upper_quartile() - samples.lower_quartile();
+        assert_almost_eq!(iqr, 4.0, 0.1);
+        assert_almost_eq!(iqr, n.inverse_cdf(0.75) - n.inverse_cdf(0.25), 0.1);
+    }
 }
@@ -1,4 +1,4 @@
-use distribution::{Continuous, Univariate};
+use distribution::{internal, Continuous, Domain, Moments, Parametric, Support, Univariate};
 use function::gamma;
 use rand::distributions::Distribution;
 use rand::Rng;
@@ -69,6 +69,19 @@ impl Chi {
     }
 }
 
+impl Parametric for Chi {
+    /// Returns the `freedom` parameter of the chi distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("freedom", self.freedom)]
+    }
+
+    /// Constructs a chi distribution from its `freedom` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Chi> {
+        let p = internal::parameter_values(params, &["freedom"])?;
+        Chi::new(p[0])
+    }
+}
+
 impl Distribution<f64> for Chi {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         (0..self.freedom as i64)
@@ -130,6 +143,24 @@ impl Max<f64> for Chi {
     }
 }
 
+impl Domain for Chi {
+    /// Returns the support of the chi distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Chi {
     /// Returns the mean of the chi distribution
     ///
@@ -188,6 +219,8 @@ impl Variance<f64> for Chi {
     }
 }
 
+impl Dispersion for Chi {}
+
 impl Entropy<f64> for Chi {
     /// Returns the entropy of the chi distribution
     ///
@@ -317,6 +350,8 @@ impl Continuous<f64, f64> for Chi {
     }
 }
 
+impl Moments for Chi {}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
@@ -1,4 +1,7 @@
-use distribution::{Continuous, Gamma, Univariate};
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, Gamma, InverseCDF, Moments, Parametric,
+    Support, Univariate,
+};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -100,6 +103,19 @@ impl ChiSquared {
     }
 }
 
+impl Parametric for ChiSquared {
+    /// Returns the `freedom` parameter of the chi-squared distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("freedom", self.freedom)]
+    }
+
+    /// Constructs a chi-squared distribution from its `freedom` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<ChiSquared> {
+        let p = internal::parameter_values(params, &["freedom"])?;
+        ChiSquared::new(p[0])
+    }
+}
+
 impl Distribution<f64> for ChiSquared {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         Distribution::sample(&self.g, r)
@@ -153,6 +169,24 @@ impl Max<f64> for ChiSquared {
     }
 }
 
+impl Domain for ChiSquared {
+    /// Returns the support of the chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for ChiSquared {
     /// Returns the mean of the chi-squared distribution
     ///
@@ -196,6 +230,8 @@ impl Variance<f64> for ChiSquared {
     }
 }
 
+impl Dispersion for ChiSquared {}
+
 impl Entropy<f64> for ChiSquared {
     /// Returns the entropy of the chi-squared distribution
     ///
@@ -290,12 +326,68 @@ impl Continuous<f64, f64> for ChiSquared {
     }
 }
 
+impl Moments for ChiSquared {
+    /// Returns the `k`-th raw moment of the chi-squared distribution
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the closed form moments of the underlying gamma
+    /// distribution
+    fn raw_moment(&self, k: u32) -> f64 {
+        self.g.raw_moment(k)
+    }
+
+    /// Returns the `k`-th central moment of the chi-squared distribution
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the closed form moments of the underlying gamma
+    /// distribution
+    fn central_moment(&self, k: u32) -> f64 {
+        self.g.central_moment(k)
+    }
+}
+
+impl InverseCDF<f64> for ChiSquared {
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the inverse cumulative distribution function of the
+    /// underlying gamma distribution
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.g.inverse_cdf(x)
+    }
+}
+
+impl CheckedInverseCDF<f64> for ChiSquared {
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the inverse cumulative distribution function of the
+    /// underlying gamma distribution
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        self.g.checked_inverse_cdf(x)
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::Median;
-    use distribution::ChiSquared;
+    use distribution::{ChiSquared, InverseCDF};
     use distribution::internal::*;
 
     fn try_create(freedom: f64) -> ChiSquared {
@@ -336,4 +428,13 @@ mod test {
         test::check_continuous_distribution(&try_create(2.0), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(5.0), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        // two degrees of freedom has the closed form quantile -2ln(1 - p)
+        for &p in &[0.01f64, 0.25, 0.5, 0.75, 0.99] {
+            test_almost(2.0, -2.0 * (-p).ln_1p(), 1e-13, |x| x.inverse_cdf(p));
+        }
+        test_almost(1.0, 3.841458820694124, 1e-12, |x| x.inverse_cdf(0.95));
+    }
 }
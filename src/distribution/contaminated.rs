@@ -0,0 +1,383 @@
+use distribution::{Continuous, Univariate};
+use function::exponential;
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the contamination model `(1 - ε) * Clean + ε * Contaminant`,
+/// a mixture of two univariate continuous distributions where a fraction
+/// `ε` of the observations is drawn from the contaminating distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Cauchy, Contaminated, Normal};
+/// use statrs::statistics::Mean;
+///
+/// let clean = Normal::new(0.0, 1.0).unwrap();
+/// let outliers = Normal::new(10.0, 1.0).unwrap();
+/// let n = Contaminated::new(clean, outliers, 0.1).unwrap();
+/// assert_eq!(n.mean(), 1.0);
+///
+/// // heavy tailed contamination
+/// assert!(Contaminated::new(clean, Cauchy::new(0.0, 5.0).unwrap(), 0.05).is_ok());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Contaminated<D1, D2> {
+    clean: D1,
+    contaminant: D2,
+    epsilon: f64,
+}
+
+impl<D1, D2> Contaminated<D1, D2> {
+    /// Constructs a new contamination model drawing from `contaminant`
+    /// with probability `epsilon` and from `clean` otherwise
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `epsilon` is `NaN` or not in `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Contaminated, Normal};
+    ///
+    /// let clean = Normal::new(0.0, 1.0).unwrap();
+    /// let contaminant = Normal::new(0.0, 10.0).unwrap();
+    /// let mut result = Contaminated::new(clean, contaminant, 0.05);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Contaminated::new(clean, contaminant, 1.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(clean: D1, contaminant: D2, epsilon: f64) -> Result<Contaminated<D1, D2>> {
+        if epsilon.is_nan() || epsilon < 0.0 || epsilon > 1.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Contaminated {
+                clean: clean,
+                contaminant: contaminant,
+                epsilon: epsilon,
+            })
+        }
+    }
+
+    /// Returns the clean distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Contaminated, Normal};
+    ///
+    /// let clean = Normal::new(0.0, 1.0).unwrap();
+    /// let n = Contaminated::new(clean, Normal::new(0.0, 10.0).unwrap(), 0.05).unwrap();
+    /// assert_eq!(*n.clean(), clean);
+    /// ```
+    pub fn clean(&self) -> &D1 {
+        &self.clean
+    }
+
+    /// Returns the contaminating distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Contaminated, Normal};
+    ///
+    /// let contaminant = Normal::new(0.0, 10.0).unwrap();
+    /// let n = Contaminated::new(Normal::new(0.0, 1.0).unwrap(), contaminant, 0.05).unwrap();
+    /// assert_eq!(*n.contaminant(), contaminant);
+    /// ```
+    pub fn contaminant(&self) -> &D2 {
+        &self.contaminant
+    }
+
+    /// Returns the contamination fraction `ε`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Contaminated, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let n = Contaminated::new(n, n, 0.05).unwrap();
+    /// assert_eq!(n.epsilon(), 0.05);
+    /// ```
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    // weighs a value of the clean and the contaminating distribution,
+    // skipping a component with zero weight so that the result exactly
+    // reproduces the other component even where the skipped one is not
+    // finite
+    fn mix(&self, clean: f64, contaminant: f64) -> f64 {
+        if self.epsilon == 0.0 {
+            clean
+        } else if self.epsilon == 1.0 {
+            contaminant
+        } else {
+            (1.0 - self.epsilon) * clean + self.epsilon * contaminant
+        }
+    }
+}
+
+impl<D1, D2> Distribution<f64> for Contaminated<D1, D2>
+where
+    D1: Distribution<f64>,
+    D2: Distribution<f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        if r.gen::<f64>() < self.epsilon {
+            self.contaminant.sample(r)
+        } else {
+            self.clean.sample(r)
+        }
+    }
+}
+
+impl<D1, D2> Univariate<f64, f64> for Contaminated<D1, D2>
+where
+    D1: Univariate<f64, f64>,
+    D2: Univariate<f64, f64>,
+{
+    /// Calculates the cumulative distribution function for the
+    /// contamination model at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - ε) * F_1(x) + ε * F_2(x)
+    /// ```
+    ///
+    /// where `F_1` and `F_2` are the cdfs of the clean and the
+    /// contaminating distribution
+    fn cdf(&self, x: f64) -> f64 {
+        self.mix(self.clean.cdf(x), self.contaminant.cdf(x))
+    }
+}
+
+impl<D1, D2> Min<f64> for Contaminated<D1, D2>
+where
+    D1: Min<f64>,
+    D2: Min<f64>,
+{
+    /// Returns the minimum value in the domain of the contamination model
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min(min(X_1), min(X_2))
+    /// ```
+    fn min(&self) -> f64 {
+        self.clean.min().min(self.contaminant.min())
+    }
+}
+
+impl<D1, D2> Max<f64> for Contaminated<D1, D2>
+where
+    D1: Max<f64>,
+    D2: Max<f64>,
+{
+    /// Returns the maximum value in the domain of the contamination model
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// max(max(X_1), max(X_2))
+    /// ```
+    fn max(&self) -> f64 {
+        self.clean.max().max(self.contaminant.max())
+    }
+}
+
+impl<D1, D2> Mean<f64> for Contaminated<D1, D2>
+where
+    D1: Mean<f64>,
+    D2: Mean<f64>,
+{
+    /// Returns the mean of the contamination model
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - ε) * μ_1 + ε * μ_2
+    /// ```
+    fn mean(&self) -> f64 {
+        self.mix(self.clean.mean(), self.contaminant.mean())
+    }
+}
+
+impl<D1, D2> Variance<f64> for Contaminated<D1, D2>
+where
+    D1: Variance<f64>,
+    D2: Variance<f64>,
+{
+    /// Returns the variance of the contamination model
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - ε) * σ_1^2 + ε * σ_2^2 + ε * (1 - ε) * (μ_1 - μ_2)^2
+    /// ```
+    fn variance(&self) -> f64 {
+        let within = self.mix(self.clean.variance(), self.contaminant.variance());
+        if self.epsilon == 0.0 || self.epsilon == 1.0 {
+            within
+        } else {
+            let d = self.clean.mean() - self.contaminant.mean();
+            within + self.epsilon * (1.0 - self.epsilon) * d * d
+        }
+    }
+
+    /// Returns the standard deviation of the contamination model
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt((1 - ε) * σ_1^2 + ε * σ_2^2 + ε * (1 - ε) * (μ_1 - μ_2)^2)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl<D1, D2> Dispersion for Contaminated<D1, D2>
+where
+    D1: Variance<f64>,
+    D2: Variance<f64>,
+{
+}
+
+impl<D1, D2> Continuous<f64, f64> for Contaminated<D1, D2>
+where
+    D1: Continuous<f64, f64>,
+    D2: Continuous<f64, f64>,
+{
+    /// Calculates the probability density function for the contamination
+    /// model at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - ε) * f_1(x) + ε * f_2(x)
+    /// ```
+    ///
+    /// where `f_1` and `f_2` are the pdfs of the clean and the
+    /// contaminating distribution
+    fn pdf(&self, x: f64) -> f64 {
+        self.mix(self.clean.pdf(x), self.contaminant.pdf(x))
+    }
+
+    /// Calculates the log probability density function for the
+    /// contamination model at `x`, combining the log densities of the
+    /// components so that it stays finite where both densities underflow
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 - ε) * f_1(x) + ε * f_2(x))
+    /// ```
+    ///
+    /// where `f_1` and `f_2` are the pdfs of the clean and the
+    /// contaminating distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if self.epsilon == 0.0 {
+            return self.clean.ln_pdf(x);
+        }
+        if self.epsilon == 1.0 {
+            return self.contaminant.ln_pdf(x);
+        }
+        exponential::logsumexp(&[
+            (1.0 - self.epsilon).ln() + self.clean.ln_pdf(x),
+            self.epsilon.ln() + self.contaminant.ln_pdf(x),
+        ])
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{Cauchy, Contaminated, Continuous, Normal, Univariate};
+    use function::integration::adaptive_quadrature_infinite;
+    use rng;
+
+    fn try_create(epsilon: f64) -> Contaminated<Normal, Cauchy> {
+        let n = Contaminated::new(Normal::new(1.0, 2.0).unwrap(), Cauchy::new(0.0, 5.0).unwrap(), epsilon);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(0.0);
+        try_create(0.1);
+        try_create(1.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(Contaminated::new(n, n, -0.1).is_err());
+        assert!(Contaminated::new(n, n, 1.1).is_err());
+        assert!(Contaminated::new(n, n, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_no_contamination() {
+        let n = try_create(0.0);
+        let clean = Normal::new(1.0, 2.0).unwrap();
+        for &x in &[-100.0f64, -1.0, 0.0, 1.0, 3.5, 40.0] {
+            assert_eq!(n.pdf(x), clean.pdf(x));
+            assert_eq!(n.ln_pdf(x), clean.ln_pdf(x));
+            assert_eq!(n.cdf(x), clean.cdf(x));
+        }
+    }
+
+    #[test]
+    fn test_mixture() {
+        let n = try_create(0.2);
+        let (clean, contaminant) = (Normal::new(1.0, 2.0).unwrap(), Cauchy::new(0.0, 5.0).unwrap());
+        for &x in &[-10.0f64, 0.0, 2.5] {
+            assert_almost_eq!(n.pdf(x), 0.8 * clean.pdf(x) + 0.2 * contaminant.pdf(x), 1e-15);
+            assert_almost_eq!(n.ln_pdf(x), n.pdf(x).ln(), 1e-14);
+            assert_almost_eq!(n.cdf(x), 0.8 * clean.cdf(x) + 0.2 * contaminant.cdf(x), 1e-15);
+        }
+        // far in the tail only the contaminant contributes
+        assert!(n.ln_pdf(1e5).is_finite());
+        assert_almost_eq!(n.ln_pdf(1e5), 0.2f64.ln() + contaminant.ln_pdf(1e5), 1e-12);
+        assert_eq!(n.min(), f64::NEG_INFINITY);
+        assert_eq!(n.max(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let (a, b) = (Normal::new(1.0, 2.0).unwrap(), Normal::new(-4.0, 0.5).unwrap());
+        let n = Contaminated::new(a, b, 0.3).unwrap();
+        assert_almost_eq!(n.mean(), 0.7 * 1.0 + 0.3 * -4.0, 1e-15);
+        let second = 0.7 * (4.0 + 1.0) + 0.3 * (0.25 + 16.0);
+        assert_almost_eq!(n.variance(), second - n.mean() * n.mean(), 1e-14);
+        let total = adaptive_quadrature_infinite(|x| n.pdf(x), n.min(), n.max(), 1e-12);
+        assert_almost_eq!(total, 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let (a, b) = (Normal::new(0.0, 1.0).unwrap(), Normal::new(100.0, 1.0).unwrap());
+        let n = Contaminated::new(a, b, 0.1).unwrap();
+        let samples: Vec<f64> = (0..100000).map(|_| n.sample(&mut r)).collect();
+        let outliers = samples.iter().filter(|&&x| x > 50.0).count() as f64 / 100000.0;
+        assert_almost_eq!(outliers, 0.1, 5e-3);
+        // the standard error of the mean is sqrt(901 / 100000) ≈ 0.095
+        assert_almost_eq!((&samples).mean(), n.mean(), 0.3);
+    }
+}
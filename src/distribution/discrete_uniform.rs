@@ -1,4 +1,4 @@
-use distribution::{Discrete, Univariate};
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -53,6 +53,24 @@ impl DiscreteUniform {
     }
 }
 
+impl Parametric for DiscreteUniform {
+    /// Returns the `min` and `max` parameters of the
+    /// discrete uniform distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("min", self.min as f64), ("max", self.max as f64)]
+    }
+
+    /// Constructs a discrete uniform distribution from its `min` and `max`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<DiscreteUniform> {
+        let p = internal::parameter_values(params, &["min", "max"])?;
+        DiscreteUniform::new(
+            internal::i64_parameter(p[0])?,
+            internal::i64_parameter(p[1])?,
+        )
+    }
+}
+
 impl Distribution<f64> for DiscreteUniform {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         r.gen_range(self.min, self.max + 1) as f64
@@ -110,6 +128,22 @@ impl Max<i64> for DiscreteUniform {
     }
 }
 
+impl Domain for DiscreteUniform {
+    /// Returns the support of the discrete uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {min, min + 1, ..., max}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: self.min as f64,
+            hi: self.max as f64,
+        }
+    }
+}
+
 impl Mean<f64> for DiscreteUniform {
     /// Returns the mean of the discrete uniform distribution
     ///
@@ -148,6 +182,8 @@ impl Variance<f64> for DiscreteUniform {
     }
 }
 
+impl Dispersion for DiscreteUniform {}
+
 impl Entropy<f64> for DiscreteUniform {
     /// Returns the entropy of the discrete uniform distribution
     ///
@@ -0,0 +1,392 @@
+use distribution::{Discrete, Domain, Support, Univariate};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements a discrete distribution with finite support defined by an
+/// explicit probability mass for each value
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Discrete, EmpiricalDiscrete};
+/// use statrs::statistics::Mean;
+///
+/// let n = EmpiricalDiscrete::new(&[(-1, 1.0), (2, 3.0)]).unwrap();
+/// assert_eq!(n.pmf(2), 0.75);
+/// assert_eq!(n.mean(), 1.25);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmpiricalDiscrete {
+    values: Vec<i64>,
+    norm_pmf: Vec<f64>,
+    cdf: Vec<f64>,
+    alias: Vec<usize>,
+    threshold: Vec<f64>,
+}
+
+impl EmpiricalDiscrete {
+    /// Constructs a new discrete distribution where each `(value,
+    /// probability)` pair of `pmf` assigns the probability mass
+    /// `probability` to `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pmf` is empty, any probability is negative,
+    /// `NaN` or infinite, or the probabilities sum to 0
+    ///
+    /// # Note
+    ///
+    /// The probabilities do not need to be normalized. Masses given for
+    /// the same value are added together and values with a mass of 0 are
+    /// not part of the support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::EmpiricalDiscrete;
+    ///
+    /// let mut result = EmpiricalDiscrete::new(&[(0, 1.0), (5, 2.0)]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = EmpiricalDiscrete::new(&[(0, 1.0), (5, -2.0)]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(pmf: &[(i64, f64)]) -> Result<EmpiricalDiscrete> {
+        let probs: Vec<f64> = pmf.iter().map(|&(_, p)| p).collect();
+        if !super::internal::is_valid_multinomial(&probs, true)
+            || probs.iter().any(|p| p.is_infinite())
+        {
+            return Err(StatsError::BadParams);
+        }
+
+        let mut pairs: Vec<(i64, f64)> = pmf.iter().cloned().filter(|&(_, p)| p > 0.0).collect();
+        pairs.sort_by_key(|&(x, _)| x);
+        let mut values: Vec<i64> = Vec::with_capacity(pairs.len());
+        let mut masses: Vec<f64> = Vec::with_capacity(pairs.len());
+        for (x, p) in pairs {
+            if values.last() == Some(&x) {
+                *masses.last_mut().unwrap() += p;
+            } else {
+                values.push(x);
+                masses.push(p);
+            }
+        }
+
+        let sum: f64 = masses.iter().sum();
+        let norm_pmf: Vec<f64> = masses.iter().map(|p| p / sum).collect();
+        let mut cdf = Vec::with_capacity(norm_pmf.len());
+        let mut acc = 0.0;
+        for p in &norm_pmf {
+            acc += p;
+            cdf.push(acc);
+        }
+        let (alias, threshold) = alias_tables(&norm_pmf);
+        Ok(EmpiricalDiscrete {
+            values: values,
+            norm_pmf: norm_pmf,
+            cdf: cdf,
+            alias: alias,
+            threshold: threshold,
+        })
+    }
+
+    /// Returns the values with positive probability mass in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::EmpiricalDiscrete;
+    ///
+    /// let n = EmpiricalDiscrete::new(&[(3, 1.0), (1, 1.0), (2, 0.0)]).unwrap();
+    /// assert_eq!(n.values(), &[1, 3]);
+    /// ```
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+/// Builds the tables of Walker's alias method for sampling from `pmf` in
+/// constant time using Vose's algorithm. Column `i` is sampled as `i` with
+/// probability `threshold[i]` and as `alias[i]` otherwise
+fn alias_tables(pmf: &[f64]) -> (Vec<usize>, Vec<f64>) {
+    let n = pmf.len();
+    let mut scaled: Vec<f64> = pmf.iter().map(|p| p * n as f64).collect();
+    let mut alias: Vec<usize> = (0..n).collect();
+    let mut threshold = vec![1.0; n];
+    let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| scaled[i] < 1.0);
+    while let (Some(&l), Some(&g)) = (small.last(), large.last()) {
+        small.pop();
+        threshold[l] = scaled[l];
+        alias[l] = g;
+        scaled[g] -= 1.0 - scaled[l];
+        if scaled[g] < 1.0 {
+            large.pop();
+            small.push(g);
+        }
+    }
+    // the remaining columns are full up to rounding errors
+    (alias, threshold)
+}
+
+impl Distribution<f64> for EmpiricalDiscrete {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let i = r.gen_range(0, self.values.len());
+        if r.gen::<f64>() < self.threshold[i] {
+            self.values[i] as f64
+        } else {
+            self.values[self.alias[i]] as f64
+        }
+    }
+}
+
+impl Univariate<i64, f64> for EmpiricalDiscrete {
+    /// Calculates the cumulative distribution function for the empirical
+    /// discrete distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(p_j) for x_j <= x
+    /// ```
+    ///
+    /// where `x_j` are the values and `p_j` their normalized masses
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        let count = self.values.iter().take_while(|&&v| v as f64 <= x).count();
+        if count == 0 {
+            0.0
+        } else if count == self.values.len() {
+            1.0
+        } else {
+            self.cdf[count - 1]
+        }
+    }
+}
+
+impl Min<i64> for EmpiricalDiscrete {
+    /// Returns the smallest value with positive probability mass
+    fn min(&self) -> i64 {
+        self.values[0]
+    }
+}
+
+impl Max<i64> for EmpiricalDiscrete {
+    /// Returns the largest value with positive probability mass
+    fn max(&self) -> i64 {
+        self.values[self.values.len() - 1]
+    }
+}
+
+impl Domain for EmpiricalDiscrete {
+    /// Returns the support of the empirical discrete distribution, the
+    /// values with positive probability mass
+    fn support(&self) -> Support {
+        Support::DiscreteSet(self.values.iter().map(|&x| x as f64).collect())
+    }
+}
+
+impl Mean<f64> for EmpiricalDiscrete {
+    /// Returns the mean of the empirical discrete distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(x_j * p_j)
+    /// ```
+    fn mean(&self) -> f64 {
+        self.values
+            .iter()
+            .zip(self.norm_pmf.iter())
+            .map(|(&x, p)| x as f64 * p)
+            .sum()
+    }
+}
+
+impl Variance<f64> for EmpiricalDiscrete {
+    /// Returns the variance of the empirical discrete distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(p_j * (x_j - μ)^2)
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn variance(&self) -> f64 {
+        let mu = self.mean();
+        self.values
+            .iter()
+            .zip(self.norm_pmf.iter())
+            .map(|(&x, p)| {
+                let d = x as f64 - mu;
+                p * d * d
+            })
+            .sum()
+    }
+
+    /// Returns the standard deviation of the empirical discrete
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(sum(p_j * (x_j - μ)^2))
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for EmpiricalDiscrete {}
+
+impl Entropy<f64> for EmpiricalDiscrete {
+    /// Returns the entropy of the empirical discrete distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -sum(p_j * ln(p_j))
+    /// ```
+    fn entropy(&self) -> f64 {
+        -self.norm_pmf.iter().map(|p| p * p.ln()).sum::<f64>()
+    }
+}
+
+impl Mode<i64> for EmpiricalDiscrete {
+    /// Returns the mode of the empirical discrete distribution, the
+    /// smallest of the values with the largest probability mass
+    fn mode(&self) -> i64 {
+        let mut best = 0;
+        for (i, &p) in self.norm_pmf.iter().enumerate() {
+            if p > self.norm_pmf[best] {
+                best = i;
+            }
+        }
+        self.values[best]
+    }
+}
+
+impl Discrete<i64, f64> for EmpiricalDiscrete {
+    /// Calculates the probability mass function for the empirical discrete
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p_j if x = x_j, 0 otherwise
+    /// ```
+    fn pmf(&self, x: i64) -> f64 {
+        match self.values.binary_search(&x) {
+            Ok(i) => self.norm_pmf[i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Calculates the log probability mass function for the empirical
+    /// discrete distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(p_j) if x = x_j, -INF otherwise
+    /// ```
+    fn ln_pmf(&self, x: i64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{Discrete, Domain, EmpiricalDiscrete, Support, Univariate};
+    use rng;
+
+    fn try_create(pmf: &[(i64, f64)]) -> EmpiricalDiscrete {
+        let n = EmpiricalDiscrete::new(pmf);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(&[(0, 1.0)]);
+        try_create(&[(3, 0.5), (-2, 0.25), (7, 0.0)]);
+        try_create(&[(1, 1.0), (1, 2.0)]);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        assert!(EmpiricalDiscrete::new(&[]).is_err());
+        assert!(EmpiricalDiscrete::new(&[(0, 0.0)]).is_err());
+        assert!(EmpiricalDiscrete::new(&[(0, 1.0), (1, -0.5)]).is_err());
+        assert!(EmpiricalDiscrete::new(&[(0, 1.0), (1, f64::NAN)]).is_err());
+        assert!(EmpiricalDiscrete::new(&[(0, 1.0), (1, f64::INFINITY)]).is_err());
+    }
+
+    #[test]
+    fn test_pmf() {
+        let n = try_create(&[(5, 2.0), (-3, 1.0), (0, 0.0), (5, 1.0), (10, 4.0)]);
+        assert_eq!(n.values(), &[-3, 5, 10]);
+        assert_eq!(n.pmf(-3), 1.0 / 8.0);
+        assert_eq!(n.pmf(5), 3.0 / 8.0);
+        assert_eq!(n.pmf(10), 4.0 / 8.0);
+        assert_eq!(n.pmf(0), 0.0);
+        assert_eq!(n.pmf(11), 0.0);
+        assert_eq!(n.ln_pmf(10), 0.5f64.ln());
+        assert_eq!(n.ln_pmf(0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = try_create(&[(5, 3.0), (-3, 1.0), (10, 4.0)]);
+        assert_eq!(n.cdf(-4.0), 0.0);
+        assert_eq!(n.cdf(-3.0), 1.0 / 8.0);
+        assert_eq!(n.cdf(4.9), 1.0 / 8.0);
+        assert_eq!(n.cdf(5.0), 4.0 / 8.0);
+        assert_eq!(n.cdf(10.0), 1.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+        assert!(n.cdf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_moments() {
+        let pmf = [(5, 3.0), (-3, 1.0), (10, 4.0)];
+        let n = try_create(&pmf);
+        let mean = pmf.iter().map(|&(x, p)| x as f64 * p / 8.0).sum::<f64>();
+        let variance = pmf.iter().map(|&(x, p)| (x as f64 - mean).powi(2) * p / 8.0).sum::<f64>();
+        assert_almost_eq!(n.mean(), mean, 1e-15);
+        assert_almost_eq!(n.variance(), variance, 1e-13);
+        assert_almost_eq!(n.std_dev(), variance.sqrt(), 1e-14);
+        assert_eq!(n.mode(), 10);
+        assert_eq!(n.min(), -3);
+        assert_eq!(n.max(), 10);
+        assert_eq!(try_create(&[(1, 1.0), (2, 1.0)]).mode(), 1);
+        assert_almost_eq!(try_create(&[(1, 1.0), (2, 1.0)]).entropy(), 2f64.ln(), 1e-15);
+        assert_eq!(n.support(), Support::DiscreteSet(vec![-3.0, 5.0, 10.0]));
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let n = try_create(&[(-2, 0.1), (0, 0.05), (3, 0.5), (4, 0.15), (9, 0.2)]);
+        let count = 200000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut r)).collect();
+        for &x in n.values() {
+            let observed = samples.iter().filter(|&&s| s == x as f64).count() as f64;
+            let expected = n.pmf(x) * count as f64;
+            assert!((observed - expected).abs() < 5.0 * expected.sqrt());
+        }
+        assert!(samples.iter().all(|&s| n.pmf(s as i64) > 0.0));
+    }
+}
@@ -1,4 +1,4 @@
-use distribution::{Continuous, Gamma, Univariate};
+use distribution::{internal, Continuous, Domain, Gamma, Moments, Parametric, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -79,6 +79,19 @@ impl Erlang {
     }
 }
 
+impl Parametric for Erlang {
+    /// Returns the `shape` and `rate` parameters of the erlang distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape", self.shape() as f64), ("rate", self.rate())]
+    }
+
+    /// Constructs an erlang distribution from its `shape` and `rate` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Erlang> {
+        let p = internal::parameter_values(params, &["shape", "rate"])?;
+        Erlang::new(internal::u64_parameter(p[0])?, p[1])
+    }
+}
+
 impl Distribution<f64> for Erlang {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         Distribution::sample(&self.g, r)
@@ -133,6 +146,19 @@ impl Max<f64> for Erlang {
     }
 }
 
+impl Domain for Erlang {
+    /// Returns the support of the erlang distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        self.g.support()
+    }
+}
+
 impl Mean<f64> for Erlang {
     /// Returns the mean of the erlang distribution
     ///
@@ -181,6 +207,8 @@ impl Variance<f64> for Erlang {
     }
 }
 
+impl Dispersion for Erlang {}
+
 impl Entropy<f64> for Erlang {
     /// Returns the entropy of the erlang distribution
     ///
@@ -273,6 +301,28 @@ impl Continuous<f64, f64> for Erlang {
     }
 }
 
+impl Moments for Erlang {
+    /// Returns the `k`-th raw moment of the erlang distribution
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the closed form moments of the underlying gamma
+    /// distribution
+    fn raw_moment(&self, k: u32) -> f64 {
+        self.g.raw_moment(k)
+    }
+
+    /// Returns the `k`-th central moment of the erlang distribution
+    ///
+    /// # Remarks
+    ///
+    /// Delegates to the closed form moments of the underlying gamma
+    /// distribution
+    fn central_moment(&self, k: u32) -> f64 {
+        self.g.central_moment(k)
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
@@ -1,4 +1,7 @@
-use distribution::{ziggurat, Continuous, Univariate};
+use distribution::{
+    internal, ziggurat, Continuous, Domain, Moments, Parametric, Support, Univariate,
+};
+use function::factorial;
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -68,6 +71,19 @@ impl Exponential {
     }
 }
 
+impl Parametric for Exponential {
+    /// Returns the `rate` parameter of the exponential distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("rate", self.rate)]
+    }
+
+    /// Constructs an exponential distribution from its `rate` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Exponential> {
+        let p = internal::parameter_values(params, &["rate"])?;
+        Exponential::new(p[0])
+    }
+}
+
 impl Distribution<f64> for Exponential {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         ziggurat::sample_exp_1(r) / self.rate
@@ -89,7 +105,26 @@ impl Univariate<f64, f64> for Exponential {
         if x < 0.0 {
             0.0
         } else {
-            1.0 - (-self.rate * x).exp()
+            -(-self.rate * x).exp_m1()
+        }
+    }
+
+    /// Calculates the log of the survival function for the exponential
+    /// distribution at `x`, which is exact even where the survival function
+    /// underflows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -λx
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -self.rate * x
         }
     }
 }
@@ -122,6 +157,24 @@ impl Max<f64> for Exponential {
     }
 }
 
+impl Domain for Exponential {
+    /// Returns the support of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Exponential {
     /// Returns the mean of the exponential distribution
     ///
@@ -165,6 +218,8 @@ impl Variance<f64> for Exponential {
     }
 }
 
+impl Dispersion for Exponential {}
+
 impl Entropy<f64> for Exponential {
     /// Returns the entropy of the exponential distribution
     ///
@@ -259,13 +314,85 @@ impl Continuous<f64, f64> for Exponential {
     }
 }
 
+impl Moments for Exponential {
+    /// Returns the `k`-th raw moment of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k! / λ^k
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn raw_moment(&self, k: u32) -> f64 {
+        factorial::factorial(u64::from(k)) / self.rate.powi(k as i32)
+    }
+
+    /// Returns the `k`-th central moment of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// !k / λ^k
+    /// ```
+    ///
+    /// where `!k` is the number of derangements of `k` elements and `λ` is
+    /// the rate
+    fn central_moment(&self, k: u32) -> f64 {
+        // !k = (k - 1) * (!(k - 1) + !(k - 2)) with !0 = 1 and !1 = 0
+        if k == 0 {
+            return 1.0;
+        }
+        let (mut prev, mut cur) = (1.0, 0.0);
+        for i in 2..k + 1 {
+            let next = f64::from(i - 1) * (cur + prev);
+            prev = cur;
+            cur = next;
+        }
+        cur / self.rate.powi(k as i32)
+    }
+}
+
+/// Evaluates the probability density function of the exponential
+/// distribution at `x` for every rate in `rates`, without constructing an
+/// `Exponential` for each of them
+///
+/// # Remarks
+///
+/// Entries for rates that `Exponential::new` would reject are `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{exponential_pdf_grid, Continuous, Exponential};
+///
+/// let grid = exponential_pdf_grid(2.0, &[0.5, 1.0, 2.0]);
+/// assert_eq!(grid[1], Exponential::new(1.0).unwrap().pdf(2.0));
+/// ```
+pub fn exponential_pdf_grid(x: f64, rates: &[f64]) -> Vec<f64> {
+    rates
+        .iter()
+        .map(|&rate| {
+            if rate.is_nan() || rate <= 0.0 {
+                f64::NAN
+            } else if x < 0.0 {
+                0.0
+            } else {
+                rate * (-rate * x).exp()
+            }
+        })
+        .collect()
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Exponential};
+    use distribution::{exponential_pdf_grid, Univariate, Continuous, Moments, Exponential, Domain, Support};
     use distribution::internal::*;
+    use distribution::ziggurat_tables;
+    use rng;
 
     fn try_create(rate: f64) -> Exponential {
         let n = Exponential::new(rate);
@@ -340,6 +467,14 @@ mod test {
         test_case(10.0, 0.01, |x| x.variance());
     }
 
+    #[test]
+    fn test_dispersion() {
+        test_case(0.1, 1.0, |x| x.coefficient_of_variation());
+        test_case(1.0, 1.0, |x| x.coefficient_of_variation());
+        test_case(10.0, 1.0, |x| x.coefficient_of_variation());
+        test_almost(4.0, 0.25, 1e-15, |x| x.index_of_dispersion());
+    }
+
     #[test]
     fn test_std_dev() {
         test_case(0.1, 10.0, |x| x.std_dev());
@@ -455,6 +590,16 @@ mod test {
         test_case(f64::INFINITY, 1.0, |x| x.cdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_cdf_small_x() {
+        // 1 - e^(-x) loses all precision below the machine epsilon
+        test_almost(1.0, 1e-10, 1e-20, |x| x.cdf(1e-10));
+        test_case(1.0, -(-1e-10f64).exp_m1(), |x| x.cdf(1e-10));
+        test_case(1.0, 1e-20, |x| x.cdf(1e-20));
+        test_almost(2.0, 2e-300, 1e-310, |x| x.cdf(1e-300));
+        test_almost(1e-5, 9.9999999995e-11, 1e-24, |x| x.cdf(1e-5));
+    }
+
     #[test]
     fn test_neg_cdf() {
         test_case(0.1, 0.0, |x| x.cdf(-1.0));
@@ -466,4 +611,91 @@ mod test {
         test::check_continuous_distribution(&try_create(1.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(2.5), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_log_likelihood_censored() {
+        use distribution::CensoredLikelihood;
+
+        let rate = 0.7;
+        let n = try_create(rate);
+        let observed = [0.2, 1.3, 2.9, 0.45];
+        let censored = [1.0, 3.5, 0.1];
+        // ln(λ) - λx per event and ln(e^(-λc)) = -λc per censored observation
+        let expected = observed.iter().map(|x| rate.ln() - rate * x).sum::<f64>()
+            - censored.iter().map(|c| rate * c).sum::<f64>();
+        assert_almost_eq!(n.log_likelihood_censored(&observed, &censored), expected, 1e-12);
+        assert_almost_eq!(n.log_likelihood_censored(&observed, &[]), observed.iter().map(|&x| n.ln_pdf(x)).sum::<f64>(), 1e-12);
+        assert_eq!(n.log_likelihood_censored(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(2.5);
+        assert_almost_eq!(n.central_moment(2), n.variance(), 1e-15);
+        assert_almost_eq!(n.raw_moment(3), 6.0 / 15.625, 1e-15);
+        assert_almost_eq!(n.central_moment(3), 2.0 / 15.625, 1e-15);
+        assert_almost_eq!(n.central_moment(4), 9.0 / 39.0625, 1e-15);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        for &rate in &[0.5f64, 1.0, 4.0] {
+            let n = try_create(rate);
+            let samples: Vec<f64> = (0..200000).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x.is_finite()));
+            assert_almost_eq!((&samples).mean() * rate, 1.0, 0.01);
+            assert_almost_eq!((&samples).variance() * rate * rate, 1.0, 0.03);
+            // the tail beyond the base strip of the ziggurat is sampled
+            // separately, compare its mass against the analytic survival
+            let r0 = ziggurat_tables::ZIG_EXP_R / rate;
+            let tail = samples.iter().filter(|&&x| x > r0).count() as f64 / samples.len() as f64;
+            assert_almost_eq!(tail, (-rate * r0).exp(), 1.5e-4);
+        }
+    }
+
+    #[test]
+    fn test_support() {
+        let s = try_create(2.0).support();
+        assert_eq!(s, Support::Continuous { lo: 0.0, hi: f64::INFINITY, lo_closed: true, hi_closed: false });
+        assert!(s.contains(0.0));
+        assert!(s.contains(1.5));
+        assert!(!s.contains(-1e-300));
+        assert_eq!(s.bounds(), (0.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_exponential_pdf_grid() {
+        let rates = [0.1, 1.0, 3.5, f64::INFINITY];
+        for &x in &[-1.0f64, 0.0, 0.5, 20.0] {
+            let grid = exponential_pdf_grid(x, &rates);
+            for (i, &rate) in rates.iter().enumerate() {
+                let expected = try_create(rate).pdf(x);
+                assert!(grid[i] == expected || grid[i].is_nan() && expected.is_nan());
+            }
+        }
+        let invalid = exponential_pdf_grid(1.0, &[0.0, -1.0, f64::NAN]);
+        assert!(invalid.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let n = try_create(2.0);
+        assert_eq!(n.ln_sf(-1.0), 0.0);
+        assert_eq!(n.ln_sf(0.0), 0.0);
+        assert_eq!(n.ln_sf(1.5), -3.0);
+        assert_eq!(n.ln_sf(1e4), -2e4);
+        assert_eq!(n.ln_sf(f64::INFINITY), f64::NEG_INFINITY);
+        assert_almost_eq!(n.ln_cdf(1.5), n.cdf(1.5).ln(), 1e-15);
+    }
+
+    #[test]
+    fn test_log_likelihood_censored_far_tail() {
+        use distribution::CensoredLikelihood;
+        let n = try_create(1.0);
+        assert_eq!(n.log_likelihood_censored(&[], &[1000.0]), -1000.0);
+        assert_eq!(n.log_likelihood_censored(&[1.0], &[1000.0, 2.0]), -1003.0);
+    }
 }
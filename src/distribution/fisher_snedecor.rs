@@ -1,4 +1,4 @@
-use distribution::{Continuous, Univariate};
+use distribution::{internal, Continuous, Domain, Parametric, Support, Univariate};
 use function::beta;
 use rand::distributions::Distribution;
 use rand::Rng;
@@ -93,6 +93,21 @@ impl FisherSnedecor {
     }
 }
 
+impl Parametric for FisherSnedecor {
+    /// Returns the `freedom_1` and `freedom_2` parameters of the
+    /// fisher-snedecor distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("freedom_1", self.freedom_1), ("freedom_2", self.freedom_2)]
+    }
+
+    /// Constructs a fisher-snedecor distribution from its `freedom_1` and
+    /// `freedom_2` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<FisherSnedecor> {
+        let p = internal::parameter_values(params, &["freedom_1", "freedom_2"])?;
+        FisherSnedecor::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for FisherSnedecor {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         (super::gamma::sample_unchecked(r, self.freedom_1 / 2.0, 0.5) * self.freedom_2)
@@ -159,6 +174,24 @@ impl Max<f64> for FisherSnedecor {
     }
 }
 
+impl Domain for FisherSnedecor {
+    /// Returns the support of the fisher-snedecor distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for FisherSnedecor {
     /// Returns the mean of the fisher-snedecor distribution
     ///
@@ -255,6 +288,26 @@ impl Variance<f64> for FisherSnedecor {
     }
 }
 
+impl Dispersion for FisherSnedecor {
+    /// Returns the coefficient of variation of the distribution, or `NaN`
+    /// if its mean is zero or its mean or variance is undefined
+    fn coefficient_of_variation(&self) -> f64 {
+        match (self.checked_mean(), self.checked_std_dev()) {
+            (Ok(mean), Ok(std_dev)) if mean != 0.0 && mean.is_finite() => std_dev / mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Returns the index of dispersion of the distribution, or `NaN` if its
+    /// mean is zero or its mean or variance is undefined
+    fn index_of_dispersion(&self) -> f64 {
+        match (self.checked_mean(), self.checked_variance()) {
+            (Ok(mean), Ok(variance)) if mean != 0.0 && mean.is_finite() => variance / mean,
+            _ => f64::NAN,
+        }
+    }
+}
+
 impl CheckedVariance<f64> for FisherSnedecor {
     /// Returns the variance of the fisher-snedecor distribution
     ///
@@ -524,6 +577,13 @@ mod test {
         assert_almost_eq!(expected, x, acc);
     }
 
+    fn test_is_nan<F>(freedom_1: f64, freedom_2: f64, eval: F)
+        where F: Fn(FisherSnedecor) -> f64
+    {
+        let x = get_value(freedom_1, freedom_2, eval);
+        assert!(x.is_nan());
+    }
+
     #[test]
     fn test_create() {
         create_case(0.1, 0.1);
@@ -614,6 +674,18 @@ mod test {
         assert!(n.checked_std_dev().is_err());
     }
 
+    #[test]
+    fn test_dispersion() {
+        test_almost(1.0, 10.0, 4.6875f64.sqrt() / 1.25, 1e-15, |x| x.coefficient_of_variation());
+        test_almost(1.0, 10.0, 3.75, 1e-15, |x| x.index_of_dispersion());
+        // the variance is undefined
+        test_is_nan(1.0, 3.0, |x| x.coefficient_of_variation());
+        test_is_nan(1.0, 3.0, |x| x.index_of_dispersion());
+        // the mean is undefined
+        test_is_nan(1.0, 2.0, |x| x.coefficient_of_variation());
+        test_is_nan(1.0, 1.0, |x| x.index_of_dispersion());
+    }
+
     #[test]
     fn test_skewness() {
         test_almost(0.1, 10.0, 15.78090735784977089658, 1e-14, |x| x.skewness());
@@ -1,6 +1,9 @@
-use distribution::{Continuous, Univariate};
-use function::gamma;
-use rand::distributions::Distribution;
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Moments, Parametric, Support,
+    Univariate,
+};
+use function::{gamma, optimization};
+use rand::distributions::{Distribution, Open01};
 use rand::Rng;
 use statistics::*;
 use std::f64;
@@ -24,6 +27,8 @@ use {Result, StatsError};
 pub struct Gamma {
     shape: f64,
     rate: f64,
+    gamma_shape: f64,
+    ln_gamma_shape: f64,
 }
 
 impl Gamma {
@@ -54,10 +59,46 @@ impl Gamma {
             (_, _, false) => Ok(Gamma {
                 shape: shape,
                 rate: rate,
+                gamma_shape: gamma::gamma(shape),
+                ln_gamma_shape: gamma::ln_gamma(shape),
             }),
         }
     }
 
+    /// Constructs a new gamma distribution with a shape (k) of `shape` and a
+    /// scale (θ) of `scale`
+    ///
+    /// # Remarks
+    ///
+    /// The scale is the reciprocal of the rate, i.e. this is equivalent to
+    /// `Gamma::new(shape, 1.0 / scale)`. This is the parameterization used by
+    /// e.g. NumPy, where `Gamma::new` would interpret the scale as a rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `scale` are `NaN`.
+    /// Also returns an error if `shape <= 0.0`, `scale <= 0.0` or
+    /// `scale == INF`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let n = Gamma::from_shape_scale(3.0, 2.0).unwrap();
+    /// assert_eq!(n.rate(), 0.5);
+    ///
+    /// let result = Gamma::from_shape_scale(3.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_shape_scale(shape: f64, scale: f64) -> Result<Gamma> {
+        if scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Gamma::new(shape, 1.0 / scale)
+        }
+    }
+
     /// Returns the shape (α) of the gamma distribution
     ///
     /// # Examples
@@ -85,6 +126,113 @@ impl Gamma {
     pub fn rate(&self) -> f64 {
         self.rate
     }
+
+    /// Returns the scale (θ) of the gamma distribution, the reciprocal of
+    /// the rate (β)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let n = Gamma::new(3.0, 4.0).unwrap();
+    /// assert_eq!(n.scale(), 0.25);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        1.0 / self.rate
+    }
+
+    /// Returns the distribution of the sum of independent gamma random
+    /// variables distributed according to `self` and `other`, which is
+    /// gamma distributed when both share the same rate
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rates of `self` and `other` differ
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Gamma(α_1 + α_2, β)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let a = Gamma::new(3.0, 2.0).unwrap();
+    /// let b = Gamma::new(1.5, 2.0).unwrap();
+    /// let n = a.add_same_rate(&b).unwrap();
+    /// assert_eq!(n.shape(), 4.5);
+    /// assert_eq!(n.rate(), 2.0);
+    ///
+    /// let c = Gamma::new(1.5, 1.0).unwrap();
+    /// assert!(a.add_same_rate(&c).is_err());
+    /// ```
+    pub fn add_same_rate(&self, other: &Gamma) -> Result<Gamma> {
+        if self.rate != other.rate {
+            Err(StatsError::BadParams)
+        } else {
+            Gamma::new(self.shape + other.shape, self.rate)
+        }
+    }
+
+    /// Returns the Kullback-Leibler divergence `KL(self || other)` of the
+    /// gamma distribution `other` from `self`
+    ///
+    /// # Remarks
+    ///
+    /// A gamma distribution with an infinite rate is degenerate, so the
+    /// divergence is infinite if either rate is infinite unless both
+    /// distributions are identical
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α_p - α_q) * ψ(α_p) - ln(Γ(α_p)) + ln(Γ(α_q))
+    ///     + α_q * (ln(β_p) - ln(β_q)) + α_p * (β_q - β_p) / β_p
+    /// ```
+    ///
+    /// where `α_p`, `β_p` are the shape and rate of `self`, `α_q`, `β_q` the
+    /// shape and rate of `other`, `Γ` is the gamma function and `ψ` is the
+    /// digamma function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gamma;
+    ///
+    /// let p = Gamma::new(2.0, 1.0).unwrap();
+    /// let q = Gamma::new(3.0, 2.0).unwrap();
+    /// assert_eq!(p.kl_divergence(&p), 0.0);
+    /// assert!((p.kl_divergence(&q) - 0.190921303781642242).abs() < 1e-14);
+    /// ```
+    pub fn kl_divergence(&self, other: &Gamma) -> f64 {
+        if self == other {
+            0.0
+        } else if self.rate == f64::INFINITY || other.rate == f64::INFINITY {
+            f64::INFINITY
+        } else {
+            (self.shape - other.shape) * gamma::digamma(self.shape) - self.ln_gamma_shape
+                + other.ln_gamma_shape
+                + other.shape * (self.rate.ln() - other.rate.ln())
+                + self.shape * (other.rate - self.rate) / self.rate
+        }
+    }
+}
+
+impl Parametric for Gamma {
+    /// Returns the `shape` and `rate` parameters of the gamma distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape", self.shape), ("rate", self.rate)]
+    }
+
+    /// Constructs a gamma distribution from its `shape` and `rate` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Gamma> {
+        let p = internal::parameter_values(params, &["shape", "rate"])?;
+        Gamma::new(p[0], p[1])
+    }
 }
 
 impl Distribution<f64> for Gamma {
@@ -151,6 +299,24 @@ impl Max<f64> for Gamma {
     }
 }
 
+impl Domain for Gamma {
+    /// Returns the support of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Gamma {
     /// Returns the mean of the gamma distribution
     ///
@@ -207,6 +373,8 @@ impl Variance<f64> for Gamma {
     }
 }
 
+impl Dispersion for Gamma {}
+
 impl Entropy<f64> for Gamma {
     /// Returns the entropy of the gamma distribution
     ///
@@ -223,7 +391,7 @@ impl Entropy<f64> for Gamma {
             0.0
         } else {
             self.shape - self.rate.ln()
-                + gamma::ln_gamma(self.shape)
+                + self.ln_gamma_shape
                 + (1.0 - self.shape) * gamma::digamma(self.shape)
         }
     }
@@ -285,18 +453,13 @@ impl Continuous<f64, f64> for Gamma {
     ///
     /// where `α` is the shape, `β` is the rate, and `Γ` is the gamma function
     fn pdf(&self, x: f64) -> f64 {
-        if x < 0.0 {
-            0.0
-        } else if self.shape == 1.0 {
-            self.rate * (-self.rate * x).exp()
-        } else if self.shape > 160.0 {
-            self.ln_pdf(x).exp()
-        } else if x == f64::INFINITY {
-            0.0
-        } else {
-            self.rate.powf(self.shape) * x.powf(self.shape - 1.0) * (-self.rate * x).exp()
-                / gamma::gamma(self.shape)
-        }
+        pdf_unchecked(
+            x,
+            self.shape,
+            self.rate,
+            self.gamma_shape,
+            self.ln_gamma_shape,
+        )
     }
 
     /// Calculates the log probability density function for the gamma
@@ -316,18 +479,159 @@ impl Continuous<f64, f64> for Gamma {
     ///
     /// where `α` is the shape, `β` is the rate, and `Γ` is the gamma function
     fn ln_pdf(&self, x: f64) -> f64 {
-        if x < 0.0 {
-            f64::NEG_INFINITY
-        } else if self.shape == 1.0 {
-            self.rate.ln() - self.rate * x
-        } else if x == f64::INFINITY {
-            f64::NEG_INFINITY
+        ln_pdf_unchecked(x, self.shape, self.rate, self.ln_gamma_shape)
+    }
+}
+
+impl Moments for Gamma {
+    /// Returns the `k`-th raw moment of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// α * (α + 1) * ... * (α + k - 1) / β^k
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn raw_moment(&self, k: u32) -> f64 {
+        (0..k).fold(1.0, |acc, i| acc * (self.shape + f64::from(i))) / self.rate.powi(k as i32)
+    }
+
+    /// Returns the `k`-th central moment of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(binomial(k, j) * (-μ)^(k - j) * E[X^j]) for j in 0..=k
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn central_moment(&self, k: u32) -> f64 {
+        internal::shift_moment(|j| self.raw_moment(j), -self.mean(), k)
+    }
+}
+
+impl InverseCDF<f64> for Gamma {
+    /// Calculates the inverse cumulative distribution function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution, the root of `cdf(y) - x` is found
+    /// numerically with `function::optimization::find_root`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Gamma {
+    /// Calculates the inverse cumulative distribution function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution, the root of `cdf(y) - x` is found
+    /// numerically with `function::optimization::find_root`
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            return Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0));
+        }
+        if x == 0.0 {
+            return Ok(0.0);
+        }
+        if x == 1.0 {
+            return Ok(f64::INFINITY);
+        }
+        // grow the bracket until it contains the quantile
+        let mut upper = self.mean() + self.std_dev();
+        while self.cdf(upper) < x {
+            if upper.is_infinite() {
+                return Err(StatsError::ComputationFailedToConverge);
+            }
+            upper *= 2.0;
+        }
+        optimization::checked_find_root(|y| self.cdf(y) - x, 0.0, upper, 0.0)
+    }
+}
+
+/// Evaluates the probability density function of the gamma distribution at
+/// `x` for every combination of a shape in `shapes` and a rate in `rates`,
+/// computing the gamma function only once per shape instead of
+/// constructing a `Gamma` for each combination
+///
+/// # Remarks
+///
+/// The densities are returned in row-major order, i.e. the entry at index
+/// `i * rates.len() + j` is the density for `shapes[i]` and `rates[j]`.
+/// Entries for parameters that `Gamma::new` would reject are `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{gamma_pdf_grid, Continuous, Gamma};
+///
+/// let grid = gamma_pdf_grid(1.5, &[1.0, 2.0, 3.0], &[0.5, 2.0]);
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[3], Gamma::new(2.0, 2.0).unwrap().pdf(1.5));
+/// ```
+pub fn gamma_pdf_grid(x: f64, shapes: &[f64], rates: &[f64]) -> Vec<f64> {
+    let mut grid = Vec::with_capacity(shapes.len() * rates.len());
+    for &shape in shapes {
+        let valid_shape = !shape.is_nan() && shape > 0.0;
+        let (gamma_shape, ln_gamma_shape) = if valid_shape {
+            (gamma::gamma(shape), gamma::ln_gamma(shape))
         } else {
-            self.shape * self.rate.ln() + (self.shape - 1.0) * x.ln()
-                - self.rate * x
-                - gamma::ln_gamma(self.shape)
+            (f64::NAN, f64::NAN)
+        };
+        for &rate in rates {
+            if !valid_shape || rate.is_nan() || rate <= 0.0 {
+                grid.push(f64::NAN);
+            } else {
+                grid.push(pdf_unchecked(x, shape, rate, gamma_shape, ln_gamma_shape));
+            }
         }
     }
+    grid
+}
+
+/// performs an unchecked pdf calculation for a gamma distribution with the
+/// given shape and rate at x, using the precomputed gamma function and log
+/// gamma function of the shape
+fn pdf_unchecked(x: f64, shape: f64, rate: f64, gamma_shape: f64, ln_gamma_shape: f64) -> f64 {
+    if x < 0.0 {
+        0.0
+    } else if shape == 1.0 {
+        rate * (-rate * x).exp()
+    } else if shape > 160.0 {
+        ln_pdf_unchecked(x, shape, rate, ln_gamma_shape).exp()
+    } else if x == f64::INFINITY {
+        0.0
+    } else {
+        rate.powf(shape) * x.powf(shape - 1.0) * (-rate * x).exp() / gamma_shape
+    }
+}
+
+/// performs an unchecked log(pdf) calculation for a gamma distribution with
+/// the given shape and rate at x, using the precomputed log gamma function
+/// of the shape
+fn ln_pdf_unchecked(x: f64, shape: f64, rate: f64, ln_gamma_shape: f64) -> f64 {
+    if x < 0.0 {
+        f64::NEG_INFINITY
+    } else if shape == 1.0 {
+        rate.ln() - rate * x
+    } else if x == f64::INFINITY {
+        f64::NEG_INFINITY
+    } else {
+        shape * rate.ln() + (shape - 1.0) * x.ln() - rate * x - ln_gamma_shape
+    }
 }
 
 /// Samples from a gamma distribution with a shape of `shape` and a
@@ -348,7 +652,8 @@ pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, shape: f64, rate: f64) -> f6
 
     let a = if shape < 1.0 { shape + 1.0 } else { shape };
     let afix = if shape < 1.0 {
-        r.gen::<f64>().powf(1.0 / shape)
+        let u: f64 = r.sample(Open01);
+        u.powf(1.0 / shape)
     } else {
         1.0
     };
@@ -368,7 +673,7 @@ pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, shape: f64, rate: f64) -> f6
         if u < 1.0 - 0.0331 * x * x {
             return afix * d * v / rate;
         }
-        if u.ln() < 0.5 * x + d * (1.0 - v - v.ln()) {
+        if u.ln() < 0.5 * x + d * (1.0 - v + v.ln()) {
             return afix * d * v / rate;
         }
     }
@@ -379,8 +684,10 @@ pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, shape: f64, rate: f64) -> f6
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Gamma};
+    use distribution::{gamma_pdf_grid, Univariate, Continuous, Moments, NumericalEntropy, Gamma, InverseCDF, CheckedInverseCDF};
     use distribution::internal::*;
+    use function::integration::adaptive_quadrature_infinite;
+    use rng;
 
     fn try_create(shape: f64, rate: f64) -> Gamma {
         let n = Gamma::new(shape, rate);
@@ -528,6 +835,24 @@ mod test {
         test_case(10.0, f64::INFINITY, 0.0, |x| x.pdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_pdf_matches_uncached_normalization() {
+        use function::gamma;
+        let shapes = [0.5, 1.5, 3.0, 10.0, 100.0];
+        let rates = [0.1, 1.0, 4.0];
+        for &shape in shapes.iter() {
+            for &rate in rates.iter() {
+                let n = try_create(shape, rate);
+                for &x in [0.5f64, 1.0, 2.5, 10.0].iter() {
+                    let pdf = rate.powf(shape) * x.powf(shape - 1.0) * (-rate * x).exp() / gamma::gamma(shape);
+                    let ln_pdf = shape * rate.ln() + (shape - 1.0) * x.ln() - rate * x - gamma::ln_gamma(shape);
+                    assert_eq!(pdf, n.pdf(x));
+                    assert_eq!(ln_pdf, n.ln_pdf(x));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_pdf_at_zero() {
         test_almost(1.0, 0.1, 0.1, 1e-10, |x| x.pdf(0.0));
@@ -572,4 +897,150 @@ mod test {
         test::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(9.0, 2.0), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(3.0, 2.0);
+        assert_almost_eq!(n.central_moment(2), n.variance(), 1e-14);
+        assert_almost_eq!(n.raw_moment(2), 3.0, 1e-14);
+        assert_almost_eq!(n.central_moment(3) / n.variance().powf(1.5), n.skewness(), 1e-13);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_case(2.0, 1.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(2.0, 1.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        for &p in &[1e-10f64, 0.01, 0.25, 0.5, 0.75, 0.99] {
+            // shape 1 is the exponential distribution with a closed form quantile
+            test_almost(1.0, 2.5, -(-p).ln_1p() / 2.5, 1e-13, |x| x.inverse_cdf(p));
+        }
+        for &p in &[0.01, 0.25, 0.5, 0.75, 0.99] {
+            for &(shape, rate) in &[(0.5, 1.0), (3.0, 0.1), (10.0, 10.0)] {
+                test_almost(shape, rate, p, 1e-13, |x| x.cdf(x.inverse_cdf(p)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_inverse_cdf_bad_input() {
+        let n = try_create(2.0, 1.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_from_shape_scale() {
+        for &(shape, scale) in &[(1.0, 1.0), (3.0, 2.0), (0.5, 0.1), (10.0, 7.5)] {
+            let n = Gamma::from_shape_scale(shape, scale).unwrap();
+            let m = try_create(shape, 1.0 / scale);
+            assert_eq!(n, m);
+            assert_eq!(n.shape(), shape);
+            assert_almost_eq!(n.scale(), scale, 1e-15);
+            for &x in &[0.1, 1.0, 2.5, 10.0] {
+                assert_eq!(n.pdf(x), m.pdf(x));
+                assert_eq!(n.ln_pdf(x), m.ln_pdf(x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_shape_scale_bad_params() {
+        assert!(Gamma::from_shape_scale(1.0, 0.0).is_err());
+        assert!(Gamma::from_shape_scale(1.0, -1.0).is_err());
+        assert!(Gamma::from_shape_scale(1.0, f64::NAN).is_err());
+        assert!(Gamma::from_shape_scale(1.0, f64::INFINITY).is_err());
+        assert!(Gamma::from_shape_scale(0.0, 1.0).is_err());
+        assert!(Gamma::from_shape_scale(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_numerical_entropy() {
+        for &(shape, rate) in &[(1.0, 0.1), (2.5, 1.0), (10.0, 10.0)] {
+            let n = try_create(shape, rate);
+            assert_almost_eq!(n.numerical_entropy(), n.entropy(), 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        for &(shape, rate) in &[(0.3, 2.0), (2.0, 0.5), (50.0, 3.0)] {
+            let n = try_create(shape, rate);
+            let samples: Vec<f64> = (0..100000).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x.is_finite()));
+            assert_almost_eq!((&samples).mean() / (shape / rate), 1.0, 0.02);
+            assert_almost_eq!((&samples).variance() / (shape / (rate * rate)), 1.0, 0.06);
+        }
+    }
+
+    #[test]
+    fn test_gamma_pdf_grid() {
+        let shapes = [0.5, 1.0, 3.0, 200.0];
+        let rates = [0.1, 1.0, 4.0];
+        for &x in &[-1.0f64, 0.0, 0.7, 5.0, 50.0, f64::INFINITY] {
+            let grid = gamma_pdf_grid(x, &shapes, &rates);
+            assert_eq!(grid.len(), 12);
+            for (i, &shape) in shapes.iter().enumerate() {
+                for (j, &rate) in rates.iter().enumerate() {
+                    let expected = try_create(shape, rate).pdf(x);
+                    let actual = grid[i * 3 + j];
+                    assert!(actual == expected || actual.is_nan() && expected.is_nan());
+                }
+            }
+        }
+        let invalid = gamma_pdf_grid(1.0, &[1.0, 0.0, f64::NAN], &[1.0, -1.0]);
+        assert!(!invalid[0].is_nan());
+        assert!(invalid[1..].iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_add_same_rate() {
+        let a = try_create(1.0, 0.5);
+        let b = try_create(2.5, 0.5);
+        let n = a.add_same_rate(&b).unwrap();
+        assert_eq!(n.shape(), 3.5);
+        assert_eq!(n.rate(), 0.5);
+        assert_eq!(n, b.add_same_rate(&a).unwrap());
+        assert_eq!(n, try_create(3.5, 0.5));
+        assert!(a.add_same_rate(&try_create(2.5, 0.6)).is_err());
+        assert!(a.add_same_rate(&try_create(1.0, f64::INFINITY)).is_err());
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        assert_almost_eq!(try_create(2.0, 1.0).kl_divergence(&try_create(3.0, 2.0)), 0.19092130378164224, 1e-14);
+        assert_almost_eq!(try_create(0.5, 2.0).kl_divergence(&try_create(1.5, 0.5)), 2.974804387141314, 1e-13);
+        assert_almost_eq!(try_create(10.0, 3.0).kl_divergence(&try_create(9.0, 2.5)), 0.02875535620942662, 1e-14);
+        let n = try_create(3.0, 2.0);
+        assert_eq!(n.kl_divergence(&n), 0.0);
+        assert!(try_create(3.0, 2.0).kl_divergence(&try_create(3.0, 2.5)) > 0.0);
+        let inf = try_create(10.0, f64::INFINITY);
+        assert_eq!(inf.kl_divergence(&inf), 0.0);
+        assert_eq!(inf.kl_divergence(&n), f64::INFINITY);
+        assert_eq!(n.kl_divergence(&inf), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_kl_divergence_numerical() {
+        for &(p, q) in &[((2.0, 1.0), (3.0, 2.0)), ((0.5, 2.0), (1.5, 0.5)), ((4.0, 0.5), (2.0, 0.25))] {
+            let p = try_create(p.0, p.1);
+            let q = try_create(q.0, q.1);
+            let numerical = adaptive_quadrature_infinite(
+                |x| {
+                    let ln_p = p.ln_pdf(x);
+                    if ln_p == f64::NEG_INFINITY {
+                        0.0
+                    } else {
+                        ln_p.exp() * (ln_p - q.ln_pdf(x))
+                    }
+                },
+                0.0,
+                f64::INFINITY,
+                1e-12,
+            );
+            assert_almost_eq!(p.kl_divergence(&q), numerical, 1e-9);
+        }
+    }
 }
@@ -0,0 +1,206 @@
+use distribution::internal;
+use distribution::InverseCDF;
+use function::erf;
+use rand::distributions::Distribution;
+use rand::Rng;
+use std::f64;
+use Result;
+
+/// Implements the [Gaussian
+/// copula](https://en.wikipedia.org/wiki/Copula_(probability_theory)#Gaussian_copula),
+/// the joint distribution of uniform marginals whose dependence is that of
+/// a multivariate normal distribution with a given correlation matrix
+///
+/// # Examples
+///
+/// ```
+/// # extern crate rand;
+/// # extern crate statrs;
+/// use statrs::distribution::{Beta, GaussianCopula, InverseCDF, Normal};
+///
+/// # fn main() {
+/// let c = GaussianCopula::new(&[1.0, 0.8, 0.8, 1.0]).unwrap();
+/// let mut r = rand::thread_rng();
+/// let b = Beta::new(2.0, 5.0).unwrap();
+/// let n = Normal::new(3.0, 1.0).unwrap();
+/// let marginals: [&dyn InverseCDF<f64>; 2] = [&b, &n];
+/// let sample = c.sample_marginals(&mut r, &marginals);
+/// assert_eq!(sample.len(), 2);
+/// assert!(sample[0] > 0.0 && sample[0] < 1.0);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussianCopula {
+    dim: usize,
+    correlation: Vec<f64>,
+    chol: Vec<f64>,
+}
+
+impl GaussianCopula {
+    /// Constructs a new Gaussian copula from a correlation matrix stored in
+    /// row-major order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `correlation` is empty, not square, contains
+    /// `NaN` or infinite entries, or is not a symmetric positive-definite
+    /// matrix with a unit diagonal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GaussianCopula;
+    ///
+    /// let mut result = GaussianCopula::new(&[1.0, 0.5, 0.5, 1.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GaussianCopula::new(&[1.0, 1.5, 1.5, 1.0]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(correlation: &[f64]) -> Result<GaussianCopula> {
+        let (dim, chol) = internal::correlation_cholesky(correlation)?;
+        Ok(GaussianCopula {
+            dim: dim,
+            correlation: correlation.to_vec(),
+            chol: chol,
+        })
+    }
+
+    /// Returns the number of dimensions of the copula
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GaussianCopula;
+    ///
+    /// let c = GaussianCopula::new(&[1.0, 0.5, 0.5, 1.0]).unwrap();
+    /// assert_eq!(c.dim(), 2);
+    /// ```
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the correlation matrix of the copula in row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GaussianCopula;
+    ///
+    /// let c = GaussianCopula::new(&[1.0, 0.5, 0.5, 1.0]).unwrap();
+    /// assert_eq!(c.correlation(), [1.0, 0.5, 0.5, 1.0]);
+    /// ```
+    pub fn correlation(&self) -> &[f64] {
+        &self.correlation
+    }
+
+    /// Draws a sample whose `i`th coordinate is distributed according to
+    /// `marginals[i]`, with the dependence between the coordinates given
+    /// by the copula
+    ///
+    /// # Panics
+    ///
+    /// If the number of marginals differs from the dimension of the copula
+    pub fn sample_marginals<R: Rng + ?Sized>(
+        &self,
+        r: &mut R,
+        marginals: &[&dyn InverseCDF<f64>],
+    ) -> Vec<f64> {
+        assert_eq!(marginals.len(), self.dim);
+        self.sample(r)
+            .into_iter()
+            .zip(marginals)
+            .map(|(u, d)| d.inverse_cdf(u))
+            .collect()
+    }
+}
+
+impl Distribution<Vec<f64>> for GaussianCopula {
+    /// Draws a vector of dependent uniforms by applying the standard normal
+    /// cdf to each coordinate of a correlated multivariate normal sample
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> Vec<f64> {
+        internal::sample_correlated_normals(r, &self.chol, self.dim)
+            .into_iter()
+            .map(|z| 0.5 * erf::erfc(-z / f64::consts::SQRT_2))
+            .collect()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Beta, GaussianCopula, InverseCDF, Normal};
+    use rng;
+
+    fn correlation(xs: &[f64], ys: &[f64]) -> f64 {
+        xs.iter().covariance(ys.iter()) / (xs.iter().std_dev() * ys.iter().std_dev())
+    }
+
+    #[test]
+    fn test_create() {
+        assert!(GaussianCopula::new(&[1.0]).is_ok());
+        assert!(GaussianCopula::new(&[1.0, -0.99, -0.99, 1.0]).is_ok());
+        assert!(GaussianCopula::new(&[1.0, 0.3, 0.1, 0.3, 1.0, 0.5, 0.1, 0.5, 1.0]).is_ok());
+        assert!(GaussianCopula::new(&[]).is_err());
+        assert!(GaussianCopula::new(&[1.0, 0.5, 0.5]).is_err());
+        assert!(GaussianCopula::new(&[1.0, 0.5, 0.2, 1.0]).is_err());
+        assert!(GaussianCopula::new(&[1.0, 1.0, 1.0, 1.0]).is_err());
+        assert!(GaussianCopula::new(&[1.0, f64::NAN, f64::NAN, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_sample_correlation() {
+        let mut r = rng::seeded(0);
+        let rho = 0.7;
+        let c = GaussianCopula::new(&[1.0, rho, rho, 1.0]).unwrap();
+        let samples: Vec<Vec<f64>> = (0..20000).map(|_| c.sample(&mut r)).collect();
+        let us: Vec<f64> = samples.iter().map(|s| s[0]).collect();
+        let vs: Vec<f64> = samples.iter().map(|s| s[1]).collect();
+        // the marginals are uniform
+        for u in &[&us, &vs] {
+            assert!(u.iter().all(|&x| x > 0.0 && x < 1.0));
+            assert_almost_eq!(u.iter().mean(), 0.5, 0.01);
+            assert_almost_eq!(u.iter().variance(), 1.0 / 12.0, 0.002);
+        }
+        // the pearson correlation of the uniforms is the spearman correlation
+        // of the copula
+        let spearman = 6.0 / f64::consts::PI * (rho / 2.0).asin();
+        assert_almost_eq!(correlation(&us, &vs), spearman, 0.01);
+    }
+
+    #[test]
+    fn test_sample_marginals() {
+        let mut r = rng::seeded(0);
+        let c = GaussianCopula::new(&[1.0, -0.5, 0.6, -0.5, 1.0, -0.2, 0.6, -0.2, 1.0]).unwrap();
+        let b = Beta::new(2.0, 5.0).unwrap();
+        let n = Normal::new(3.0, 1.5).unwrap();
+        let m = Normal::new(-1.0, 0.5).unwrap();
+        let marginals: [&dyn InverseCDF<f64>; 3] = [&b, &n, &m];
+        let samples: Vec<Vec<f64>> = (0..20000).map(|_| c.sample_marginals(&mut r, &marginals)).collect();
+        let xs: Vec<f64> = samples.iter().map(|s| s[0]).collect();
+        let ys: Vec<f64> = samples.iter().map(|s| s[1]).collect();
+        let zs: Vec<f64> = samples.iter().map(|s| s[2]).collect();
+        assert_almost_eq!(xs.iter().mean(), 2.0 / 7.0, 0.005);
+        assert_almost_eq!(xs.iter().variance(), 10.0 / 392.0, 0.001);
+        assert_almost_eq!(ys.iter().mean(), 3.0, 0.03);
+        assert_almost_eq!(ys.iter().std_dev(), 1.5, 0.03);
+        assert_almost_eq!(zs.iter().mean(), -1.0, 0.01);
+        assert_almost_eq!(zs.iter().std_dev(), 0.5, 0.01);
+        // normal marginals recover the correlation of the copula
+        assert_almost_eq!(correlation(&ys, &zs), -0.2, 0.02);
+        assert!(correlation(&xs, &ys) < -0.3);
+        assert!(correlation(&xs, &zs) > 0.4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_marginals_dimension_mismatch() {
+        let mut r = ::rand::thread_rng();
+        let c = GaussianCopula::new(&[1.0, 0.5, 0.5, 1.0]).unwrap();
+        let n = Normal::new(0.0, 1.0).unwrap();
+        c.sample_marginals(&mut r, &[&n]);
+    }
+}
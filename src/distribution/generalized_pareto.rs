@@ -0,0 +1,861 @@
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Parametric, Support, Univariate,
+};
+use function::optimization;
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [generalized
+/// Pareto](https://en.wikipedia.org/wiki/Generalized_Pareto_distribution)
+/// distribution
+///
+/// # Remarks
+///
+/// The generalized Pareto distribution is the limiting distribution of the
+/// exceedances over a high threshold, which makes it the basis of
+/// peaks-over-threshold analysis of extreme values. A shape of zero gives
+/// the exponential distribution, a positive shape a heavy Pareto-like
+/// tail and a negative shape a distribution with a finite upper bound.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedPareto, Continuous};
+/// use statrs::statistics::Mean;
+///
+/// let n = GeneralizedPareto::new(0.0, 1.0, 0.5).unwrap();
+/// assert_eq!(n.mean(), 2.0);
+/// assert_eq!(n.pdf(0.0), 1.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedPareto {
+    /// Constructs a new generalized Pareto distribution with a location (μ)
+    /// of `location`, a scale (σ) of `scale` and a shape (ξ) of `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `location`, `scale` or `shape` are `NaN`
+    /// or infinite, or if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let mut result = GeneralizedPareto::new(0.0, 1.0, -0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedPareto::new(0.0, 0.0, 0.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedPareto> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(GeneralizedPareto {
+                location: location,
+                scale: scale,
+                shape: shape,
+            })
+        }
+    }
+
+    /// Estimates the scale and shape of a generalized Pareto distribution
+    /// with the given `location` from `samples` by maximum likelihood. In
+    /// a peaks-over-threshold analysis the location is the threshold and
+    /// the samples are the observations exceeding it.
+    ///
+    /// # Remarks
+    ///
+    /// The likelihood is maximized over `θ = ξ / σ`, for which the maximum
+    /// likelihood shape and scale follow in closed form. The profile
+    /// likelihood is first evaluated on a grid covering the whole range of
+    /// valid `θ` and then refined with a golden section search around the
+    /// best grid point. For shapes below `-1` the likelihood is unbounded,
+    /// so the estimate is restricted to `ξ >= -1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` is not finite, if there are fewer than
+    /// two samples, if any sample is `NaN`, infinite or below `location`,
+    /// or if all samples are equal to `location`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ = sum(ln(1 + θ * (x_i - μ))) / n
+    /// σ = ξ / θ
+    /// ```
+    ///
+    /// where `μ` is the location, `n` the number of samples and `θ`
+    /// maximizes the profile log likelihood `-n * (ln(σ) + ξ + 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::fit_mle(1.0, &[1.2, 1.5, 1.9, 2.4, 3.5, 6.0]).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// assert!(n.scale() > 0.0);
+    ///
+    /// assert!(GeneralizedPareto::fit_mle(1.0, &[0.5, 2.0]).is_err());
+    /// ```
+    pub fn fit_mle(location: f64, samples: &[f64]) -> Result<GeneralizedPareto> {
+        if !location.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if samples.len() < 2 {
+            return Err(StatsError::SpecialCase(
+                "Cannot fit a generalized Pareto distribution to fewer than two samples",
+            ));
+        }
+        if samples.iter().any(|&x| !x.is_finite() || x < location) {
+            return Err(StatsError::ArgGte("samples", location));
+        }
+        let y: Vec<f64> = samples.iter().map(|&x| x - location).collect();
+        let n = y.len() as f64;
+        let mean = y.iter().sum::<f64>() / n;
+        let max = y.iter().fold(0.0f64, |acc, &x| acc.max(x));
+        if max <= 0.0 {
+            return Err(StatsError::SpecialCase(
+                "Cannot fit a generalized Pareto distribution to samples that all equal the location",
+            ));
+        }
+
+        // returns the maximum likelihood shape and scale for a given theta
+        // together with the profile log likelihood, which is NaN outside of
+        // the region of valid shapes
+        let profile = |theta: f64| -> (f64, f64, f64) {
+            let (shape, scale) = if theta == 0.0 {
+                (0.0, mean)
+            } else {
+                let shape = y.iter().map(|&x| (theta * x).ln_1p()).sum::<f64>() / n;
+                (shape, shape / theta)
+            };
+            if shape < -1.0 || scale.is_nan() || scale <= 0.0 || !scale.is_finite() {
+                (shape, scale, f64::NAN)
+            } else {
+                (shape, scale, -n * (scale.ln() + shape + 1.0))
+            }
+        };
+        // theta lies in (-1 / max, INF), which is covered by a logistic map
+        // of s onto the negative range and an exponential map onto the
+        // positive range
+        let neg_theta = |s: f64| -1.0 / (max * (1.0 + (-s).exp()));
+        let pos_theta = |s: f64| s.exp() / mean;
+
+        let step = 0.25;
+        let mut best = (profile(0.0).2, 0.0, 0.0, false);
+        for i in 0..=240 {
+            let s = -30.0 + step * i as f64;
+            let l = profile(neg_theta(s)).2;
+            if l > best.0 || best.0.is_nan() {
+                best = (l, s, neg_theta(s), false);
+            }
+            // the exponential map covers the positive range by s = 15
+            if s <= 15.0 {
+                let l = profile(pos_theta(s)).2;
+                if l > best.0 || best.0.is_nan() {
+                    best = (l, s, pos_theta(s), true);
+                }
+            }
+        }
+
+        let theta = if best.2 == 0.0 {
+            0.0
+        } else {
+            let map = |s: f64| if best.3 { pos_theta(s) } else { neg_theta(s) };
+            let s = optimization::golden_section_max(
+                |s| {
+                    let l = profile(map(s)).2;
+                    if l.is_nan() {
+                        f64::NEG_INFINITY
+                    } else {
+                        l
+                    }
+                },
+                best.1 - step,
+                best.1 + step,
+                1e-12,
+            );
+            map(s)
+        };
+        let (shape, scale, _) = profile(theta);
+        GeneralizedPareto::new(location, scale, shape)
+    }
+
+    /// Returns the location (μ) of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale (σ) of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape (ξ) of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.shape(), 0.5);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `ln(1 + ξz) / ξ` for the standardized value `z`, which
+    /// tends to `z` as the shape tends to zero
+    fn log_term(&self, z: f64) -> f64 {
+        if self.shape == 0.0 {
+            z
+        } else {
+            (self.shape * z).ln_1p() / self.shape
+        }
+    }
+}
+
+impl Parametric for GeneralizedPareto {
+    /// Returns the `location`, `scale` and `shape` parameters of the
+    /// generalized Pareto distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("location", self.location),
+            ("scale", self.scale),
+            ("shape", self.shape),
+        ]
+    }
+
+    /// Constructs a generalized Pareto distribution from its `location`,
+    /// `scale` and `shape` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<GeneralizedPareto> {
+        let p = internal::parameter_values(params, &["location", "scale", "shape"])?;
+        GeneralizedPareto::new(p[0], p[1], p[2])
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.inverse_cdf(r.gen::<f64>())
+    }
+}
+
+impl Univariate<f64, f64> for GeneralizedPareto {
+    /// Calculates the cumulative distribution function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     1 - e^(-(x - μ) / σ)
+    /// } else {
+    ///     1 - (1 + ξ(x - μ) / σ)^(-1 / ξ)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            -(-self.log_term((x - self.location) / self.scale)).exp_m1()
+        }
+    }
+}
+
+impl InverseCDF<f64> for GeneralizedPareto {
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized Pareto distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     μ - σ * ln(1 - x)
+    /// } else {
+    ///     μ + σ * ((1 - x)^(-ξ) - 1) / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for GeneralizedPareto {
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized Pareto distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     μ - σ * ln(1 - x)
+    /// } else {
+    ///     μ + σ * ((1 - x)^(-ξ) - 1) / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            return Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0));
+        }
+        let ln_q = (-x).ln_1p();
+        let z = if self.shape == 0.0 {
+            -ln_q
+        } else {
+            (-self.shape * ln_q).exp_m1() / self.shape
+        };
+        Ok(self.location + self.scale * z)
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    /// Returns the minimum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    /// Returns the maximum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Domain for GeneralizedPareto {
+    /// Returns the support of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     [μ, μ - σ / ξ]
+    /// } else {
+    ///     [μ, ∞)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.location,
+            hi: self.max(),
+            lo_closed: true,
+            hi_closed: self.shape < 0.0,
+        }
+    }
+}
+
+impl Mean<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ >= 1 {
+    ///     INF
+    /// } else {
+    ///     μ + σ / (1 - ξ)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mean(&self) -> f64 {
+        if self.shape >= 1.0 {
+            f64::INFINITY
+        } else {
+            self.location + self.scale / (1.0 - self.shape)
+        }
+    }
+}
+
+impl CheckedMean<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized Pareto distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape >= 1.0`, where the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ / (1 - ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn checked_mean(&self) -> Result<f64> {
+        if self.shape >= 1.0 {
+            Err(StatsError::ArgLt("shape", 1.0))
+        } else {
+            Ok(self.mean())
+        }
+    }
+}
+
+impl Variance<f64> for GeneralizedPareto {
+    /// Returns the variance of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ >= 1 / 2 {
+    ///     INF
+    /// } else {
+    ///     σ^2 / ((1 - ξ)^2 * (1 - 2ξ))
+    /// }
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn variance(&self) -> f64 {
+        if self.shape >= 0.5 {
+            f64::INFINITY
+        } else {
+            let a = self.scale / (1.0 - self.shape);
+            a * a / (1.0 - 2.0 * self.shape)
+        }
+    }
+
+    /// Returns the standard deviation of the generalized Pareto
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ >= 1 / 2 {
+    ///     INF
+    /// } else {
+    ///     σ / ((1 - ξ) * sqrt(1 - 2ξ))
+    /// }
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for GeneralizedPareto {}
+
+impl CheckedVariance<f64> for GeneralizedPareto {
+    /// Returns the variance of the generalized Pareto distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape >= 0.5`, where the variance is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 / ((1 - ξ)^2 * (1 - 2ξ))
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn checked_variance(&self) -> Result<f64> {
+        if self.shape >= 0.5 {
+            Err(StatsError::ArgLt("shape", 0.5))
+        } else {
+            Ok(self.variance())
+        }
+    }
+
+    /// Returns the standard deviation of the generalized Pareto
+    /// distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape >= 0.5`, where the variance is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ / ((1 - ξ) * sqrt(1 - 2ξ))
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn checked_std_dev(&self) -> Result<f64> {
+        self.checked_variance().map(|x| x.sqrt())
+    }
+}
+
+impl Entropy<f64> for GeneralizedPareto {
+    /// Returns the entropy of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + ξ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn entropy(&self) -> f64 {
+        self.scale.ln() + self.shape + 1.0
+    }
+}
+
+impl Median<f64> for GeneralizedPareto {
+    /// Returns the median of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     μ + σ * ln(2)
+    /// } else {
+    ///     μ + σ * (2^ξ - 1) / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<f64> for GeneralizedPareto {
+    /// Returns the mode of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// For `ξ < -1` the density increases towards the upper bound, which
+    /// is then the mode
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < -1 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     μ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mode(&self) -> f64 {
+        if self.shape < -1.0 {
+            self.max()
+        } else {
+            self.location
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    /// Calculates the probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     e^(-(x - μ) / σ) / σ
+    /// } else {
+    ///     (1 + ξ(x - μ) / σ)^(-1 / ξ - 1) / σ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.location || x > self.max() {
+            0.0
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ == 0 {
+    ///     -ln(σ) - (x - μ) / σ
+    /// } else {
+    ///     -ln(σ) - (1 / ξ + 1) * ln(1 + ξ(x - μ) / σ)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.location || x > self.max() {
+            f64::NEG_INFINITY
+        } else {
+            let z = (x - self.location) / self.scale;
+            if self.shape == 0.0 {
+                -self.scale.ln() - z
+            } else {
+                -self.scale.ln() - (1.0 / self.shape + 1.0) * (self.shape * z).ln_1p()
+            }
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{CheckedInverseCDF, Continuous, Exponential, GeneralizedPareto, InverseCDF, Univariate};
+    use distribution::internal::*;
+    use rng;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedPareto {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let x = eval(try_create(location, scale, shape));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn samples(dist: &GeneralizedPareto, count: usize) -> Vec<f64> {
+        let mut r = rng::seeded(0);
+        (0..count).map(|_| dist.sample(&mut r)).collect()
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(1.0, 2.0, -0.3);
+        assert_eq!(n.location(), 1.0);
+        assert_eq!(n.scale(), 2.0);
+        assert_eq!(n.shape(), -0.3);
+        assert!(GeneralizedPareto::new(0.0, 0.0, 0.5).is_err());
+        assert!(GeneralizedPareto::new(0.0, -1.0, 0.5).is_err());
+        assert!(GeneralizedPareto::new(f64::NAN, 1.0, 0.5).is_err());
+        assert!(GeneralizedPareto::new(0.0, f64::NAN, 0.5).is_err());
+        assert!(GeneralizedPareto::new(0.0, 1.0, f64::NAN).is_err());
+        assert!(GeneralizedPareto::new(0.0, f64::INFINITY, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(1.0, 2.0, 0.3, 1.0 + 2.0 / 0.7, 1e-15, |x| x.mean());
+        test_almost(1.0, 2.0, 0.3, 4.0 / (0.49 * 0.4), 1e-13, |x| x.variance());
+        test_almost(1.0, 2.0, 0.3, 1.9931471805599452, 1e-15, |x| x.entropy());
+        test_almost(0.0, 1.0, 0.0, 1.0, 1e-15, |x| x.mean());
+        test_almost(0.0, 1.0, 0.0, 1.0, 1e-15, |x| x.variance());
+        let n = try_create(0.0, 1.0, 1.0);
+        assert_eq!(n.mean(), f64::INFINITY);
+        assert!(n.checked_mean().is_err());
+        let n = try_create(0.0, 1.0, 0.5);
+        assert_eq!(n.checked_mean().unwrap(), 2.0);
+        assert_eq!(n.variance(), f64::INFINITY);
+        assert!(n.checked_variance().is_err());
+        assert!(n.checked_std_dev().is_err());
+        assert_almost_eq!(try_create(0.0, 1.0, -1.0).checked_std_dev().unwrap(), 1.0 / 12f64.sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(1.0, 2.0, 0.3, 2.5409627556327754, 1e-14, |x| x.median());
+        test_almost(0.0, 1.0, 0.0, f64::consts::LN_2, 1e-15, |x| x.median());
+        assert_eq!(try_create(1.0, 2.0, 0.3).mode(), 1.0);
+        assert_eq!(try_create(1.0, 2.0, -2.0).mode(), 2.0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let n = try_create(-1.0, 0.5, -0.4);
+        assert_eq!(n.min(), -1.0);
+        assert_eq!(n.max(), 0.25);
+        assert_eq!(try_create(-1.0, 0.5, 0.0).max(), f64::INFINITY);
+        assert_eq!(try_create(-1.0, 0.5, 0.4).max(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(1.0, 2.0, 0.3, 0.3654825608469475, 1e-15, |x| x.pdf(1.5));
+        test_almost(1.0, 2.0, 0.3, 0.2728638669070324, 1e-15, |x| x.pdf(2.0));
+        test_almost(0.0, 1.0, 0.0, 0.22313016014842982, 1e-15, |x| x.pdf(1.5));
+        test_almost(-1.0, 0.5, -0.4, 0.9295160030897801, 1e-15, |x| x.pdf(-0.5));
+        test_almost(-1.0, 0.5, -0.4, 0.17888543819998315, 1e-15, |x| x.pdf(0.0));
+        let n = try_create(-1.0, 0.5, -0.4);
+        assert_eq!(n.pdf(-1.5), 0.0);
+        assert_eq!(n.pdf(0.3), 0.0);
+        assert_eq!(n.ln_pdf(0.3), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(1.0, 2.0, 0.3, -1.0065367140716585, 1e-15, |x| x.ln_pdf(1.5));
+        test_almost(0.0, 1.0, 0.0, -1.5, 1e-15, |x| x.ln_pdf(1.5));
+        test_almost(-1.0, 0.5, -0.4, -0.0730912550890407, 1e-15, |x| x.ln_pdf(-0.5));
+        test_almost(-1.0, 0.5, -0.4, -1.7210096880912054, 1e-14, |x| x.ln_pdf(0.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(1.0, 2.0, 0.3, 0.21421249417906288, 1e-15, |x| x.cdf(1.5));
+        test_almost(1.0, 2.0, 0.3, 0.3724131061138255, 1e-15, |x| x.cdf(2.0));
+        test_almost(0.0, 1.0, 0.0, 0.7768698398515702, 1e-15, |x| x.cdf(1.5));
+        test_almost(-1.0, 0.5, -0.4, 0.721145199073066, 1e-15, |x| x.cdf(-0.5));
+        test_almost(-1.0, 0.5, -0.4, 0.9821114561800017, 1e-15, |x| x.cdf(0.0));
+        let n = try_create(-1.0, 0.5, -0.4);
+        assert_eq!(n.cdf(-1.0), 0.0);
+        assert_eq!(n.cdf(0.25), 1.0);
+        assert_eq!(n.cdf(10.0), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(1.0, 2.0, 0.3, 1.214086649521267, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(1.0, 2.0, 0.3, 20.873811370233142, 1e-12, |x| x.inverse_cdf(0.99));
+        test_almost(0.0, 1.0, 0.0, 4.605170185988091, 1e-14, |x| x.inverse_cdf(0.99));
+        test_almost(-1.0, 0.5, -0.4, -0.6973228540689987, 1e-15, |x| x.inverse_cdf(0.5));
+        let n = try_create(-1.0, 0.5, -0.4);
+        assert_eq!(n.inverse_cdf(0.0), -1.0);
+        assert_almost_eq!(n.inverse_cdf(1.0), 0.25, 1e-15);
+        assert_eq!(try_create(-1.0, 0.5, 0.4).inverse_cdf(1.0), f64::INFINITY);
+        assert_eq!(try_create(-1.0, 0.5, 0.0).inverse_cdf(1.0), f64::INFINITY);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+        for &p in &[1e-10f64, 0.3, 0.7, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_exponential_limit() {
+        // a shape of zero is the exponential distribution and the
+        // distribution is continuous in the shape around zero
+        let e = Exponential::new(0.5).unwrap();
+        let zero = try_create(0.0, 2.0, 0.0);
+        for &x in &[0.0f64, 0.1, 1.0, 5.0, 30.0] {
+            assert_almost_eq!(zero.pdf(x), e.pdf(x), 1e-16);
+            assert_almost_eq!(zero.cdf(x), e.cdf(x), 1e-15);
+            for &shape in &[1e-12f64, -1e-12] {
+                let n = try_create(0.0, 2.0, shape);
+                assert_almost_eq!(n.pdf(x), zero.pdf(x), 1e-10);
+                assert_almost_eq!(n.cdf(x), zero.cdf(x), 1e-10);
+            }
+        }
+        for &p in &[0.01f64, 0.5, 0.99] {
+            let x = zero.inverse_cdf(p);
+            assert_almost_eq!(try_create(0.0, 2.0, 1e-12).inverse_cdf(p), x, 1e-9);
+            assert_almost_eq!(try_create(0.0, 2.0, -1e-12).inverse_cdf(p), x, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_fit_mle() {
+        for &(scale, shape) in &[(2.0f64, 0.3f64), (1.5, 0.0), (0.5, -0.25), (3.0, 0.8)] {
+            let n = try_create(1.0, scale, shape);
+            let data = samples(&n, 20000);
+            let fit = GeneralizedPareto::fit_mle(1.0, &data).unwrap();
+            assert_eq!(fit.location(), 1.0);
+            assert_almost_eq!(fit.shape(), shape, 0.04);
+            assert_almost_eq!(fit.scale() / scale, 1.0, 0.04);
+            let ln_lik = |d: &GeneralizedPareto| data.iter().map(|&x| d.ln_pdf(x)).sum::<f64>();
+            assert!(ln_lik(&fit) >= ln_lik(&n));
+        }
+    }
+
+    #[test]
+    fn test_fit_mle_is_maximum() {
+        let n = try_create(0.0, 1.0, 0.2);
+        let data = samples(&n, 500);
+        let fit = GeneralizedPareto::fit_mle(0.0, &data).unwrap();
+        let ln_lik = |d: &GeneralizedPareto| data.iter().map(|&x| d.ln_pdf(x)).sum::<f64>();
+        let best = ln_lik(&fit);
+        for &(ds, dx) in &[(1.01f64, 0.0f64), (0.99, 0.0), (1.0, 0.01), (1.0, -0.01)] {
+            let other = try_create(0.0, fit.scale() * ds, fit.shape() + dx);
+            assert!(ln_lik(&other) < best);
+        }
+    }
+
+    #[test]
+    fn test_fit_mle_bad_input() {
+        assert!(GeneralizedPareto::fit_mle(0.0, &[]).is_err());
+        assert!(GeneralizedPareto::fit_mle(0.0, &[1.0]).is_err());
+        assert!(GeneralizedPareto::fit_mle(0.0, &[1.0, -1.0]).is_err());
+        assert!(GeneralizedPareto::fit_mle(0.0, &[1.0, f64::NAN]).is_err());
+        assert!(GeneralizedPareto::fit_mle(0.0, &[1.0, f64::INFINITY]).is_err());
+        assert!(GeneralizedPareto::fit_mle(0.0, &[0.0, 0.0]).is_err());
+        assert!(GeneralizedPareto::fit_mle(f64::NAN, &[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(0.0, 1.0, 0.3), 0.0, 200.0);
+        test::check_continuous_distribution(&try_create(-1.0, 0.5, -0.4), -1.0, 0.25);
+        test::check_continuous_distribution(&try_create(2.0, 1.5, 0.0), 2.0, 60.0);
+    }
+}
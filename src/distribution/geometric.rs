@@ -1,4 +1,4 @@
-use distribution::{Discrete, Univariate};
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::distributions::OpenClosed01;
 use rand::Rng;
@@ -69,6 +69,19 @@ impl Geometric {
     }
 }
 
+impl Parametric for Geometric {
+    /// Returns the `p` parameter of the geometric distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("p", self.p)]
+    }
+
+    /// Constructs a geometric distribution from its `p` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Geometric> {
+        let p = internal::parameter_values(params, &["p"])?;
+        Geometric::new(p[0])
+    }
+}
+
 impl Distribution<f64> for Geometric {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         if self.p == 1.0 {
@@ -130,6 +143,22 @@ impl Max<u64> for Geometric {
     }
 }
 
+impl Domain for Geometric {
+    /// Returns the support of the geometric distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {1, 2, ...}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: 1.0,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
 impl Mean<f64> for Geometric {
     /// Returns the mean of the geometric distribution
     ///
@@ -171,6 +200,8 @@ impl Variance<f64> for Geometric {
     }
 }
 
+impl Dispersion for Geometric {}
+
 impl Entropy<f64> for Geometric {
     /// Returns the entropy of the geometric distribution
     ///
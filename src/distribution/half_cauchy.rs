@@ -0,0 +1,426 @@
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Parametric, Support, Univariate,
+};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the half-Cauchy distribution, the distribution of `|X|` for a
+/// Cauchy distributed `X` with location zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HalfCauchy, Continuous};
+/// use statrs::statistics::Median;
+///
+/// let n = HalfCauchy::new(2.0).unwrap();
+/// assert_eq!(n.median(), 2.0);
+/// assert_eq!(n.pdf(0.0), 1.0 / std::f64::consts::PI);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HalfCauchy {
+    scale: f64,
+}
+
+impl HalfCauchy {
+    /// Constructs a new half-Cauchy distribution with a scale of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfCauchy;
+    ///
+    /// let mut result = HalfCauchy::new(1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = HalfCauchy::new(-1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64) -> Result<HalfCauchy> {
+        if scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(HalfCauchy { scale: scale })
+        }
+    }
+
+    /// Returns the scale of the half-Cauchy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfCauchy;
+    ///
+    /// let n = HalfCauchy::new(2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Parametric for HalfCauchy {
+    /// Returns the `scale` parameter of the half-Cauchy distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("scale", self.scale)]
+    }
+
+    /// Constructs a half-Cauchy distribution from its `scale` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<HalfCauchy> {
+        let p = internal::parameter_values(params, &["scale"])?;
+        HalfCauchy::new(p[0])
+    }
+}
+
+impl Distribution<f64> for HalfCauchy {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.scale * (f64::consts::FRAC_PI_2 * r.gen::<f64>()).tan()
+    }
+}
+
+impl Univariate<f64, f64> for HalfCauchy {
+    /// Calculates the cumulative distribution function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / π) * arctan(x / γ)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            f64::consts::FRAC_2_PI * (x / self.scale).atan()
+        }
+    }
+}
+
+impl InverseCDF<f64> for HalfCauchy {
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-Cauchy distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ * tan(πx / 2)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for HalfCauchy {
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-Cauchy distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ * tan(πx / 2)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else if x == 1.0 {
+            Ok(f64::INFINITY)
+        } else {
+            Ok(self.scale * (f64::consts::FRAC_PI_2 * x).tan())
+        }
+    }
+}
+
+impl Min<f64> for HalfCauchy {
+    /// Returns the minimum value in the domain of the half-Cauchy
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfCauchy {
+    /// Returns the maximum value in the domain of the half-Cauchy
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Domain for HalfCauchy {
+    /// Returns the support of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
+impl Mean<f64> for HalfCauchy {
+    /// Returns the mean of the half-Cauchy distribution, which diverges
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn mean(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Variance<f64> for HalfCauchy {
+    /// Returns the variance of the half-Cauchy distribution, which diverges
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn variance(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    /// Returns the standard deviation of the half-Cauchy distribution,
+    /// which diverges
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn std_dev(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Dispersion for HalfCauchy {}
+
+impl Entropy<f64> for HalfCauchy {
+    /// Returns the entropy of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2πγ)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn entropy(&self) -> f64 {
+        (2.0 * f64::consts::PI * self.scale).ln()
+    }
+}
+
+impl Median<f64> for HalfCauchy {
+    /// Returns the median of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn median(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Mode<f64> for HalfCauchy {
+    /// Returns the mode of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Continuous<f64, f64> for HalfCauchy {
+    /// Calculates the probability density function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 / (πγ * (1 + (x / γ)^2))
+    /// ```
+    ///
+    /// for `x >= 0` where `γ` is the scale, i.e. twice the density of the
+    /// underlying Cauchy distribution
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let z = x / self.scale;
+            2.0 / (f64::consts::PI * self.scale * (1.0 + z * z))
+        }
+    }
+
+    /// Calculates the log probability density function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / (πγ * (1 + (x / γ)^2)))
+    /// ```
+    ///
+    /// for `x >= 0` where `γ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            let z = x / self.scale;
+            f64::consts::LN_2 - (f64::consts::PI * self.scale * (1.0 + z * z)).ln()
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{Cauchy, CheckedInverseCDF, Continuous, HalfCauchy, InverseCDF, Univariate};
+    use distribution::internal::*;
+
+    fn try_create(scale: f64) -> HalfCauchy {
+        let n = HalfCauchy::new(scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_case<F>(scale: f64, expected: f64, eval: F)
+        where F: Fn(HalfCauchy) -> f64
+    {
+        let x = eval(try_create(scale));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(HalfCauchy) -> f64
+    {
+        let x = eval(try_create(scale));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(0.1).scale(), 0.1);
+        assert_eq!(try_create(10.0).scale(), 10.0);
+        assert!(HalfCauchy::new(0.0).is_err());
+        assert!(HalfCauchy::new(-1.0).is_err());
+        assert!(HalfCauchy::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_case(1.0, f64::INFINITY, |x| x.mean());
+        test_case(1.0, f64::INFINITY, |x| x.variance());
+        test_case(1.0, f64::INFINITY, |x| x.std_dev());
+        test_case(3.0, 3.0, |x| x.median());
+        test_case(3.0, 0.0, |x| x.mode());
+        test_almost(0.5, 1.1447298858494002, 1e-15, |x| x.entropy());
+        test_almost(2.0, 2.5310242469692907, 1e-15, |x| x.entropy());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(1.0, 0.0, |x| x.min());
+        test_case(1.0, f64::INFINITY, |x| x.max());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(0.5, 0.9362055475993845, 1e-15, |x| x.pdf(0.3));
+        test_almost(0.5, 0.25464790894703254, 1e-15, |x| x.pdf(1.0));
+        test_almost(2.0, 0.3113055121601865, 1e-15, |x| x.pdf(0.3));
+        test_almost(2.0, 0.06366197723675814, 1e-16, |x| x.pdf(4.0));
+        test_case(1.0, 0.0, |x| x.pdf(-0.1));
+        // twice the density of the cauchy distribution
+        let c = Cauchy::new(0.0, 2.0).unwrap();
+        for &x in &[0.0f64, 0.5, 3.0, 100.0] {
+            test_almost(2.0, 2.0 * c.pdf(x), 1e-16, |n| n.pdf(x));
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(0.5, -0.0659202244774701, 1e-15, |x| x.ln_pdf(0.3));
+        test_almost(0.5, -3.932822794625147, 1e-15, |x| x.ln_pdf(4.0));
+        test_almost(2.0, -1.3678734371636099, 1e-15, |x| x.ln_pdf(1.0));
+        test_case(1.0, f64::NEG_INFINITY, |x| x.ln_pdf(-0.1));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.5, 0.3440417392452613, 1e-15, |x| x.cdf(0.3));
+        test_almost(0.5, 0.9208331516788689, 1e-15, |x| x.cdf(4.0));
+        test_almost(2.0, 0.09478628455497924, 1e-16, |x| x.cdf(0.3));
+        test_almost(2.0, 0.7048327646991335, 1e-15, |x| x.cdf(4.0));
+        test_case(1.0, 0.0, |x| x.cdf(-1.0));
+        test_case(1.0, 1.0, |x| x.cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(2.0);
+        for &p in &[1e-6f64, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-14);
+        }
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(0.01), 0.0, 100.0);
+    }
+}
@@ -0,0 +1,467 @@
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Parametric, Support, Univariate,
+};
+use function::erf;
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [Half-normal](https://en.wikipedia.org/wiki/Half-normal_distribution)
+/// distribution, the distribution of `|X|` for a normally distributed `X`
+/// with mean zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HalfNormal, Continuous};
+/// use statrs::statistics::Mean;
+/// use statrs::prec;
+///
+/// let n = HalfNormal::new(1.0).unwrap();
+/// assert_eq!(n.mean(), (2.0 / std::f64::consts::PI).sqrt());
+/// assert!(prec::almost_eq(n.pdf(0.0), (2.0 / std::f64::consts::PI).sqrt(), 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HalfNormal {
+    scale: f64,
+}
+
+impl HalfNormal {
+    /// Constructs a new half-normal distribution with a scale of `scale`,
+    /// the standard deviation of the underlying normal distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfNormal;
+    ///
+    /// let mut result = HalfNormal::new(1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = HalfNormal::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64) -> Result<HalfNormal> {
+        if scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(HalfNormal { scale: scale })
+        }
+    }
+
+    /// Returns the scale of the half-normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfNormal;
+    ///
+    /// let n = HalfNormal::new(2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Parametric for HalfNormal {
+    /// Returns the `scale` parameter of the half-normal distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("scale", self.scale)]
+    }
+
+    /// Constructs a half-normal distribution from its `scale` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<HalfNormal> {
+        let p = internal::parameter_values(params, &["scale"])?;
+        HalfNormal::new(p[0])
+    }
+}
+
+impl Distribution<f64> for HalfNormal {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        super::normal::sample_unchecked(r, 0.0, self.scale).abs()
+    }
+}
+
+impl Univariate<f64, f64> for HalfNormal {
+    /// Calculates the cumulative distribution function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// erf(x / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf` is the error function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            erf::erf(x / (self.scale * f64::consts::SQRT_2))
+        }
+    }
+}
+
+impl InverseCDF<f64> for HalfNormal {
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2) * erf_inv(x)
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf_inv` is the inverse error function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for HalfNormal {
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-normal distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2) * erf_inv(x)
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf_inv` is the inverse error function
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else {
+            Ok(self.scale * f64::consts::SQRT_2 * erf::erf_inv(x))
+        }
+    }
+}
+
+impl Min<f64> for HalfNormal {
+    /// Returns the minimum value in the domain of the half-normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfNormal {
+    /// Returns the maximum value in the domain of the half-normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Domain for HalfNormal {
+    /// Returns the support of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
+impl Mean<f64> for HalfNormal {
+    /// Returns the mean of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2 / π)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn mean(&self) -> f64 {
+        self.scale * (2.0 / f64::consts::PI).sqrt()
+    }
+}
+
+impl Variance<f64> for HalfNormal {
+    /// Returns the variance of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * (1 - 2 / π)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn variance(&self) -> f64 {
+        self.scale * self.scale * (1.0 - 2.0 / f64::consts::PI)
+    }
+
+    /// Returns the standard deviation of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(1 - 2 / π)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for HalfNormal {}
+
+impl Entropy<f64> for HalfNormal {
+    /// Returns the entropy of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * ln(π * e * σ^2 / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn entropy(&self) -> f64 {
+        0.5 * (f64::consts::PI * f64::consts::E * self.scale * self.scale / 2.0).ln()
+    }
+}
+
+impl Median<f64> for HalfNormal {
+    /// Returns the median of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2) * erf_inv(1 / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf_inv` is the inverse error function
+    fn median(&self) -> f64 {
+        self.scale * f64::consts::SQRT_2 * erf::erf_inv(0.5)
+    }
+}
+
+impl Mode<f64> for HalfNormal {
+    /// Returns the mode of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Continuous<f64, f64> for HalfNormal {
+    /// Calculates the probability density function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (sqrt(2) / (σ * sqrt(π))) * e^(-x^2 / (2σ^2))
+    /// ```
+    ///
+    /// for `x >= 0` where `σ` is the scale, i.e. twice the density of the
+    /// underlying normal distribution
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            2.0 * super::normal::pdf_unchecked(x, 0.0, self.scale)
+        }
+    }
+
+    /// Calculates the log probability density function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((sqrt(2) / (σ * sqrt(π))) * e^(-x^2 / (2σ^2)))
+    /// ```
+    ///
+    /// for `x >= 0` where `σ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::consts::LN_2 + super::normal::ln_pdf_unchecked(x, 0.0, self.scale)
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{CheckedInverseCDF, Continuous, Domain, HalfNormal, InverseCDF, Support, Univariate};
+    use distribution::internal::*;
+    use rng;
+
+    fn try_create(scale: f64) -> HalfNormal {
+        let n = HalfNormal::new(scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_case<F>(scale: f64, expected: f64, eval: F)
+        where F: Fn(HalfNormal) -> f64
+    {
+        let x = eval(try_create(scale));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(HalfNormal) -> f64
+    {
+        let x = eval(try_create(scale));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(0.1).scale(), 0.1);
+        assert_eq!(try_create(10.0).scale(), 10.0);
+        assert!(HalfNormal::new(0.0).is_err());
+        assert!(HalfNormal::new(-1.0).is_err());
+        assert!(HalfNormal::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_mean() {
+        for &scale in &[0.5f64, 1.0, 2.0, 7.5] {
+            test_almost(scale, scale * (2.0 / f64::consts::PI).sqrt(), 1e-15, |x| x.mean());
+        }
+    }
+
+    #[test]
+    fn test_variance() {
+        test_almost(0.5, 0.09084505690810467, 1e-16, |x| x.variance());
+        test_almost(2.0, 1.4535209105296747, 1e-15, |x| x.variance());
+        test_almost(2.0, 1.4535209105296747f64.sqrt(), 1e-15, |x| x.std_dev());
+    }
+
+    #[test]
+    fn test_entropy() {
+        test_almost(0.5, 0.032644172084782125, 1e-15, |x| x.entropy());
+        test_almost(2.0, 1.4189385332046727, 1e-15, |x| x.entropy());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(0.5, 0.33724487509804085, 1e-15, |x| x.median());
+        test_almost(2.0, 1.3489795003921634, 1e-14, |x| x.median());
+        test_almost(2.0, 0.5, 1e-15, |x| x.cdf(x.median()));
+        test_case(2.0, 0.0, |x| x.mode());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(1.0, 0.0, |x| x.min());
+        test_case(1.0, f64::INFINITY, |x| x.max());
+        assert_eq!(try_create(1.0).support(), Support::Continuous {
+            lo: 0.0, hi: f64::INFINITY, lo_closed: true, hi_closed: false
+        });
+    }
+
+    #[test]
+    fn test_pdf() {
+        for &scale in &[0.5f64, 1.0, 2.0] {
+            test_almost(scale, (2.0 / f64::consts::PI).sqrt() / scale, 1e-15, |x| x.pdf(0.0));
+        }
+        test_almost(0.5, 1.3328984115671985, 1e-15, |x| x.pdf(0.3));
+        test_almost(0.5, 0.2159638660527522, 1e-15, |x| x.pdf(1.0));
+        test_almost(0.5, 2.0209084334147568e-14, 1e-28, |x| x.pdf(4.0));
+        test_almost(2.0, 0.3944793309078889, 1e-15, |x| x.pdf(0.3));
+        test_almost(2.0, 0.35206532676429947, 1e-15, |x| x.pdf(1.0));
+        test_almost(2.0, 0.05399096651318805, 1e-16, |x| x.pdf(4.0));
+        test_case(1.0, 0.0, |x| x.pdf(-0.1));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(0.5, 0.2873558279152179, 1e-15, |x| x.ln_pdf(0.3));
+        test_almost(0.5, -31.532644172084783, 1e-13, |x| x.ln_pdf(4.0));
+        test_almost(2.0, -1.0439385332046727, 1e-15, |x| x.ln_pdf(1.0));
+        test_case(1.0, f64::NEG_INFINITY, |x| x.ln_pdf(-0.1));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.5, 0.45149376449985285, 1e-10, |x| x.cdf(0.3));
+        test_almost(0.5, 0.9544997361036416, 1e-10, |x| x.cdf(1.0));
+        test_almost(2.0, 0.11923538474048503, 1e-10, |x| x.cdf(0.3));
+        test_almost(2.0, 0.3829249225480262, 1e-10, |x| x.cdf(1.0));
+        test_almost(2.0, 0.9544997361036416, 1e-10, |x| x.cdf(4.0));
+        test_case(1.0, 0.0, |x| x.cdf(0.0));
+        test_case(1.0, 0.0, |x| x.cdf(-1.0));
+        test_case(1.0, 1.0, |x| x.cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(2.0);
+        for &p in &[0.01f64, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-10);
+        }
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0), 0.0, 10.0);
+        test::check_continuous_distribution(&try_create(0.3), 0.0, 3.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let n = try_create(2.0);
+        let samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert_almost_eq!(samples.iter().mean(), n.mean(), 0.03);
+        assert_almost_eq!(samples.iter().variance(), n.variance(), 0.05);
+    }
+}
@@ -1,4 +1,4 @@
-use distribution::{Discrete, Univariate};
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
 use function::factorial;
 use rand::distributions::Distribution;
 use rand::Rng;
@@ -109,6 +109,38 @@ impl Hypergeometric {
             self.draws as f64,
         )
     }
+
+    /// Returns the probability mass at `i` given the log of the number of
+    /// ways of choosing the draws from the population
+    fn cdf_term(&self, i: u64, ln_denom: f64) -> f64 {
+        (factorial::ln_binomial(self.successes, i)
+            + factorial::ln_binomial(self.population - self.successes, self.draws - i)
+            - ln_denom)
+            .exp()
+    }
+}
+
+impl Parametric for Hypergeometric {
+    /// Returns the `population`, `successes` and `draws` parameters of the
+    /// hypergeometric distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("population", self.population as f64),
+            ("successes", self.successes as f64),
+            ("draws", self.draws as f64),
+        ]
+    }
+
+    /// Constructs a hypergeometric distribution from its `population`,
+    /// `successes` and `draws` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Hypergeometric> {
+        let p = internal::parameter_values(params, &["population", "successes", "draws"])?;
+        Hypergeometric::new(
+            internal::u64_parameter(p[0])?,
+            internal::u64_parameter(p[1])?,
+            internal::u64_parameter(p[2])?,
+        )
+    }
 }
 
 impl Distribution<f64> for Hypergeometric {
@@ -157,14 +189,39 @@ impl Univariate<u64, f64> for Hypergeometric {
         } else {
             let k = x.floor() as u64;
             let ln_denom = factorial::ln_binomial(self.population, self.draws);
-            (0..k + 1).fold(0.0, |acc, i| {
-                acc + (factorial::ln_binomial(self.successes, i)
-                    + factorial::ln_binomial(self.population - self.successes, self.draws - i)
-                    - ln_denom)
-                    .exp()
-            })
+            (0..k + 1).fold(0.0, |acc, i| acc + self.cdf_term(i, ln_denom))
         }
     }
+
+    /// Calculates the cumulative distribution function for the hypergeometric
+    /// distribution at each of `xs`. If `xs` is sorted in increasing order
+    /// the probability masses summed for each point are carried over to the
+    /// next, so the whole grid costs as much as a single evaluation at its
+    /// last point.
+    fn cdf_grid(&self, xs: &[f64]) -> Vec<f64> {
+        if !xs.windows(2).all(|w| w[0] <= w[1]) {
+            return xs.iter().map(|&x| self.cdf(x)).collect();
+        }
+        let ln_denom = factorial::ln_binomial(self.population, self.draws);
+        let mut acc = 0.0;
+        let mut next = 0;
+        xs.iter()
+            .map(|&x| {
+                if x < self.min() as f64 {
+                    0.0
+                } else if x >= self.max() as f64 {
+                    1.0
+                } else {
+                    let k = x.floor() as u64;
+                    while next <= k {
+                        acc += self.cdf_term(next, ln_denom);
+                        next += 1;
+                    }
+                    acc
+                }
+            })
+            .collect()
+    }
 }
 
 impl Min<u64> for Hypergeometric {
@@ -201,6 +258,22 @@ impl Max<u64> for Hypergeometric {
     }
 }
 
+impl Domain for Hypergeometric {
+    /// Returns the support of the hypergeometric distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {max(0, n + K - N), ..., min(K, n)}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: self.min() as f64,
+            hi: self.max() as f64,
+        }
+    }
+}
+
 impl Mean<f64> for Hypergeometric {
     /// Returns the mean of the hypergeometric distribution
     ///
@@ -279,6 +352,26 @@ impl Variance<f64> for Hypergeometric {
     }
 }
 
+impl Dispersion for Hypergeometric {
+    /// Returns the coefficient of variation of the distribution, or `NaN`
+    /// if its mean is zero or its mean or variance is undefined
+    fn coefficient_of_variation(&self) -> f64 {
+        match (self.checked_mean(), self.checked_std_dev()) {
+            (Ok(mean), Ok(std_dev)) if mean != 0.0 && mean.is_finite() => std_dev / mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Returns the index of dispersion of the distribution, or `NaN` if its
+    /// mean is zero or its mean or variance is undefined
+    fn index_of_dispersion(&self) -> f64 {
+        match (self.checked_mean(), self.checked_variance()) {
+            (Ok(mean), Ok(variance)) if mean != 0.0 && mean.is_finite() => variance / mean,
+            _ => f64::NAN,
+        }
+    }
+}
+
 impl CheckedVariance<f64> for Hypergeometric {
     /// Returns the variance of the hypergeometric distribution
     ///
@@ -375,15 +468,47 @@ impl CheckedSkewness<f64> for Hypergeometric {
 impl Mode<u64> for Hypergeometric {
     /// Returns the mode of the hypergeometric distribution
     ///
+    /// # Remarks
+    ///
+    /// If `(n + 1) * (K + 1) / (N + 2)` is an integer `m` both `m` and
+    /// `m - 1` are modes and the smaller one is returned. Use
+    /// `Modes::modes` to get both.
+    ///
     /// # Formula
     ///
     /// ```ignore
-    /// floor((n + 1) * (k + 1) / (N + 2))
+    /// ceil((n + 1) * (K + 1) / (N + 2)) - 1
     /// ```
     ///
     /// where `N` is population, `K` is successes, and `n` is draws
     fn mode(&self) -> u64 {
-        ((self.draws + 1) * (self.successes + 1) / (self.population + 2)) as u64
+        self.modes()[0]
+    }
+}
+
+impl Modes<u64> for Hypergeometric {
+    /// Returns the modes of the hypergeometric distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// let m = (n + 1) * (K + 1) / (N + 2)
+    /// if m is an integer {
+    ///     [m - 1, m]
+    /// } else {
+    ///     [floor(m)]
+    /// }
+    /// ```
+    ///
+    /// where `N` is population, `K` is successes, and `n` is draws
+    fn modes(&self) -> Vec<u64> {
+        let num = (self.draws + 1) * (self.successes + 1);
+        let m = num / (self.population + 2);
+        if m * (self.population + 2) == num && m > self.min() {
+            vec![m - 1, m]
+        } else {
+            vec![m]
+        }
     }
 }
 
@@ -431,7 +556,7 @@ mod test {
     use std::f64;
     use std::fmt::Debug;
     use statistics::*;
-    use distribution::{Univariate, Discrete, Hypergeometric};
+    use distribution::{Univariate, Discrete, Hypergeometric, Parametric};
     use distribution::internal::*;
 
     fn try_create(population: u64, successes: u64, draws: u64) -> Hypergeometric {
@@ -575,10 +700,22 @@ mod test {
     fn test_mode() {
         test_case(0, 0, 0, 0, |x| x.mode());
         test_case(1, 1, 1, 1, |x| x.mode());
-        test_case(2, 1, 1, 1, |x| x.mode());
+        test_case(2, 1, 1, 0, |x| x.mode());
         test_case(2, 2, 2, 2, |x| x.mode());
         test_case(10, 1, 1, 0, |x| x.mode());
-        test_case(10, 5, 3, 2, |x| x.mode());
+        test_case(10, 5, 3, 1, |x| x.mode());
+        test_case(20, 8, 6, 2, |x| x.mode());
+    }
+
+    #[test]
+    fn test_modes() {
+        test_case(0, 0, 0, vec![0], |x| x.modes());
+        test_case(2, 1, 1, vec![0, 1], |x| x.modes());
+        test_case(2, 2, 2, vec![2], |x| x.modes());
+        test_case(10, 5, 3, vec![1, 2], |x| x.modes());
+        test_case(20, 8, 6, vec![2], |x| x.modes());
+        let n = try_create(10, 5, 3);
+        assert_eq!(n.pmf(1), n.pmf(2));
     }
 
     #[test]
@@ -653,4 +790,34 @@ mod test {
         test::check_discrete_distribution(&try_create(5, 4, 3), 4);
         test::check_discrete_distribution(&try_create(3, 2, 1), 2);
     }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(try_create(10, 5, 3).parameters(), vec![("population", 10.0), ("successes", 5.0), ("draws", 3.0)]);
+    }
+
+    #[test]
+    fn test_from_parameters() {
+        let n = try_create(10, 5, 3);
+        assert_eq!(Hypergeometric::from_parameters(&n.parameters()).unwrap(), n);
+        assert!(Hypergeometric::from_parameters(&[("population", 10.0), ("successes", 11.0), ("draws", 3.0)]).is_err());
+        assert!(Hypergeometric::from_parameters(&[("population", 10.0), ("successes", 5.0)]).is_err());
+    }
+
+    #[test]
+    fn test_cdf_grid() {
+        let n = try_create(50, 20, 30);
+        let sorted = [-1.0, 0.0, 5.0, 10.5, 11.0, 11.0, 12.9, 19.0, 20.0, 25.0];
+        let grid = n.cdf_grid(&sorted);
+        assert_eq!(grid.len(), sorted.len());
+        for (&x, &c) in sorted.iter().zip(grid.iter()) {
+            assert_eq!(c, n.cdf(x));
+        }
+        let unsorted = [12.9, -1.0, 20.0, 5.0, 11.0, 0.0];
+        let grid = n.cdf_grid(&unsorted);
+        for (&x, &c) in unsorted.iter().zip(grid.iter()) {
+            assert_eq!(c, n.cdf(x));
+        }
+        assert!(n.cdf_grid(&[]).is_empty());
+    }
 }
@@ -1,3 +1,7 @@
+use function::factorial;
+use rand::Rng;
+use {Result, StatsError};
+
 /// Returns true if there are no elements in `x` in `arr`
 /// such that `x <= 0.0` or `x` is `f64::NAN` and `sum(arr) > 0.0`.
 /// IF `incl_zero` is true, it tests for `x < 0.0` instead of `x <= 0.0`
@@ -17,9 +21,153 @@ pub fn is_valid_multinomial(arr: &[f64], incl_zero: bool) -> bool {
     sum != 0.0
 }
 
+// Absolute tolerance used when numerically integrating over the density of
+// a distribution
+pub const INTEGRATION_TOLERANCE: f64 = 1e-12;
+
+/// Shifts the moments about one point to moments about another, i.e. computes
+/// `E[(Y + shift)^k]` from `moment(j) = E[Y^j]` using the binomial expansion
+pub fn shift_moment<F>(moment: F, shift: f64, k: u32) -> f64
+where
+    F: Fn(u32) -> f64,
+{
+    (0..k + 1).fold(0.0, |acc, j| {
+        acc + factorial::binomial(u64::from(k), u64::from(j))
+            * moment(j)
+            * shift.powi((k - j) as i32)
+    })
+}
+
+/// Validates that `corr` is a symmetric positive-definite correlation matrix
+/// stored in row-major order and returns its dimension together with the
+/// lower triangular Cholesky factor `L`, with `L * L^T = corr`, also in
+/// row-major order
+pub fn correlation_cholesky(corr: &[f64]) -> Result<(usize, Vec<f64>)> {
+    let dim = (corr.len() as f64).sqrt().round() as usize;
+    if dim == 0 || dim * dim != corr.len() || corr.iter().any(|x| !x.is_finite()) {
+        return Err(StatsError::BadParams);
+    }
+    for i in 0..dim {
+        if (corr[i * dim + i] - 1.0).abs() > CORRELATION_TOLERANCE {
+            return Err(StatsError::BadParams);
+        }
+        for j in 0..i {
+            if (corr[i * dim + j] - corr[j * dim + i]).abs() > CORRELATION_TOLERANCE {
+                return Err(StatsError::BadParams);
+            }
+        }
+    }
+
+    match cholesky(corr, dim) {
+        Some(chol) => Ok((dim, chol)),
+        None => Err(StatsError::SpecialCase(
+            "correlation matrix must be positive definite",
+        )),
+    }
+}
+
+/// Returns the lower triangular Cholesky factor `L`, with `L * L^T = a`, of
+/// the symmetric matrix `a` of dimension `dim` in row-major order, or `None`
+/// if `a` is not positive definite. Only the lower triangle of `a` is read
+pub fn cholesky(a: &[f64], dim: usize) -> Option<Vec<f64>> {
+    let mut chol = vec![0.0; dim * dim];
+    for i in 0..dim {
+        for j in 0..i + 1 {
+            let sum = (0..j).fold(a[i * dim + j], |acc, k| {
+                acc - chol[i * dim + k] * chol[j * dim + k]
+            });
+            if i == j {
+                if sum.is_nan() || sum <= 0.0 {
+                    return None;
+                }
+                chol[i * dim + i] = sum.sqrt();
+            } else {
+                chol[i * dim + j] = sum / chol[j * dim + j];
+            }
+        }
+    }
+    Some(chol)
+}
+
+/// Solves `L * L^T * x = b` for `x` by forward and back substitution, where
+/// `chol` is the lower triangular Cholesky factor `L` of dimension
+/// `b.len()` in row-major order
+pub fn cholesky_solve(chol: &[f64], b: &[f64]) -> Vec<f64> {
+    let dim = b.len();
+    let mut y = vec![0.0; dim];
+    for i in 0..dim {
+        let sum = (0..i).fold(b[i], |acc, k| acc - chol[i * dim + k] * y[k]);
+        y[i] = sum / chol[i * dim + i];
+    }
+    let mut x = vec![0.0; dim];
+    for i in (0..dim).rev() {
+        let sum = (i + 1..dim).fold(y[i], |acc, k| acc - chol[k * dim + i] * x[k]);
+        x[i] = sum / chol[i * dim + i];
+    }
+    x
+}
+
+// Absolute tolerance on the unit diagonal and the symmetry of a correlation
+// matrix
+const CORRELATION_TOLERANCE: f64 = 1e-12;
+
+/// Draws a standard multivariate normal sample with the correlation matrix
+/// whose lower triangular Cholesky factor `chol` of dimension `dim` is given
+/// in row-major order
+pub fn sample_correlated_normals<R: Rng + ?Sized>(r: &mut R, chol: &[f64], dim: usize) -> Vec<f64> {
+    let z: Vec<f64> = (0..dim)
+        .map(|_| super::normal::sample_unchecked(r, 0.0, 1.0))
+        .collect();
+    (0..dim)
+        .map(|i| (0..i + 1).fold(0.0, |acc, k| acc + chol[i * dim + k] * z[k]))
+        .collect()
+}
+
+/// Returns the values of the parameters named `names` from the labeled
+/// `params`, in the order of `names`. Fails if any name is missing or
+/// repeated, or if `params` contains a name not in `names`
+pub fn parameter_values(params: &[(&str, f64)], names: &[&str]) -> Result<Vec<f64>> {
+    if params.len() != names.len() {
+        return Err(StatsError::BadParams);
+    }
+    names
+        .iter()
+        .map(|name| {
+            let mut matches = params.iter().filter(|p| p.0 == *name);
+            match (matches.next(), matches.next()) {
+                (Some(p), None) => Ok(p.1),
+                _ => Err(StatsError::BadParams),
+            }
+        })
+        .collect()
+}
+
+/// Converts the parameter `x` to an unsigned integer, failing if it is not a
+/// non-negative integer representable as a `u64`
+pub fn u64_parameter(x: f64) -> Result<u64> {
+    if x.is_finite() && x >= 0.0 && x.fract() == 0.0 && x < u64::MAX as f64 {
+        Ok(x as u64)
+    } else {
+        Err(StatsError::BadParams)
+    }
+}
+
+/// Converts the parameter `x` to a signed integer, failing if it is not an
+/// integer representable as an `i64`
+pub fn i64_parameter(x: f64) -> Result<i64> {
+    if x.is_finite() && x.fract() == 0.0 && x >= i64::MIN as f64 && x < -(i64::MIN as f64) {
+        Ok(x as i64)
+    } else {
+        Err(StatsError::BadParams)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
-    use super::is_valid_multinomial;
+    use super::{
+        cholesky, cholesky_solve, correlation_cholesky, i64_parameter, is_valid_multinomial,
+        parameter_values, u64_parameter,
+    };
     use distribution::{Continuous, Discrete, Univariate};
     use std::f64;
 
@@ -127,6 +275,35 @@ pub mod test {
         check_sum_pmf_is_cdf(dist, x_max);
     }
 
+    #[test]
+    fn test_parameter_values() {
+        let names = ["a", "b"];
+        assert_eq!(
+            parameter_values(&[("b", 2.0), ("a", 1.0)], &names).unwrap(),
+            vec![1.0, 2.0]
+        );
+        assert!(parameter_values(&[("a", 1.0)], &names).is_err());
+        assert!(parameter_values(&[("a", 1.0), ("a", 2.0)], &names).is_err());
+        assert!(parameter_values(&[("a", 1.0), ("c", 2.0)], &names).is_err());
+        assert!(parameter_values(&[("a", 1.0), ("b", 2.0), ("c", 3.0)], &names).is_err());
+        assert!(parameter_values(&[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_integer_parameters() {
+        assert_eq!(u64_parameter(0.0).unwrap(), 0);
+        assert_eq!(u64_parameter(12.0).unwrap(), 12);
+        assert!(u64_parameter(-1.0).is_err());
+        assert!(u64_parameter(1.5).is_err());
+        assert!(u64_parameter(f64::NAN).is_err());
+        assert!(u64_parameter(f64::INFINITY).is_err());
+        assert!(u64_parameter(1e20).is_err());
+        assert_eq!(i64_parameter(-12.0).unwrap(), -12);
+        assert!(i64_parameter(0.5).is_err());
+        assert!(i64_parameter(1e19).is_err());
+        assert!(i64_parameter(f64::NEG_INFINITY).is_err());
+    }
+
     #[test]
     fn test_is_valid_multinomial() {
         use std::f64;
@@ -146,4 +323,48 @@ pub mod test {
         let invalid = [5.2, 0.0, 1e-15, 1000000.12];
         assert!(!is_valid_multinomial(&invalid, false));
     }
+
+    #[test]
+    fn test_correlation_cholesky() {
+        let corr = [1.0, 0.6, 0.3, 0.6, 1.0, 0.2, 0.3, 0.2, 1.0];
+        let (dim, chol) = correlation_cholesky(&corr).unwrap();
+        assert_eq!(dim, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                let prod = (0..3).fold(0.0, |acc, k| acc + chol[i * 3 + k] * chol[j * 3 + k]);
+                assert!((prod - corr[i * 3 + j]).abs() < 1e-15);
+            }
+            for j in i + 1..3 {
+                assert_eq!(chol[i * 3 + j], 0.0);
+            }
+        }
+        assert_eq!(correlation_cholesky(&[1.0]).unwrap(), (1, vec![1.0]));
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        // a symmetric positive definite matrix that is not a correlation
+        // matrix, with a known solution
+        let a = [4.0, 2.0, -2.0, 2.0, 10.0, 4.0, -2.0, 4.0, 9.0];
+        let chol = cholesky(&a, 3).unwrap();
+        let x = cholesky_solve(&chol, &[2.0, 34.0, 33.0]);
+        for (x, expected) in x.iter().zip([1.0, 2.0, 3.0].iter()) {
+            assert!((x - expected).abs() < 1e-14);
+        }
+        assert!(cholesky(&[1.0, 2.0, 2.0, 1.0], 2).is_none());
+        assert!(cholesky(&[f64::NAN], 1).is_none());
+        assert_eq!(cholesky(&[], 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_correlation_cholesky_invalid() {
+        assert!(correlation_cholesky(&[]).is_err());
+        assert!(correlation_cholesky(&[1.0, 0.5, 0.5]).is_err());
+        assert!(correlation_cholesky(&[1.0, 0.5, 0.4, 1.0]).is_err());
+        assert!(correlation_cholesky(&[2.0, 0.5, 0.5, 2.0]).is_err());
+        assert!(correlation_cholesky(&[1.0, f64::NAN, f64::NAN, 1.0]).is_err());
+        // singular and indefinite matrices
+        assert!(correlation_cholesky(&[1.0, 1.0, 1.0, 1.0]).is_err());
+        assert!(correlation_cholesky(&[1.0, 0.9, -0.9, 0.9, 1.0, 0.9, -0.9, 0.9, 1.0]).is_err());
+    }
 }
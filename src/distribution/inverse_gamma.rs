@@ -1,4 +1,4 @@
-use distribution::{Continuous, Univariate};
+use distribution::{internal, Continuous, Domain, Parametric, Support, Univariate};
 use function::gamma;
 use rand::distributions::Distribution;
 use rand::Rng;
@@ -91,6 +91,21 @@ impl InverseGamma {
     }
 }
 
+impl Parametric for InverseGamma {
+    /// Returns the `shape` and `rate` parameters of the
+    /// inverse gamma distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape", self.shape), ("rate", self.rate)]
+    }
+
+    /// Constructs an inverse gamma distribution from its `shape` and `rate`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<InverseGamma> {
+        let p = internal::parameter_values(params, &["shape", "rate"])?;
+        InverseGamma::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for InverseGamma {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         1.0 / super::gamma::sample_unchecked(r, self.shape, self.rate)
@@ -151,6 +166,24 @@ impl Max<f64> for InverseGamma {
     }
 }
 
+impl Domain for InverseGamma {
+    /// Returns the support of the inverse gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: false,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for InverseGamma {
     /// Returns the mean of the inverse distribution
     ///
@@ -229,6 +262,26 @@ impl Variance<f64> for InverseGamma {
     }
 }
 
+impl Dispersion for InverseGamma {
+    /// Returns the coefficient of variation of the distribution, or `NaN`
+    /// if its mean is zero or its mean or variance is undefined
+    fn coefficient_of_variation(&self) -> f64 {
+        match (self.checked_mean(), self.checked_std_dev()) {
+            (Ok(mean), Ok(std_dev)) if mean != 0.0 && mean.is_finite() => std_dev / mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Returns the index of dispersion of the distribution, or `NaN` if its
+    /// mean is zero or its mean or variance is undefined
+    fn index_of_dispersion(&self) -> f64 {
+        match (self.checked_mean(), self.checked_variance()) {
+            (Ok(mean), Ok(variance)) if mean != 0.0 && mean.is_finite() => variance / mean,
+            _ => f64::NAN,
+        }
+    }
+}
+
 impl CheckedVariance<f64> for InverseGamma {
     /// Returns the variance of the inverse gamma distribution
     ///
@@ -0,0 +1,545 @@
+use distribution::{internal, Continuous, Domain, Parametric, Support, Univariate};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the
+/// [Irwin-Hall](https://en.wikipedia.org/wiki/Irwin%E2%80%93Hall_distribution)
+/// distribution, the distribution of the sum of `n` independent uniform
+/// random variables on `[0, 1]`
+///
+/// # Remarks
+///
+/// For `n <= 500` the density and distribution function are evaluated
+/// exactly by the recursion of the cardinal B-splines, whose terms are all
+/// nonnegative, at a cost that grows with `n * min(x, n - x)`. For larger
+/// `n` they are approximated by the first order Edgeworth expansion about
+/// the normal distribution, whose absolute error is of order `1 / n^2` but
+/// whose relative error grows in the far tails.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{IrwinHall, Continuous};
+/// use statrs::statistics::Mean;
+///
+/// let n = IrwinHall::new(3).unwrap();
+/// assert_eq!(n.mean(), 1.5);
+/// assert_eq!(n.pdf(1.5), 0.75);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IrwinHall {
+    n: u64,
+}
+
+impl IrwinHall {
+    /// Constructs a new Irwin-Hall distribution of the sum of `n` uniform
+    /// random variables
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n == 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::IrwinHall;
+    ///
+    /// let mut result = IrwinHall::new(3);
+    /// assert!(result.is_ok());
+    ///
+    /// result = IrwinHall::new(0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(n: u64) -> Result<IrwinHall> {
+        if n == 0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(IrwinHall { n: n })
+        }
+    }
+
+    /// Returns the number of uniform random variables summed by the
+    /// Irwin-Hall distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::IrwinHall;
+    ///
+    /// let n = IrwinHall::new(3).unwrap();
+    /// assert_eq!(n.n(), 3);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl Parametric for IrwinHall {
+    /// Returns the `n` parameter of the Irwin-Hall distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("n", self.n as f64)]
+    }
+
+    /// Constructs an Irwin-Hall distribution from its `n` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<IrwinHall> {
+        let p = internal::parameter_values(params, &["n"])?;
+        IrwinHall::new(internal::u64_parameter(p[0])?)
+    }
+}
+
+impl Distribution<f64> for IrwinHall {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        (0..self.n).map(|_| r.gen::<f64>()).sum()
+    }
+}
+
+impl Univariate<f64, f64> for IrwinHall {
+    /// Calculates the cumulative distribution function for the Irwin-Hall
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / n!) * Σ_(k = 0)^(floor(x)) (-1)^k * (n choose k) * (x - k)^n
+    /// ```
+    fn cdf(&self, x: f64) -> f64 {
+        cdf_unchecked(self.n, x)
+    }
+}
+
+impl Min<f64> for IrwinHall {
+    /// Returns the minimum value in the domain of the Irwin-Hall
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for IrwinHall {
+    /// Returns the maximum value in the domain of the Irwin-Hall
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n
+    /// ```
+    fn max(&self) -> f64 {
+        self.n as f64
+    }
+}
+
+impl Domain for IrwinHall {
+    /// Returns the support of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, n]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: self.n as f64,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
+impl Mean<f64> for IrwinHall {
+    /// Returns the mean of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n / 2
+    /// ```
+    fn mean(&self) -> f64 {
+        self.n as f64 / 2.0
+    }
+}
+
+impl Variance<f64> for IrwinHall {
+    /// Returns the variance of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n / 12
+    /// ```
+    fn variance(&self) -> f64 {
+        self.n as f64 / 12.0
+    }
+
+    /// Returns the standard deviation of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(n / 12)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for IrwinHall {}
+
+impl Skewness<f64> for IrwinHall {
+    /// Returns the skewness of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Median<f64> for IrwinHall {
+    /// Returns the median of the Irwin-Hall distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n / 2
+    /// ```
+    fn median(&self) -> f64 {
+        self.n as f64 / 2.0
+    }
+}
+
+impl Mode<f64> for IrwinHall {
+    /// Returns the mode of the Irwin-Hall distribution
+    ///
+    /// # Remarks
+    ///
+    /// For `n == 1` every point of `[0, 1]` is a mode and the middle one is
+    /// returned
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n / 2
+    /// ```
+    fn mode(&self) -> f64 {
+        self.n as f64 / 2.0
+    }
+}
+
+impl Continuous<f64, f64> for IrwinHall {
+    /// Calculates the probability density function for the Irwin-Hall
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / (n - 1)!) * Σ_(k = 0)^(floor(x)) (-1)^k * (n choose k) * (x - k)^(n - 1)
+    /// ```
+    fn pdf(&self, x: f64) -> f64 {
+        pdf_unchecked(self.n, x)
+    }
+
+    /// Calculates the log probability density function for the Irwin-Hall
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / (n - 1)!) * Σ_(k = 0)^(floor(x)) (-1)^k * (n choose k) * (x - k)^(n - 1))
+    /// ```
+    fn ln_pdf(&self, x: f64) -> f64 {
+        pdf_unchecked(self.n, x).ln()
+    }
+}
+
+/// performs an unchecked pdf calculation for the sum of `n` uniform random
+/// variables at `x`, using the symmetry about `n / 2` to evaluate the lower
+/// half of the support
+pub fn pdf_unchecked(n: u64, x: f64) -> f64 {
+    let nf = n as f64;
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x < 0.0 || x > nf {
+        return 0.0;
+    }
+    let x = x.min(nf - x);
+    if n > EXACT_LIMIT {
+        let (z, sd) = standardize(n, x);
+        let he4 = z * z * z * z - 6.0 * z * z + 3.0;
+        let density = super::normal::pdf_unchecked(z, 0.0, 1.0) * (1.0 + excess(n) / 24.0 * he4);
+        return (density / sd).max(0.0);
+    }
+    if n == 1 {
+        return 1.0;
+    }
+    // the density of the sum of n uniforms is the difference of the cdfs of
+    // the sum of n - 1 uniforms at x and x - 1
+    let cdfs = spline_cdfs(n - 1, x);
+    (cdfs[0] - cdfs.get(1).cloned().unwrap_or(0.0)).max(0.0)
+}
+
+/// performs an unchecked cdf calculation for the sum of `n` uniform random
+/// variables at `x`, using the symmetry about `n / 2` to evaluate the lower
+/// half of the support
+pub fn cdf_unchecked(n: u64, x: f64) -> f64 {
+    let nf = n as f64;
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= nf {
+        return 1.0;
+    }
+    let lower = |x: f64| {
+        if n > EXACT_LIMIT {
+            let (z, _) = standardize(n, x);
+            let he3 = z * z * z - 3.0 * z;
+            let correction = super::normal::pdf_unchecked(z, 0.0, 1.0) * excess(n) / 24.0 * he3;
+            (super::normal::cdf_unchecked(z, 0.0, 1.0) - correction).clamp(0.0, 1.0)
+        } else {
+            spline_cdfs(n, x)[0]
+        }
+    };
+    if x <= nf - x {
+        lower(x)
+    } else {
+        1.0 - lower(nf - x)
+    }
+}
+
+// Largest number of summands whose density and distribution function are
+// evaluated exactly
+const EXACT_LIMIT: u64 = 500;
+
+/// Returns the standardized value of `x` for the sum of `n` uniforms and
+/// the standard deviation of the sum
+fn standardize(n: u64, x: f64) -> (f64, f64) {
+    let sd = (n as f64 / 12.0).sqrt();
+    ((x - n as f64 / 2.0) / sd, sd)
+}
+
+/// Returns the excess kurtosis of the sum of `n` uniforms
+fn excess(n: u64) -> f64 {
+    -1.2 / n as f64
+}
+
+/// Computes the cdf of the sum of `n` uniforms at `x - j` for
+/// `j = 0, ..., floor(x)` by the recursion
+/// `F_m(y) = (y * F_(m - 1)(y) + (m - y) * F_(m - 1)(y - 1)) / m`
+/// starting from the unit step `F_0`
+fn spline_cdfs(n: u64, x: f64) -> Vec<f64> {
+    let mut cdfs = vec![1.0; x.floor() as usize + 1];
+    let len = cdfs.len();
+    for m in 1..n + 1 {
+        let m = m as f64;
+        for j in 0..len {
+            let y = x - j as f64;
+            let shifted = if j + 1 < len { cdfs[j + 1] } else { 0.0 };
+            cdfs[j] = (y * cdfs[j] + (m - y) * shifted) / m;
+        }
+    }
+    cdfs
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Bates, Continuous, IrwinHall, Parametric, Univariate};
+    use distribution::internal::*;
+    use function::integration::adaptive_quadrature;
+    use rng;
+
+    fn try_create(n: u64) -> IrwinHall {
+        let d = IrwinHall::new(n);
+        assert!(d.is_ok());
+        d.unwrap()
+    }
+
+    fn test_case<F>(n: u64, expected: f64, eval: F)
+        where F: Fn(IrwinHall) -> f64
+    {
+        let x = eval(try_create(n));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(n: u64, expected: f64, acc: f64, eval: F)
+        where F: Fn(IrwinHall) -> f64
+    {
+        let x = eval(try_create(n));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(1).n(), 1);
+        assert_eq!(try_create(10).n(), 10);
+        assert!(IrwinHall::new(0).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_case(3, 1.5, |x| x.mean());
+        test_case(3, 1.5, |x| x.median());
+        test_case(3, 1.5, |x| x.mode());
+        test_case(3, 0.0, |x| x.skewness());
+        test_case(3, 0.25, |x| x.variance());
+        test_case(3, 0.5, |x| x.std_dev());
+        test_case(12, 1.0, |x| x.variance());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(4, 0.0, |x| x.min());
+        test_case(4, 4.0, |x| x.max());
+    }
+
+    #[test]
+    fn test_pdf_tent() {
+        // n = 2 gives the triangular density on [0, 2]
+        let d = try_create(2);
+        for &x in &[0.0f64, 0.3, 0.5, 1.0, 1.25, 1.9, 2.0] {
+            let expected = if x <= 1.0 { x } else { 2.0 - x };
+            assert_almost_eq!(d.pdf(x), expected, 1e-15);
+        }
+        test_case(2, 0.0, |x| x.pdf(-0.1));
+        test_case(2, 0.0, |x| x.pdf(2.1));
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_case(1, 1.0, |x| x.pdf(0.0));
+        test_case(1, 1.0, |x| x.pdf(0.5));
+        test_case(1, 1.0, |x| x.pdf(1.0));
+        test_case(3, 0.75, |x| x.pdf(1.5));
+        test_almost(3, 0.045, 1e-16, |x| x.pdf(0.3));
+        test_almost(3, 0.045, 1e-15, |x| x.pdf(2.7));
+        test_almost(10, 0.24314925044091712, 1e-14, |x| x.pdf(4.0));
+        test_almost(20, 0.3066931017379824, 1e-13, |x| x.pdf(10.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        test_almost(3, 0.75f64.ln(), 1e-15, |x| x.ln_pdf(1.5));
+        test_case(3, f64::NEG_INFINITY, |x| x.ln_pdf(-0.1));
+        test_case(3, f64::NEG_INFINITY, |x| x.ln_pdf(3.5));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_case(2, 0.0, |x| x.cdf(0.0));
+        test_almost(2, 0.125, 1e-15, |x| x.cdf(0.5));
+        test_almost(2, 0.875, 1e-15, |x| x.cdf(1.5));
+        test_almost(3, 1.0 / 6.0, 1e-15, |x| x.cdf(1.0));
+        test_almost(3, 0.5, 1e-15, |x| x.cdf(1.5));
+        test_almost(10, 0.1389015652557319, 1e-14, |x| x.cdf(4.0));
+        test_case(3, 1.0, |x| x.cdf(3.0));
+        test_case(3, 1.0, |x| x.cdf(10.0));
+    }
+
+    #[test]
+    fn test_large_n() {
+        // exact values that the alternating sum loses entirely to cancellation
+        test_almost(100, 0.13799020407550003, 1e-14, |x| x.pdf(50.0));
+        test_almost(100, 1.6944608367520422e-12, 1e-24, |x| x.pdf(30.0));
+        test_almost(100, 1.6905451086423e-87, 1e-99, |x| x.pdf(5.0));
+        test_almost(100, 0.364704863595912, 1e-14, |x| x.cdf(49.0));
+        test_almost(100, 0.00025065623009830237, 1e-16, |x| x.cdf(40.0));
+        test_almost(100, 0.9997493437699017, 1e-14, |x| x.cdf(60.0));
+        test_almost(200, 0.09764718375128739, 1e-14, |x| x.pdf(100.0));
+        // sqrt(12 / (2πn)) by the central limit theorem
+        test_almost(100, 0.13819765978853418, 5e-4, |x| x.pdf(50.0));
+        let d = try_create(100);
+        for i in 0..101 {
+            let x = d.cdf(i as f64);
+            assert!(x >= 0.0 && x <= 1.0);
+            assert!(d.pdf(i as f64) >= 0.0);
+        }
+        for &n in &[170u64, 400] {
+            let d = try_create(n);
+            assert!(d.pdf(n as f64 / 2.0).is_finite());
+            assert!(d.cdf(n as f64 / 3.0).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_edgeworth() {
+        // beyond the exact evaluation the expansion is accurate to its order
+        test_almost(501, 0.06172367247499593, 1e-7, |x| x.pdf(250.5));
+        // far in the tail only the absolute error stays small
+        test_almost(501, 2.3801549917124807e-15, 1e-15, |x| x.pdf(200.0));
+        test_almost(501, 0.052084428934718194, 1e-7, |x| x.cdf(240.0));
+        test_almost(501, 0.5, 1e-15, |x| x.cdf(250.5));
+        let d = try_create(10000);
+        for &x in &[0.0f64, 4000.0, 4900.0, 5000.0, 5100.0, 6000.0, 10000.0] {
+            assert!(d.cdf(x) >= 0.0 && d.cdf(x) <= 1.0);
+            assert!(d.pdf(x) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_pdf_integrates_to_one() {
+        for n in 1..9 {
+            let d = try_create(n);
+            // integrate piecewise so each interval holds a single polynomial
+            let total: f64 = (0..n)
+                .map(|k| adaptive_quadrature(|x| d.pdf(x), k as f64, k as f64 + 1.0, 1e-14))
+                .sum();
+            assert_almost_eq!(total, 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_bates() {
+        // the Bates distribution is the Irwin-Hall distribution scaled by 1 / n
+        let d = try_create(5);
+        let b = Bates::new(5).unwrap();
+        for &x in &[0.3f64, 1.7, 2.5, 4.2] {
+            assert_almost_eq!(b.cdf(x / 5.0), d.cdf(x), 1e-15);
+            assert_almost_eq!(b.pdf(x / 5.0), 5.0 * d.pdf(x), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = rng::seeded(0);
+        let d = try_create(4);
+        let samples: Vec<f64> = (0..20000).map(|_| d.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0 && x <= 4.0));
+        assert_almost_eq!(samples.iter().mean(), 2.0, 0.02);
+        assert_almost_eq!(samples.iter().variance(), 1.0 / 3.0, 0.015);
+    }
+
+    #[test]
+    fn test_parameters() {
+        let d = try_create(7);
+        assert_eq!(d.parameters(), vec![("n", 7.0)]);
+        assert_eq!(IrwinHall::from_parameters(&d.parameters()).unwrap(), d);
+        assert!(IrwinHall::from_parameters(&[("n", 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1), 0.0, 1.0);
+        test::check_continuous_distribution(&try_create(2), 0.0, 2.0);
+        test::check_continuous_distribution(&try_create(5), 0.0, 5.0);
+    }
+}
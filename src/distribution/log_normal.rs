@@ -1,4 +1,6 @@
-use distribution::{Continuous, Univariate};
+use distribution::{
+    internal, Continuous, Domain, Moments, Normal, Parametric, Support, Univariate,
+};
 use function::erf;
 use rand::distributions::Distribution;
 use rand::Rng;
@@ -57,6 +59,58 @@ impl LogNormal {
             })
         }
     }
+
+    /// Returns the normal distribution of the logarithm of a log-normal
+    /// random variable, i.e. the normal distribution with a mean of
+    /// `location` and a standard deviation of `scale`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{LogNormal, Normal};
+    ///
+    /// let n = LogNormal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.underlying_normal(), Normal::new(1.0, 2.0).unwrap());
+    /// ```
+    pub fn underlying_normal(&self) -> Normal {
+        Normal::new(self.location, self.scale).unwrap()
+    }
+}
+
+impl<'a> From<&'a Normal> for LogNormal {
+    /// Converts a normal distribution into the log-normal distribution of
+    /// its exponential, with a location equal to the mean and a scale
+    /// equal to the standard deviation of the normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{LogNormal, Normal};
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(LogNormal::from(&n), LogNormal::new(1.0, 2.0).unwrap());
+    /// ```
+    fn from(normal: &'a Normal) -> LogNormal {
+        LogNormal {
+            location: normal.mean(),
+            scale: normal.std_dev(),
+        }
+    }
+}
+
+impl Parametric for LogNormal {
+    /// Returns the `location` and `scale` parameters of the
+    /// log-normal distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("location", self.location), ("scale", self.scale)]
+    }
+
+    /// Constructs a log-normal distribution from its `location` and `scale`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<LogNormal> {
+        let p = internal::parameter_values(params, &["location", "scale"])?;
+        LogNormal::new(p[0], p[1])
+    }
 }
 
 impl Distribution<f64> for LogNormal {
@@ -117,6 +171,24 @@ impl Max<f64> for LogNormal {
     }
 }
 
+impl Domain for LogNormal {
+    /// Returns the support of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: false,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for LogNormal {
     /// Returns the mean of the log-normal distribution
     ///
@@ -161,6 +233,8 @@ impl Variance<f64> for LogNormal {
     }
 }
 
+impl Dispersion for LogNormal {}
+
 impl Entropy<f64> for LogNormal {
     /// Returns the entropy of the log-normal distribution
     ///
@@ -266,13 +340,16 @@ impl Continuous<f64, f64> for LogNormal {
     }
 }
 
+impl Moments for LogNormal {}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, LogNormal};
+    use distribution::{Univariate, Continuous, LogNormal, Normal};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(mean: f64, std_dev: f64) -> LogNormal {
         let n = LogNormal::new(mean, std_dev);
@@ -617,4 +694,27 @@ mod test {
         test::check_continuous_distribution(&try_create(0.0, 0.25), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(0.0, 0.5), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_from_normal() {
+        let normal = Normal::new(-1.5, 0.25).unwrap();
+        let n = LogNormal::from(&normal);
+        assert_eq!(n, try_create(-1.5, 0.25));
+        assert_eq!(n.underlying_normal(), normal);
+        assert_eq!(LogNormal::from(&n.underlying_normal()), n);
+        assert_eq!(n.median(), normal.mean().exp());
+    }
+
+    #[test]
+    fn test_sample_matches_underlying_normal() {
+        use rand::distributions::Distribution;
+
+        let n = try_create(0.5, 1.5);
+        let normal = n.underlying_normal();
+        let mut r1 = rng::seeded(0);
+        let mut r2 = rng::seeded(0);
+        for _ in 0..1000 {
+            assert_eq!(n.sample(&mut r1), normal.sample(&mut r2).exp());
+        }
+    }
 }
@@ -0,0 +1,486 @@
+use distribution::{internal, Continuous, Domain, Moments, Support, Univariate};
+use function::integration;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// The maximum entropy density on an interval subject to constraints on
+/// some of its raw moments, constructed by `max_entropy_fit`
+///
+/// # Remarks
+///
+/// The density has the exponential family form
+/// `exp(λ_1 * x^k_1 + ... + λ_m * x^k_m) / Z` on the support, where `k_i`
+/// are the orders of the constrained moments, `λ_i` their Lagrange
+/// multipliers and `Z` the normalizing constant. The cdf is evaluated by
+/// numerical integration of the density.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::distribution::{max_entropy_fit, Continuous, Exponential};
+///
+/// # fn main() {
+/// // the exponential distribution maximizes entropy on [0, ∞) for a
+/// // given mean
+/// let n = max_entropy_fit((0.0, std::f64::INFINITY), &[(1, 2.0)]);
+/// let e = Exponential::new(0.5).unwrap();
+/// assert_almost_eq!(n.pdf(1.0), e.pdf(1.0), 1e-8);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxEntDensity {
+    min: f64,
+    max: f64,
+    orders: Vec<u32>,
+    multipliers: Vec<f64>,
+    moments: Vec<f64>,
+    ln_normalizer: f64,
+}
+
+impl MaxEntDensity {
+    /// Returns the order of each constrained moment together with its
+    /// Lagrange multiplier, the coefficient of `x^k` in the log density
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::max_entropy_fit;
+    ///
+    /// let n = max_entropy_fit((0.0, 1.0), &[(1, 0.5)]);
+    /// assert_eq!(n.multipliers().len(), 1);
+    /// assert!(n.multipliers()[0].1.abs() < 1e-8);
+    /// ```
+    pub fn multipliers(&self) -> Vec<(u32, f64)> {
+        self.orders
+            .iter()
+            .cloned()
+            .zip(self.multipliers.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the unnormalized log density at `x`
+    fn exponent(&self, x: f64) -> f64 {
+        exponent(&self.orders, &self.multipliers, x)
+    }
+}
+
+/// Returns the density of maximum entropy on `support` whose raw moments
+/// match `moments`, given as pairs of an order `k` and the value of
+/// `E[X^k]`
+///
+/// # Panics
+///
+/// If `checked_max_entropy_fit` would return an error
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::distribution::{max_entropy_fit, Continuous, Normal};
+///
+/// # fn main() {
+/// // the normal distribution maximizes entropy on the real line for a
+/// // given mean and variance
+/// let n = max_entropy_fit((std::f64::NEG_INFINITY, std::f64::INFINITY), &[(1, 0.0), (2, 1.0)]);
+/// let z = Normal::new(0.0, 1.0).unwrap();
+/// assert_almost_eq!(n.pdf(0.5), z.pdf(0.5), 1e-8);
+/// # }
+/// ```
+pub fn max_entropy_fit(support: (f64, f64), moments: &[(u32, f64)]) -> MaxEntDensity {
+    checked_max_entropy_fit(support, moments).unwrap()
+}
+
+/// Returns the density of maximum entropy on `support` whose raw moments
+/// match `moments`, given as pairs of an order `k` and the value of
+/// `E[X^k]`
+///
+/// # Remarks
+///
+/// The Lagrange multipliers are found by damped Newton iteration on the
+/// convex dual problem, minimizing `ln(Z) - Σ λ_i * m_i`, whose gradient is
+/// the residual of the moment constraints and whose Hessian is the
+/// covariance of the constrained powers of `x`. The integrals are evaluated
+/// with adaptive quadrature, so the density should not be too concentrated
+/// relative to the scale of its support. Without any constraints the
+/// result is the uniform distribution on a bounded support.
+///
+/// # Errors
+///
+/// If either bound of `support` is `NaN` or `support.0 >= support.1`, if a
+/// moment order is `0` or repeated, if a moment value is not finite, if the
+/// support is unbounded and no moment is constrained, if the support is
+/// unbounded in both directions and the highest constrained order is odd,
+/// or if the iteration fails to converge, as it does when no density
+/// matches the moments
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::checked_max_entropy_fit;
+///
+/// assert!(checked_max_entropy_fit((0.0, 1.0), &[(1, 0.3)]).is_ok());
+/// // the mean must lie inside the support
+/// assert!(checked_max_entropy_fit((0.0, 1.0), &[(1, 1.5)]).is_err());
+/// assert!(checked_max_entropy_fit((0.0, std::f64::INFINITY), &[]).is_err());
+/// ```
+pub fn checked_max_entropy_fit(
+    support: (f64, f64),
+    moments: &[(u32, f64)],
+) -> Result<MaxEntDensity> {
+    let (min, max) = support;
+    if min.is_nan() || max.is_nan() || min >= max {
+        return Err(StatsError::BadParams);
+    }
+    let orders: Vec<u32> = moments.iter().map(|m| m.0).collect();
+    let targets: Vec<f64> = moments.iter().map(|m| m.1).collect();
+    if orders.contains(&0) || targets.iter().any(|m| !m.is_finite()) {
+        return Err(StatsError::BadParams);
+    }
+    for (i, k) in orders.iter().enumerate() {
+        if orders[..i].contains(k) {
+            return Err(StatsError::BadParams);
+        }
+    }
+
+    // the index of the highest order, whose multiplier must make the density
+    // decay towards any infinite end of the support
+    let top = (0..orders.len()).max_by_key(|&i| orders[i]);
+    let decay_sign = if min.is_finite() && max.is_finite() {
+        None
+    } else {
+        let top = match top {
+            Some(top) => top,
+            None => return Err(StatsError::BadParams),
+        };
+        let even = orders[top] & 1 == 0;
+        if min.is_infinite() && max.is_infinite() && !even {
+            return Err(StatsError::SpecialCase(
+                "the highest constrained moment order must be even on the real line",
+            ));
+        }
+        // the sign of x^k towards the infinite end of the support
+        Some(if max.is_infinite() || even { 1.0 } else { -1.0 })
+    };
+
+    let mut lambda = vec![0.0; orders.len()];
+    if let (Some(top), Some(sign)) = (top, decay_sign) {
+        // the multiplier of a density proportional to e^(-|x|^k / (k * m))
+        let scale = if targets[top] == 0.0 {
+            1.0
+        } else {
+            targets[top].abs()
+        };
+        lambda[top] = -sign / (f64::from(orders[top]) * scale);
+    }
+
+    let dual = |lambda: &[f64], shift: f64| -> f64 {
+        if let (Some(top), Some(sign)) = (top, decay_sign) {
+            if lambda[top] * sign >= 0.0 {
+                return f64::INFINITY;
+            }
+        }
+        let z = integrate(min, max, |x| (exponent(&orders, lambda, x) - shift).exp());
+        if z.is_finite() && z > 0.0 {
+            let constraint: f64 = lambda.iter().zip(targets.iter()).map(|(l, m)| l * m).sum();
+            shift + z.ln() - constraint
+        } else {
+            f64::INFINITY
+        }
+    };
+
+    let m = orders.len();
+    let mut shift = 0.0;
+    for _ in 0..MAX_ITERATIONS {
+        // the moments of the current density, normalized by z
+        let z = integrate(min, max, |x| (exponent(&orders, &lambda, x) - shift).exp());
+        if !z.is_finite() || z <= 0.0 {
+            break;
+        }
+        shift += z.ln();
+        let expectation = |power: u32| {
+            integrate(min, max, |x| {
+                x.powi(power as i32) * (exponent(&orders, &lambda, x) - shift).exp()
+            })
+        };
+        let means: Vec<f64> = orders.iter().map(|&k| expectation(k)).collect();
+        let gradient: Vec<f64> = means
+            .iter()
+            .zip(targets.iter())
+            .map(|(e, t)| e - t)
+            .collect();
+        let converged = gradient
+            .iter()
+            .zip(targets.iter())
+            .all(|(g, t)| g.abs() <= CONVERGENCE_TOLERANCE * t.abs().max(1.0));
+        if converged {
+            return Ok(MaxEntDensity {
+                min: min,
+                max: max,
+                orders: orders,
+                multipliers: lambda,
+                moments: targets,
+                ln_normalizer: shift,
+            });
+        }
+
+        let mut hessian = vec![0.0; m * m];
+        for i in 0..m {
+            for j in 0..i + 1 {
+                let cov = expectation(orders[i] + orders[j]) - means[i] * means[j];
+                hessian[i * m + j] = cov;
+                hessian[j * m + i] = cov;
+            }
+        }
+        // the hessian is the covariance of the constrained powers and so
+        // positive definite unless the fit has degenerated
+        let neg_gradient: Vec<f64> = gradient.iter().map(|g| -g).collect();
+        let step = match internal::cholesky(&hessian, m) {
+            Some(chol) => internal::cholesky_solve(&chol, &neg_gradient),
+            None => break,
+        };
+
+        // backtrack until the dual objective decreases sufficiently
+        let current = dual(&lambda, shift);
+        let slope: f64 = gradient.iter().zip(step.iter()).map(|(g, s)| g * s).sum();
+        let mut t = 1.0;
+        let mut accepted = false;
+        for _ in 0..MAX_BACKTRACKS {
+            let trial: Vec<f64> = lambda
+                .iter()
+                .zip(step.iter())
+                .map(|(l, s)| l + t * s)
+                .collect();
+            if dual(&trial, shift) <= current + 1e-4 * t * slope {
+                lambda = trial;
+                accepted = true;
+                break;
+            }
+            t /= 2.0;
+        }
+        if !accepted {
+            break;
+        }
+    }
+    Err(StatsError::SpecialCase(
+        "max_entropy_fit failed to converge, the moments may be infeasible",
+    ))
+}
+
+// Maximum number of Newton iterations of max_entropy_fit
+const MAX_ITERATIONS: usize = 100;
+
+// Maximum number of step halvings in each Newton iteration
+const MAX_BACKTRACKS: usize = 60;
+
+// Tolerance on the moment residuals relative to the target moments
+const CONVERGENCE_TOLERANCE: f64 = 1e-10;
+
+/// Computes `Σ λ_i * x^k_i`
+fn exponent(orders: &[u32], lambda: &[f64], x: f64) -> f64 {
+    orders
+        .iter()
+        .zip(lambda.iter())
+        .map(|(&k, l)| l * x.powi(k as i32))
+        .sum()
+}
+
+/// Integrates `f` over `[min, max]`, either of which may be infinite
+fn integrate<F: Fn(f64) -> f64>(min: f64, max: f64, f: F) -> f64 {
+    integration::adaptive_quadrature_infinite(f, min, max, internal::INTEGRATION_TOLERANCE)
+}
+
+impl Univariate<f64, f64> for MaxEntDensity {
+    /// Calculates the cumulative distribution function of the maximum
+    /// entropy density at `x` by numerically integrating the density from
+    /// the lower bound of the support
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            0.0
+        } else if x >= self.max {
+            1.0
+        } else {
+            integrate(self.min, x, |t| self.pdf(t)).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl Min<f64> for MaxEntDensity {
+    /// Returns the lower bound of the support of the maximum entropy density
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl Max<f64> for MaxEntDensity {
+    /// Returns the upper bound of the support of the maximum entropy density
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Domain for MaxEntDensity {
+    /// Returns the support of the maximum entropy density
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.min,
+            hi: self.max,
+            lo_closed: self.min.is_finite(),
+            hi_closed: self.max.is_finite(),
+        }
+    }
+}
+
+impl Entropy<f64> for MaxEntDensity {
+    /// Returns the entropy of the maximum entropy density
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Z) - Σ λ_i * m_i
+    /// ```
+    ///
+    /// where `Z` is the normalizing constant, `λ_i` are the Lagrange
+    /// multipliers and `m_i` the constrained moments
+    fn entropy(&self) -> f64 {
+        let constraint: f64 = self
+            .multipliers
+            .iter()
+            .zip(self.moments.iter())
+            .map(|(l, m)| l * m)
+            .sum();
+        self.ln_normalizer - constraint
+    }
+}
+
+impl Continuous<f64, f64> for MaxEntDensity {
+    /// Calculates the probability density function of the maximum entropy
+    /// density at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// exp(Σ λ_i * x^k_i) / Z
+    /// ```
+    ///
+    /// for `x` in the support, where `Z` is the normalizing constant, `λ_i`
+    /// are the Lagrange multipliers and `k_i` the constrained orders
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function of the maximum
+    /// entropy density at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ λ_i * x^k_i - ln(Z)
+    /// ```
+    ///
+    /// for `x` in the support, where `Z` is the normalizing constant, `λ_i`
+    /// are the Lagrange multipliers and `k_i` the constrained orders
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            f64::NEG_INFINITY
+        } else if x.is_infinite() {
+            // the density decays towards an infinite end of the support
+            f64::NEG_INFINITY
+        } else {
+            self.exponent(x) - self.ln_normalizer
+        }
+    }
+}
+
+impl Moments for MaxEntDensity {}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{checked_max_entropy_fit, max_entropy_fit, Continuous, Exponential, Moments, Normal, Uniform, Univariate};
+
+    #[test]
+    fn test_normal() {
+        let n = max_entropy_fit((f64::NEG_INFINITY, f64::INFINITY), &[(1, 1.0), (2, 5.0)]);
+        let z = Normal::new(1.0, 2.0).unwrap();
+        let multipliers = n.multipliers();
+        assert_eq!(multipliers[0].0, 1);
+        assert_almost_eq!(multipliers[0].1, 0.25, 1e-8);
+        assert_eq!(multipliers[1].0, 2);
+        assert_almost_eq!(multipliers[1].1, -0.125, 1e-8);
+        for &x in &[-5.0f64, -1.0, 0.0, 1.0, 2.5, 7.0] {
+            assert_almost_eq!(n.pdf(x), z.pdf(x), 1e-9);
+            assert_almost_eq!(n.ln_pdf(x), z.ln_pdf(x), 1e-8);
+            assert_almost_eq!(n.cdf(x), z.cdf(x), 1e-8);
+        }
+        assert_almost_eq!(n.entropy(), z.entropy(), 1e-8);
+        assert_almost_eq!(n.raw_moment(1), 1.0, 1e-8);
+        assert_almost_eq!(n.raw_moment(2), 5.0, 1e-8);
+    }
+
+    #[test]
+    fn test_exponential() {
+        let n = max_entropy_fit((0.0, f64::INFINITY), &[(1, 2.0)]);
+        let e = Exponential::new(0.5).unwrap();
+        assert_almost_eq!(n.multipliers()[0].1, -0.5, 1e-9);
+        for &x in &[0.0f64, 0.1, 1.0, 4.0, 20.0] {
+            assert_almost_eq!(n.pdf(x), e.pdf(x), 1e-9);
+            assert_almost_eq!(n.cdf(x), e.cdf(x), 1e-8);
+        }
+        assert_almost_eq!(n.entropy(), e.entropy(), 1e-8);
+        assert_eq!(n.pdf(-1.0), 0.0);
+        assert_eq!(n.cdf(-1.0), 0.0);
+        assert_eq!(n.pdf(f64::INFINITY), 0.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_uniform() {
+        // without informative constraints the bounded maximum entropy
+        // density is uniform
+        let u = Uniform::new(-1.0, 3.0).unwrap();
+        for moments in &[vec![], vec![(1, 1.0)]] {
+            let n = max_entropy_fit((-1.0, 3.0), moments);
+            for &x in &[-1.0f64, 0.0, 2.0, 3.0] {
+                assert_almost_eq!(n.pdf(x), u.pdf(x), 1e-9);
+                assert_almost_eq!(n.cdf(x), u.cdf(x), 1e-9);
+            }
+            assert_almost_eq!(n.entropy(), u.entropy(), 1e-9);
+            assert_eq!(n.min(), -1.0);
+            assert_eq!(n.max(), 3.0);
+        }
+    }
+
+    #[test]
+    fn test_moments_matched() {
+        // a truncated density on a bounded support with a skewed mean and
+        // fixed second and fourth moments
+        let moments = [(1, 0.3), (2, 0.15), (4, 0.05)];
+        let n = max_entropy_fit((0.0, 1.0), &moments);
+        for &(k, m) in &moments {
+            assert_almost_eq!(n.raw_moment(k), m, 1e-8);
+        }
+        assert_almost_eq!(n.cdf(1.0 - 1e-12), 1.0, 1e-9);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let real = (f64::NEG_INFINITY, f64::INFINITY);
+        assert!(checked_max_entropy_fit((1.0, 0.0), &[]).is_err());
+        assert!(checked_max_entropy_fit((f64::NAN, 1.0), &[]).is_err());
+        assert!(checked_max_entropy_fit((0.0, 1.0), &[(0, 1.0)]).is_err());
+        assert!(checked_max_entropy_fit((0.0, 1.0), &[(1, 0.5), (1, 0.5)]).is_err());
+        assert!(checked_max_entropy_fit((0.0, 1.0), &[(1, f64::NAN)]).is_err());
+        assert!(checked_max_entropy_fit(real, &[]).is_err());
+        assert!(checked_max_entropy_fit(real, &[(1, 0.0)]).is_err());
+        // no density has a variance below zero
+        assert!(checked_max_entropy_fit(real, &[(1, 1.0), (2, 0.5)]).is_err());
+    }
+}
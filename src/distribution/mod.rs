@@ -2,35 +2,57 @@
 //! and provides
 //! concrete implementations for a variety of distributions.
 
+pub use self::affine::Affine;
+pub use self::arcsine::Arcsine;
+pub use self::bates::Bates;
 pub use self::bernoulli::Bernoulli;
 pub use self::beta::Beta;
 pub use self::binomial::Binomial;
-pub use self::categorical::Categorical;
+pub use self::categorical::{checked_transition_kl, transition_kl, Categorical};
 pub use self::cauchy::Cauchy;
 pub use self::chi::Chi;
 pub use self::chi_squared::ChiSquared;
+pub use self::contaminated::Contaminated;
 pub use self::dirichlet::Dirichlet;
 pub use self::discrete_uniform::DiscreteUniform;
+pub use self::empirical_discrete::EmpiricalDiscrete;
 pub use self::erlang::Erlang;
-pub use self::exponential::Exponential;
+pub use self::exponential::{exponential_pdf_grid, Exponential};
 pub use self::fisher_snedecor::FisherSnedecor;
-pub use self::gamma::Gamma;
+pub use self::gamma::{gamma_pdf_grid, Gamma};
+pub use self::gaussian_copula::GaussianCopula;
+pub use self::generalized_pareto::GeneralizedPareto;
 pub use self::geometric::Geometric;
+pub use self::half_cauchy::HalfCauchy;
+pub use self::half_normal::HalfNormal;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
+pub use self::irwin_hall::IrwinHall;
 pub use self::log_normal::LogNormal;
+pub use self::max_entropy::{checked_max_entropy_fit, max_entropy_fit, MaxEntDensity};
 pub use self::multinomial::Multinomial;
-pub use self::normal::Normal;
+pub use self::normal::{normal_pdf_grid, Normal, SamplerConfig};
 pub use self::pareto::Pareto;
+pub use self::planck::Planck;
 pub use self::poisson::Poisson;
+pub use self::quantile_table::QuantileTable;
+pub use self::skellam::Skellam;
+pub use self::student_t_copula::StudentTCopula;
 pub use self::students_t::StudentsT;
+pub use self::sum_of_iid::SumOfIid;
 pub use self::triangular::Triangular;
+pub use self::tukey_lambda::TukeyLambda;
 pub use self::uniform::Uniform;
 #[cfg(feature = "von_mises")]
 pub use self::von_mises::VonMises;
-pub use self::weibull::Weibull;
+pub use self::weibull::{HazardShape, Weibull};
+pub use self::yule_simon::YuleSimon;
+use function::integration;
 use statistics::{Max, Min};
 
+mod affine;
+mod arcsine;
+mod bates;
 mod bernoulli;
 mod beta;
 mod binomial;
@@ -38,31 +60,48 @@ mod categorical;
 mod cauchy;
 mod chi;
 mod chi_squared;
+mod contaminated;
 mod dirichlet;
 mod discrete_uniform;
+mod empirical_discrete;
 mod erlang;
 mod exponential;
 mod fisher_snedecor;
 mod gamma;
+mod gaussian_copula;
+mod generalized_pareto;
 mod geometric;
+mod half_cauchy;
+mod half_normal;
 mod hypergeometric;
 mod internal;
 mod inverse_gamma;
+mod irwin_hall;
 mod log_normal;
+mod max_entropy;
 mod multinomial;
 mod normal;
 mod pareto;
+mod planck;
 mod poisson;
+mod quantile_table;
+mod skellam;
+mod student_t_copula;
 mod students_t;
+mod sum_of_iid;
 mod triangular;
+mod tukey_lambda;
 mod uniform;
 #[cfg(feature = "von_mises")]
 mod von_mises;
 mod weibull;
+mod yule_simon;
 mod ziggurat;
 mod ziggurat_tables;
 
-use Result;
+use std::f64;
+use std::ops::Sub;
+use {Result, StatsError};
 
 /// The `Univariate` trait is used to specify an interface for univariate
 /// distributions e.g. distributions that have a closed form cumulative
@@ -82,6 +121,234 @@ pub trait Univariate<T, K>: Min<T> + Max<T> {
     /// assert_eq!(0.5, n.cdf(0.5));
     /// ```
     fn cdf(&self, x: K) -> K;
+
+    /// Returns the probability that a sample falls between `a` and `b`,
+    /// i.e. `cdf(b) - cdf(a)`. Implementors may override this to avoid the
+    /// loss of precision of the subtraction when `a` and `b` lie in the same
+    /// tail.
+    ///
+    /// # Panics
+    ///
+    /// If `a > b`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5, n.cdf_between(0.25, 0.75));
+    /// ```
+    fn cdf_between(&self, a: K, b: K) -> K
+    where
+        K: PartialOrd + Sub<Output = K> + Copy,
+    {
+        if a > b {
+            panic!("{}", StatsError::ArgLteArg("a", "b"));
+        }
+        self.cdf(b) - self.cdf(a)
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x`. Implementors may override this to remain accurate
+    /// far in the lower tail, where `cdf(x)` underflows to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.ln_cdf(0.5), 0.5f64.ln());
+    /// ```
+    fn ln_cdf(&self, x: K) -> f64
+    where
+        K: Into<f64>,
+    {
+        self.cdf(x).into().ln()
+    }
+
+    /// Returns the natural logarithm of the survival function, `1 - cdf(x)`,
+    /// calculated at `x`. Implementors may override this to remain accurate
+    /// far in the upper tail, where `1 - cdf(x)` underflows to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.ln_sf(0.75), 0.25f64.ln());
+    /// ```
+    fn ln_sf(&self, x: K) -> f64
+    where
+        K: Into<f64>,
+    {
+        (-self.cdf(x).into()).ln_1p()
+    }
+
+    /// Returns the cumulative distribution function calculated at each
+    /// of `xs`, i.e. `cdf(xs[i])` for the `i`th entry. Implementors may
+    /// override this to share work between the evaluations, particularly
+    /// when `xs` is sorted, but `xs` need not be sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.cdf_grid(&[0.75, 0.0, 0.5]), vec![0.75, 0.0, 0.5]);
+    /// ```
+    fn cdf_grid(&self, xs: &[f64]) -> Vec<f64>
+    where
+        K: From<f64> + Into<f64>,
+    {
+        xs.iter().map(|&x| self.cdf(K::from(x)).into()).collect()
+    }
+
+    /// Returns the cumulative distribution function calculated at `x`,
+    /// validated against the support of the distribution. Arguments below
+    /// the support give `0` and arguments at or above its upper bound
+    /// give `1`, matching the unchecked `cdf`, and `None` is returned if
+    /// `x` is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Beta, Univariate};
+    ///
+    /// let n = Beta::new(2.0, 2.0).unwrap();
+    /// assert_eq!(n.checked_cdf(-0.1), Some(0.0));
+    /// assert_eq!(n.checked_cdf(1.5), Some(1.0));
+    /// assert_eq!(n.checked_cdf(std::f64::NAN), None);
+    /// ```
+    fn checked_cdf(&self, x: K) -> Option<K>
+    where
+        Self: Domain,
+        K: Copy + Into<f64> + From<f64>,
+    {
+        let (lo, hi) = self.support().bounds();
+        let v: f64 = x.into();
+        if v.is_nan() {
+            None
+        } else if v < lo {
+            Some(K::from(0.0))
+        } else if v >= hi {
+            Some(K::from(1.0))
+        } else {
+            Some(self.cdf(x))
+        }
+    }
+
+    /// Returns the probability density function calculated at `x`,
+    /// validated against the support of the distribution, or `None` if `x`
+    /// is `NaN` or outside of the support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Beta, Continuous, Univariate};
+    ///
+    /// let n = Beta::new(2.0, 2.0).unwrap();
+    /// assert_eq!(n.checked_pdf(0.5), Some(n.pdf(0.5)));
+    /// assert_eq!(n.checked_pdf(-0.1), None);
+    /// ```
+    fn checked_pdf(&self, x: K) -> Option<K>
+    where
+        Self: Continuous<K, K> + Domain,
+        K: Copy + Into<f64>,
+    {
+        if self.support().contains(x.into()) {
+            Some(self.pdf(x))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the log of the probability density function calculated at
+    /// `x`, validated against the support of the distribution, or `None` if
+    /// `x` is `NaN` or outside of the support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exponential, Univariate};
+    ///
+    /// let n = Exponential::new(1.0).unwrap();
+    /// assert_eq!(n.checked_ln_pdf(2.0), Some(-2.0));
+    /// assert_eq!(n.checked_ln_pdf(-2.0), None);
+    /// ```
+    fn checked_ln_pdf(&self, x: K) -> Option<K>
+    where
+        Self: Continuous<K, K> + Domain,
+        K: Copy + Into<f64>,
+    {
+        if self.support().contains(x.into()) {
+            Some(self.ln_pdf(x))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the expected number of `total` samples falling in each of the
+    /// bins bounded by consecutive `bin_edges`, i.e.
+    /// `total * (cdf(e_(i + 1)) - cdf(e_i))` for the `i`th bin. The counts
+    /// only sum to `total` if the edges cover the whole support.
+    ///
+    /// # Panics
+    ///
+    /// If `bin_edges` has fewer than two entries, contains `NaN` or is not
+    /// strictly increasing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.expected_counts(&[0.0, 0.25, 1.0], 100), vec![25.0, 75.0]);
+    /// ```
+    fn expected_counts(&self, bin_edges: &[f64], total: u64) -> Vec<f64>
+    where
+        K: From<f64> + Into<f64>,
+    {
+        self.checked_expected_counts(bin_edges, total).unwrap()
+    }
+
+    /// Returns the expected number of `total` samples falling in each of the
+    /// bins bounded by consecutive `bin_edges`, i.e.
+    /// `total * (cdf(e_(i + 1)) - cdf(e_i))` for the `i`th bin. The counts
+    /// only sum to `total` if the edges cover the whole support.
+    ///
+    /// # Errors
+    ///
+    /// If `bin_edges` has fewer than two entries, contains `NaN` or is not
+    /// strictly increasing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Univariate, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!(n.checked_expected_counts(&[0.0], 100).is_err());
+    /// assert!(n.checked_expected_counts(&[0.5, 0.25], 100).is_err());
+    /// ```
+    fn checked_expected_counts(&self, bin_edges: &[f64], total: u64) -> Result<Vec<f64>>
+    where
+        K: From<f64> + Into<f64>,
+    {
+        if bin_edges.len() < 2 {
+            return Err(StatsError::ArgGte("bin_edges.len()", 2.0));
+        }
+        if bin_edges.iter().any(|x| x.is_nan()) || bin_edges.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(StatsError::BadParams);
+        }
+        let total = total as f64;
+        let cdf = self.cdf_grid(bin_edges);
+        Ok(cdf.windows(2).map(|w| total * (w[1] - w[0])).collect())
+    }
 }
 
 /// The `InverseCDF` trait is used to specify an interface for distributions
@@ -196,6 +463,298 @@ pub trait CheckedContinuous<T, K> {
     fn checked_ln_pdf(&self, x: T) -> Result<K>;
 }
 
+/// The `CensoredLikelihood` trait provides the log-likelihood of right-censored
+/// data for univariate continuous distributions. It is implemented for every
+/// distribution that is both `Continuous` and `Univariate`.
+pub trait CensoredLikelihood {
+    /// Returns the log-likelihood of the observed events in `observed`
+    /// together with the right-censored observations in `censored`, i.e.
+    /// the sum of the log density over `observed` plus the sum of the log
+    /// survival function, `ln_sf`, over `censored`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(ln(f(x_i))) + sum(ln(1 - F(c_j)))
+    /// ```
+    ///
+    /// where `f` is the pdf, `F` is the cdf, `x_i` are the observed events
+    /// and `c_j` are the censoring times
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{CensoredLikelihood, Exponential};
+    ///
+    /// let n = Exponential::new(1.0).unwrap();
+    /// assert_eq!(n.log_likelihood_censored(&[1.0], &[2.0]), -3.0);
+    /// ```
+    fn log_likelihood_censored(&self, observed: &[f64], censored: &[f64]) -> f64;
+}
+
+impl<D> CensoredLikelihood for D
+where
+    D: Continuous<f64, f64> + Univariate<f64, f64>,
+{
+    fn log_likelihood_censored(&self, observed: &[f64], censored: &[f64]) -> f64 {
+        let events: f64 = observed.iter().map(|&x| self.ln_pdf(x)).sum();
+        let survivals: f64 = censored.iter().map(|&x| self.ln_sf(x)).sum();
+        events + survivals
+    }
+}
+
+/// The `Moments` trait provides the raw and central moments of arbitrary
+/// order for univariate continuous distributions. The default
+/// implementations numerically integrate the density over the support of
+/// the distribution, from `min()` to `max()`, and may be overridden by
+/// distributions with closed form moments.
+pub trait Moments: Continuous<f64, f64> + Univariate<f64, f64> {
+    /// Returns the `k`-th raw moment, `E[X^k]`, of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Moments, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.raw_moment(2), 1.0 / 3.0);
+    /// ```
+    fn raw_moment(&self, k: u32) -> f64 {
+        integration::adaptive_quadrature_infinite(
+            |x| x.powi(k as i32) * self.pdf(x),
+            self.min(),
+            self.max(),
+            internal::INTEGRATION_TOLERANCE,
+        )
+    }
+
+    /// Returns the `k`-th central moment, `E[(X - μ)^k]`, of the
+    /// distribution where `μ` is the mean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Moments, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.central_moment(2), 1.0 / 12.0);
+    /// ```
+    fn central_moment(&self, k: u32) -> f64 {
+        let mean = self.raw_moment(1);
+        integration::adaptive_quadrature_infinite(
+            |x| (x - mean).powi(k as i32) * self.pdf(x),
+            self.min(),
+            self.max(),
+            internal::INTEGRATION_TOLERANCE,
+        )
+    }
+}
+
+/// The `NumericalEntropy` trait provides the differential entropy of
+/// univariate continuous distributions by numerically integrating
+/// `-pdf(x) * ln(pdf(x))` over the support from `min()` to `max()`. It is
+/// implemented for every distribution that is both `Continuous` and
+/// `Univariate`, including those without a closed form `Entropy`.
+pub trait NumericalEntropy {
+    /// Returns the entropy computed by numerical integration
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::distribution::{Exponential, NumericalEntropy};
+    ///
+    /// # fn main() {
+    /// let n = Exponential::new(1.0).unwrap();
+    /// assert_almost_eq!(n.numerical_entropy(), 1.0, 1e-10);
+    /// # }
+    /// ```
+    fn numerical_entropy(&self) -> f64;
+}
+
+impl<D> NumericalEntropy for D
+where
+    D: Continuous<f64, f64> + Univariate<f64, f64>,
+{
+    fn numerical_entropy(&self) -> f64 {
+        integration::adaptive_quadrature_infinite(
+            |x| {
+                let p = self.pdf(x);
+                if p > 0.0 {
+                    -p * p.ln()
+                } else {
+                    0.0
+                }
+            },
+            self.min(),
+            self.max(),
+            internal::INTEGRATION_TOLERANCE,
+        )
+    }
+}
+
+/// The `LocationScale` trait specifies a distribution family that is closed
+/// under the location-scale transformation `Y = a + bX` for `b > 0`
+pub trait LocationScale: Sized {
+    /// Returns the distribution of `loc + scale * X` where `X` is
+    /// distributed according to `self`
+    ///
+    /// # Errors
+    ///
+    /// If `scale` is not positive or if the transformed parameters are
+    /// invalid for the distribution, e.g. because `loc` is `NaN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{LocationScale, Normal};
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.transform(3.0, 0.5).unwrap(), Normal::new(3.5, 1.0).unwrap());
+    /// assert!(n.transform(3.0, 0.0).is_err());
+    /// ```
+    fn transform(&self, loc: f64, scale: f64) -> Result<Self>;
+}
+
+/// The `Parametric` trait specifies a distribution described by a fixed set
+/// of named scalar parameters, which allows generic code to enumerate them
+/// e.g. for display or serialization
+pub trait Parametric {
+    /// Returns the parameters of the distribution as pairs of a name and a
+    /// value, in the order of the arguments of its constructor. Integer
+    /// parameters are converted to `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Parametric};
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.parameters(), vec![("mean", 1.0), ("std_dev", 2.0)]);
+    /// ```
+    fn parameters(&self) -> Vec<(&'static str, f64)>;
+
+    /// Constructs the distribution from parameters labeled as by
+    /// `parameters`, in any order. Integer parameters must be given as
+    /// integral values.
+    ///
+    /// # Errors
+    ///
+    /// If a parameter is missing or repeated, if an unknown parameter is
+    /// given, or if the constructor of the distribution rejects the values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Parametric};
+    ///
+    /// let n = Normal::from_parameters(&[("std_dev", 2.0), ("mean", 1.0)]).unwrap();
+    /// assert_eq!(n, Normal::new(1.0, 2.0).unwrap());
+    /// assert!(Normal::from_parameters(&[("mean", 1.0)]).is_err());
+    /// assert!(Normal::from_parameters(&[("mean", 1.0), ("scale", 2.0)]).is_err());
+    /// ```
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+/// Describes the set of values a univariate random variable can take
+#[derive(Debug, Clone, PartialEq)]
+pub enum Support {
+    /// A real interval from `lo` to `hi` where each boundary is either
+    /// included (closed) or excluded (open). Infinite boundaries are
+    /// always open
+    Continuous {
+        lo: f64,
+        hi: f64,
+        lo_closed: bool,
+        hi_closed: bool,
+    },
+    /// All integers from `lo` to `hi` inclusive, where `hi` is infinite
+    /// for distributions with unbounded support
+    DiscreteRange { lo: f64, hi: f64 },
+    /// A finite set of values in ascending order
+    DiscreteSet(Vec<f64>),
+}
+
+impl Support {
+    /// Returns whether `x` lies in the support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Support;
+    ///
+    /// let s = Support::DiscreteRange { lo: 0.0, hi: 3.0 };
+    /// assert!(s.contains(2.0));
+    /// assert!(!s.contains(2.5));
+    /// assert!(!s.contains(4.0));
+    /// ```
+    pub fn contains(&self, x: f64) -> bool {
+        match *self {
+            Support::Continuous {
+                lo,
+                hi,
+                lo_closed,
+                hi_closed,
+            } => (x > lo || (lo_closed && x == lo)) && (x < hi || (hi_closed && x == hi)),
+            Support::DiscreteRange { lo, hi } => {
+                x.is_finite() && x.floor() == x && x >= lo && x <= hi
+            }
+            Support::DiscreteSet(ref values) => values.contains(&x),
+        }
+    }
+
+    /// Returns the smallest and largest values of the support, which are
+    /// the natural bounds for integrating or summing over it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Support;
+    /// use std::f64;
+    ///
+    /// let s = Support::DiscreteSet(vec![1.0, 4.0, 9.0]);
+    /// assert_eq!(s.bounds(), (1.0, 9.0));
+    /// ```
+    pub fn bounds(&self) -> (f64, f64) {
+        match *self {
+            Support::Continuous { lo, hi, .. } | Support::DiscreteRange { lo, hi } => (lo, hi),
+            Support::DiscreteSet(ref values) => (
+                values.first().cloned().unwrap_or(f64::NAN),
+                values.last().cloned().unwrap_or(f64::NAN),
+            ),
+        }
+    }
+}
+
+/// The `Domain` trait specifies that a univariate distribution can describe
+/// its support
+pub trait Domain {
+    /// Returns the support of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Domain, Exponential, Support};
+    /// use std::f64;
+    ///
+    /// let n = Exponential::new(1.0).unwrap();
+    /// assert_eq!(
+    ///     n.support(),
+    ///     Support::Continuous {
+    ///         lo: 0.0,
+    ///         hi: f64::INFINITY,
+    ///         lo_closed: true,
+    ///         hi_closed: false,
+    ///     }
+    /// );
+    /// ```
+    fn support(&self) -> Support;
+}
+
 /// The `Discrete` trait provides an interface for interacting with discrete
 /// statistical distributions
 ///
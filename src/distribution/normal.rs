@@ -1,6 +1,9 @@
-use distribution::{ziggurat, CheckedInverseCDF, Continuous, InverseCDF, Univariate};
+use distribution::{
+    internal, ziggurat, CheckedInverseCDF, Continuous, Domain, InverseCDF, LocationScale, Moments,
+    Parametric, Support, Univariate,
+};
 use function::erf;
-use rand::distributions::Distribution;
+use rand::distributions::{Distribution, Open01};
 use rand::Rng;
 use statistics::*;
 use std::f64;
@@ -19,10 +22,41 @@ use {consts, Result, StatsError};
 /// assert_eq!(n.mean(), 0.0);
 /// assert_eq!(n.pdf(1.0), 0.2419707245191433497978);
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq)]
+///
+/// Two normal distributions compare equal if their means and standard
+/// deviations are equal, regardless of the algorithm they sample with.
+#[derive(Debug, Copy, Clone)]
 pub struct Normal {
     mean: f64,
     std_dev: f64,
+    sampler: SamplerConfig,
+}
+
+/// Selects the algorithm used to draw samples from a `Normal` distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Normal, SamplerConfig};
+///
+/// let n = Normal::with_sampler(0.0, 1.0, SamplerConfig::BoxMuller).unwrap();
+/// assert_eq!(n.sampler(), SamplerConfig::BoxMuller);
+/// assert_eq!(SamplerConfig::default(), SamplerConfig::Ziggurat);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SamplerConfig {
+    /// The ziggurat algorithm of Marsaglia and Tsang using precomputed
+    /// tables. This is the default and the fastest option
+    #[default]
+    Ziggurat,
+    /// The Box-Muller transform of two uniform samples
+    BoxMuller,
+}
+
+impl PartialEq for Normal {
+    fn eq(&self, other: &Normal) -> bool {
+        self.mean == other.mean && self.std_dev == other.std_dev
+    }
 }
 
 impl Normal {
@@ -52,14 +86,155 @@ impl Normal {
             Ok(Normal {
                 mean: mean,
                 std_dev: std_dev,
+                sampler: SamplerConfig::default(),
             })
         }
     }
+
+    /// Constructs a new normal distribution with a mean of `mean`, a
+    /// standard deviation of `std_dev` and drawing samples with the given
+    /// `sampler` algorithm
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `std_dev` are `NaN` or if
+    /// `std_dev <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, SamplerConfig};
+    ///
+    /// let mut result = Normal::with_sampler(0.0, 1.0, SamplerConfig::BoxMuller);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Normal::with_sampler(0.0, 0.0, SamplerConfig::BoxMuller);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn with_sampler(mean: f64, std_dev: f64, sampler: SamplerConfig) -> Result<Normal> {
+        Normal::new(mean, std_dev).map(|n| Normal {
+            sampler: sampler,
+            ..n
+        })
+    }
+
+    /// Returns the algorithm used to draw samples from the normal
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, SamplerConfig};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.sampler(), SamplerConfig::Ziggurat);
+    /// ```
+    pub fn sampler(&self) -> SamplerConfig {
+        self.sampler
+    }
+
+    /// Returns the distribution of the sum of independent random variables
+    /// distributed according to `self` and `other`. The means add and the
+    /// standard deviations add in quadrature; the result draws samples with
+    /// the sampler of `self`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// N(μ_1 + μ_2, sqrt(σ_1^2 + σ_2^2))
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::{Mean, Variance};
+    ///
+    /// let a = Normal::new(1.0, 3.0).unwrap();
+    /// let b = Normal::new(2.0, 4.0).unwrap();
+    /// let n = a.add(&b);
+    /// assert_eq!(n.mean(), 3.0);
+    /// assert_eq!(n.std_dev(), 5.0);
+    /// ```
+    pub fn add(&self, other: &Normal) -> Normal {
+        Normal {
+            mean: self.mean + other.mean,
+            std_dev: self.std_dev.hypot(other.std_dev),
+            sampler: self.sampler,
+        }
+    }
+
+    /// Returns the distribution of the difference of independent random
+    /// variables distributed according to `self` and `other`. The means
+    /// subtract while the standard deviations still add in quadrature; the
+    /// result draws samples with the sampler of `self`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// N(μ_1 - μ_2, sqrt(σ_1^2 + σ_2^2))
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::{Mean, Variance};
+    ///
+    /// let a = Normal::new(1.0, 3.0).unwrap();
+    /// let b = Normal::new(2.0, 4.0).unwrap();
+    /// let n = a.sub(&b);
+    /// assert_eq!(n.mean(), -1.0);
+    /// assert_eq!(n.std_dev(), 5.0);
+    /// ```
+    pub fn sub(&self, other: &Normal) -> Normal {
+        Normal {
+            mean: self.mean - other.mean,
+            std_dev: self.std_dev.hypot(other.std_dev),
+            sampler: self.sampler,
+        }
+    }
+}
+
+impl Parametric for Normal {
+    /// Returns the `mean` and `std_dev` parameters of the normal distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mean", self.mean), ("std_dev", self.std_dev)]
+    }
+
+    /// Constructs a normal distribution from its `mean` and `std_dev`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Normal> {
+        let p = internal::parameter_values(params, &["mean", "std_dev"])?;
+        Normal::new(p[0], p[1])
+    }
 }
 
 impl Distribution<f64> for Normal {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
-        sample_unchecked(r, self.mean, self.std_dev)
+        match self.sampler {
+            SamplerConfig::Ziggurat => sample_unchecked(r, self.mean, self.std_dev),
+            SamplerConfig::BoxMuller => self.mean + self.std_dev * sample_box_muller(r),
+        }
+    }
+}
+
+impl LocationScale for Normal {
+    /// Returns the normal distribution of `loc + scale * X`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// N(a + bμ, bσ)
+    /// ```
+    ///
+    /// where `a` is `loc`, `b` is `scale`, `μ` is the mean and `σ` the
+    /// standard deviation. The sampler configuration is preserved
+    fn transform(&self, loc: f64, scale: f64) -> Result<Normal> {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Normal::with_sampler(loc + scale * self.mean, scale * self.std_dev, self.sampler)
     }
 }
 
@@ -78,6 +253,73 @@ impl Univariate<f64, f64> for Normal {
     fn cdf(&self, x: f64) -> f64 {
         cdf_unchecked(x, self.mean, self.std_dev)
     }
+
+    /// Calculates the log of the cumulative distribution function for the
+    /// normal distribution at `x`, which remains finite far in the lower
+    /// tail where the cdf underflows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((μ - x) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` is the standard deviation
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_half_erfc((self.mean - x) / (self.std_dev * f64::consts::SQRT_2))
+    }
+
+    /// Calculates the log of the survival function for the normal
+    /// distribution at `x`, which remains finite far in the upper tail
+    /// where the survival function underflows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc((x - μ) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` is the standard deviation
+    fn ln_sf(&self, x: f64) -> f64 {
+        ln_half_erfc((x - self.mean) / (self.std_dev * f64::consts::SQRT_2))
+    }
+
+    /// Calculates the probability that a sample of the normal distribution
+    /// falls between `a` and `b`
+    ///
+    /// # Panics
+    ///
+    /// If `a > b`
+    ///
+    /// # Remarks
+    ///
+    /// The probability is computed from the complementary error function
+    /// of the tail both bounds lie in, which avoids the cancellation of
+    /// `cdf(b) - cdf(a)` for intervals far in the tails
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * (erfc(z_a) - erfc(z_b))
+    /// ```
+    ///
+    /// where `z_x = (x - μ) / (σ * sqrt(2))`, `μ` is the mean and `σ` is the
+    /// standard deviation, if `a >= μ`. The reflected formula is used if
+    /// `b <= μ` and `1 - (1 / 2) * (erfc(-z_a) + erfc(z_b))` otherwise
+    fn cdf_between(&self, a: f64, b: f64) -> f64 {
+        if a > b {
+            panic!("{}", StatsError::ArgLteArg("a", "b"));
+        }
+        let za = (a - self.mean) / (self.std_dev * f64::consts::SQRT_2);
+        let zb = (b - self.mean) / (self.std_dev * f64::consts::SQRT_2);
+        if za >= 0.0 {
+            0.5 * (erf::erfc(za) - erf::erfc(zb))
+        } else if zb <= 0.0 {
+            0.5 * (erf::erfc(-zb) - erf::erfc(-za))
+        } else {
+            1.0 - 0.5 * (erf::erfc(-za) + erf::erfc(zb))
+        }
+    }
 }
 
 impl Min<f64> for Normal {
@@ -108,6 +350,24 @@ impl Max<f64> for Normal {
     }
 }
 
+impl Domain for Normal {
+    /// Returns the support of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (-∞, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+            lo_closed: false,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Normal {
     /// Returns the mean of the normal distribution
     ///
@@ -144,6 +404,8 @@ impl Variance<f64> for Normal {
     }
 }
 
+impl Dispersion for Normal {}
+
 impl Entropy<f64> for Normal {
     /// Returns the entropy of the normal distribution
     ///
@@ -202,6 +464,25 @@ impl Mode<f64> for Normal {
     }
 }
 
+impl CheckedMode<f64> for Normal {
+    /// Returns the mode of the normal distribution
+    ///
+    /// # Remarks
+    ///
+    /// The normal distribution is unimodal so this never returns an error
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn checked_mode(&self) -> Result<f64> {
+        Ok(self.mean)
+    }
+}
+
 impl Continuous<f64, f64> for Normal {
     /// Calculates the probability density function for the normal distribution
     /// at `x`
@@ -233,6 +514,39 @@ impl Continuous<f64, f64> for Normal {
     }
 }
 
+impl Moments for Normal {
+    /// Returns the `k`-th raw moment of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(binomial(k, j) * μ^(k - j) * E[(X - μ)^j]) for j in 0..=k
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn raw_moment(&self, k: u32) -> f64 {
+        internal::shift_moment(|j| self.central_moment(j), self.mean, k)
+    }
+
+    /// Returns the `k`-th central moment of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^k * (k - 1)!!
+    /// ```
+    ///
+    /// for even `k` and `0` for odd `k`, where `σ` is the standard deviation
+    fn central_moment(&self, k: u32) -> f64 {
+        if k % 2 == 1 {
+            0.0
+        } else {
+            let double_factorial = (0..k / 2).fold(1.0, |acc, i| acc * f64::from(2 * i + 1));
+            self.std_dev.powi(k as i32) * double_factorial
+        }
+    }
+}
+
 impl InverseCDF<f64> for Normal {
     /// Calculates the inverse cumulative distribution function for the
     /// normal distribution at `x`
@@ -285,6 +599,29 @@ pub fn cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// computes `ln(erfc(z) / 2)`, switching to the asymptotic expansion of
+/// `erfc` for large `z`, which converges to full precision well before
+/// `erfc(z)` underflows
+fn ln_half_erfc(z: f64) -> f64 {
+    if z.is_nan() || z < 8.0 {
+        return (0.5 * erf::erfc(z)).ln();
+    }
+    // erfc(z) = e^(-z^2) / (z * sqrt(π)) * Σ (-1)^n (2n - 1)!! / (2z^2)^n
+    let w = 0.5 / (z * z);
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1.0;
+    loop {
+        term *= -(2.0 * n - 1.0) * w;
+        if term.abs() <= f64::EPSILON * sum {
+            break;
+        }
+        sum += term;
+        n += 1.0;
+    }
+    -z * z - z.ln() - 0.5 * consts::LN_PI - f64::consts::LN_2 + sum.ln()
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -292,6 +629,40 @@ pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     (-0.5 * d * d).exp() / (consts::SQRT_2PI * std_dev)
 }
 
+/// Evaluates the probability density function of the normal distribution at
+/// `x` for every combination of a mean in `means` and a standard deviation
+/// in `std_devs`, without constructing a `Normal` for each of them
+///
+/// # Remarks
+///
+/// The densities are returned in row-major order, i.e. the entry at index
+/// `i * std_devs.len() + j` is the density for `means[i]` and
+/// `std_devs[j]`. Entries for parameters that `Normal::new` would reject
+/// are `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{normal_pdf_grid, Continuous, Normal};
+///
+/// let grid = normal_pdf_grid(1.0, &[0.0, 1.0], &[1.0, 2.0, 3.0]);
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[5], Normal::new(1.0, 3.0).unwrap().pdf(1.0));
+/// ```
+pub fn normal_pdf_grid(x: f64, means: &[f64], std_devs: &[f64]) -> Vec<f64> {
+    let mut grid = Vec::with_capacity(means.len() * std_devs.len());
+    for &mean in means {
+        for &std_dev in std_devs {
+            if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+                grid.push(f64::NAN);
+            } else {
+                grid.push(pdf_unchecked(x, mean, std_dev));
+            }
+        }
+    }
+    grid
+}
+
 /// performs an unchecked log(pdf) calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn ln_pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -304,13 +675,24 @@ pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, mean: f64, std_dev: f64) ->
     mean + std_dev * ziggurat::sample_std_normal(r)
 }
 
+/// draws a standard normal sample with the Box-Muller transform, using
+/// only one of the pair of generated samples
+fn sample_box_muller<R: Rng + ?Sized>(r: &mut R) -> f64 {
+    let u1: f64 = r.sample(Open01);
+    let u2: f64 = r.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * f64::consts::PI * u2).cos()
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Normal, InverseCDF, CheckedInverseCDF};
+    use distribution::{normal_pdf_grid, Univariate, Continuous, Normal, SamplerConfig, InverseCDF, CheckedInverseCDF, Moments, NumericalEntropy, LocationScale, Domain, Parametric, Support};
+    use rand::Rng;
     use distribution::internal::*;
+    use function::integration::adaptive_quadrature_infinite;
+    use rng;
 
     fn try_create(mean: f64, std_dev: f64) -> Normal {
         let n = Normal::new(mean, std_dev);
@@ -512,4 +894,263 @@ mod test {
         test_almost(5.0, 2.0, 10.0, 1e-14, |x| x.inverse_cdf(0.9937903346742238648330218954258077788721022530769078));
         test_case(5.0, 2.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
     }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(1.5, 2.0);
+        assert_almost_eq!(n.central_moment(2), n.variance(), 1e-14);
+        assert_almost_eq!(n.raw_moment(1), n.mean(), 1e-14);
+        for k in 0..7 {
+            let raw = adaptive_quadrature_infinite(|x| x.powi(k as i32) * n.pdf(x), f64::NEG_INFINITY, f64::INFINITY, 1e-12);
+            let central = adaptive_quadrature_infinite(|x| (x - 1.5).powi(k as i32) * n.pdf(x), f64::NEG_INFINITY, f64::INFINITY, 1e-12);
+            assert_almost_eq!(n.raw_moment(k), raw, 1e-8);
+            assert_almost_eq!(n.central_moment(k), central, 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_checked_mode() {
+        assert_eq!(try_create(-3.0, 2.0).checked_mode().unwrap(), -3.0);
+        assert_eq!(try_create(0.0, 1.0).checked_mode().unwrap(), 0.0);
+        assert_eq!(try_create(10.0, 0.5).checked_mode().unwrap(), 10.0);
+    }
+
+    #[test]
+    fn test_numerical_entropy() {
+        let expected = 0.5 * (2.0 * f64::consts::PI * f64::consts::E).ln();
+        assert_almost_eq!(try_create(0.0, 1.0).numerical_entropy(), expected, 1e-6);
+        for &(mean, std_dev) in &[(0.0, 0.1), (-5.0, 3.0), (10.0, 25.0)] {
+            let n = try_create(mean, std_dev);
+            assert_almost_eq!(n.numerical_entropy(), n.entropy(), 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_expected_counts() {
+        let n = try_create(1.0, 2.0);
+        let edges = [-3.0, -1.0, 0.0, 1.0, 2.5, 5.0];
+        let counts = n.expected_counts(&edges, 1000);
+        assert_eq!(counts.len(), 5);
+        for i in 0..5 {
+            assert_almost_eq!(counts[i], 1000.0 * (n.cdf(edges[i + 1]) - n.cdf(edges[i])), 1e-12);
+        }
+        // the counts miss the mass in the tails outside of the edges
+        let tails = 1.0 - n.cdf(5.0) + n.cdf(-3.0);
+        assert_almost_eq!(counts.iter().sum::<f64>(), 1000.0 * (1.0 - tails), 1e-10);
+        let all = n.expected_counts(&[f64::NEG_INFINITY, 0.0, f64::INFINITY], 50);
+        assert_almost_eq!(all.iter().sum::<f64>(), 50.0, 1e-12);
+        assert_eq!(n.expected_counts(&[0.0, 1.0], 0), vec![0.0]);
+
+        assert!(n.checked_expected_counts(&[], 10).is_err());
+        assert!(n.checked_expected_counts(&[1.0], 10).is_err());
+        assert!(n.checked_expected_counts(&[1.0, 1.0], 10).is_err());
+        assert!(n.checked_expected_counts(&[1.0, 0.0], 10).is_err());
+        assert!(n.checked_expected_counts(&[0.0, f64::NAN], 10).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expected_counts_unsorted() {
+        try_create(0.0, 1.0).expected_counts(&[1.0, 0.0, 2.0], 10);
+    }
+
+    #[test]
+    fn test_cdf_between() {
+        let n = try_create(0.0, 1.0);
+        assert_almost_eq!(n.cdf_between(-2.0, -1.0), 0.13590512198327784, 1e-9);
+        assert_almost_eq!(n.cdf_between(-0.5, 1.0), 0.532807207342556, 1e-9);
+        assert_almost_eq!(n.cdf_between(1.0, 2.0), 0.13590512198327784, 1e-9);
+        assert_eq!(n.cdf_between(1.0, 1.0), 0.0);
+        assert_eq!(n.cdf_between(f64::NEG_INFINITY, f64::INFINITY), 1.0);
+        let m = try_create(5.0, 2.0);
+        assert_almost_eq!(m.cdf_between(3.0, 7.0), m.cdf(7.0) - m.cdf(3.0), 1e-15);
+    }
+
+    #[test]
+    fn test_cdf_between_far_tail() {
+        let n = try_create(0.0, 1.0);
+        let expected = 5.032114945475825e-18;
+        let naive = n.cdf(8.001) - n.cdf(8.0);
+        let stable = n.cdf_between(8.0, 8.001);
+        assert!(((naive - expected) / expected).abs() > 1e-2);
+        assert!(((stable - expected) / expected).abs() < 1e-8);
+        let reflected = n.cdf_between(-8.001, -8.0);
+        assert!(((reflected - expected) / expected).abs() < 1e-8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cdf_between_reversed_bounds() {
+        try_create(0.0, 1.0).cdf_between(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_sampler_moments() {
+        use rand::distributions::Distribution;
+
+        for &sampler in &[SamplerConfig::Ziggurat, SamplerConfig::BoxMuller] {
+            let mut r = rng::seeded(0);
+            let n = Normal::with_sampler(2.0, 3.0, sampler).unwrap();
+            let samples: Vec<f64> = (0..100000).map(|_| n.sample(&mut r)).collect();
+            assert_almost_eq!((&samples).mean(), 2.0, 0.05);
+            assert_almost_eq!((&samples).variance(), 9.0, 0.15);
+        }
+    }
+
+    #[test]
+    fn test_sampler_config_changes_samples() {
+        use rand::distributions::Distribution;
+
+        let zig = try_create(0.0, 1.0);
+        let bm = Normal::with_sampler(0.0, 1.0, SamplerConfig::BoxMuller).unwrap();
+        assert_eq!(zig.sampler(), SamplerConfig::Ziggurat);
+        assert_eq!(bm.sampler(), SamplerConfig::BoxMuller);
+
+        let mut r1 = rng::seeded(0);
+        let mut r2 = rng::seeded(0);
+        let a: Vec<f64> = (0..10).map(|_| zig.sample(&mut r1)).collect();
+        let b: Vec<f64> = (0..10).map(|_| bm.sample(&mut r2)).collect();
+        assert!(a != b);
+
+        // box-muller consumes exactly two uniform samples per normal sample
+        let mut r3 = rng::seeded(0);
+        bm.sample(&mut r3);
+        let mut r4 = rng::seeded(0);
+        let _: f64 = r4.sample(rand::distributions::Open01);
+        let _: f64 = r4.gen();
+        assert_eq!(r3.gen::<u64>(), r4.gen::<u64>());
+    }
+
+    #[test]
+    fn test_location_scale() {
+        let n = try_create(1.5, 2.0);
+        let t = n.transform(-1.0, 3.0).unwrap();
+        assert_eq!(t.mean(), -1.0 + 3.0 * 1.5);
+        assert_eq!(t.std_dev(), 3.0 * 2.0);
+        assert_eq!(n.transform(0.0, 1.0).unwrap(), n);
+        let bm = Normal::with_sampler(0.0, 1.0, SamplerConfig::BoxMuller).unwrap();
+        assert_eq!(bm.transform(1.0, 2.0).unwrap().sampler(), SamplerConfig::BoxMuller);
+        assert!(n.transform(0.0, 0.0).is_err());
+        assert!(n.transform(0.0, -1.0).is_err());
+        assert!(n.transform(0.0, f64::NAN).is_err());
+        assert!(n.transform(f64::NAN, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_support() {
+        let s = try_create(1.0, 2.0).support();
+        assert_eq!(s, Support::Continuous { lo: f64::NEG_INFINITY, hi: f64::INFINITY, lo_closed: false, hi_closed: false });
+        assert!(s.contains(-1e300));
+        assert!(!s.contains(f64::INFINITY));
+        assert!(!s.contains(f64::NAN));
+    }
+
+    #[test]
+    fn test_normal_pdf_grid() {
+        let means = [-2.0, 0.0, 1.5, 10.0];
+        let std_devs = [0.1, 1.0, 2.5, f64::INFINITY];
+        for &x in &[-3.0f64, 0.0, 1.0, 12.0] {
+            let grid = normal_pdf_grid(x, &means, &std_devs);
+            assert_eq!(grid.len(), 16);
+            for (i, &mean) in means.iter().enumerate() {
+                for (j, &std_dev) in std_devs.iter().enumerate() {
+                    assert_eq!(grid[i * 4 + j], try_create(mean, std_dev).pdf(x));
+                }
+            }
+        }
+        assert!(normal_pdf_grid(0.0, &[], &std_devs).is_empty());
+        let invalid = normal_pdf_grid(0.0, &[0.0, f64::NAN], &[1.0, 0.0, -1.0, f64::NAN]);
+        assert!(!invalid[0].is_nan());
+        assert!(invalid[1..].iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = try_create(1.5, 2.0);
+        let b = Normal::with_sampler(-4.0, 0.5, SamplerConfig::BoxMuller).unwrap();
+        let sum = a.add(&b);
+        assert_eq!(sum.mean(), -2.5);
+        assert_almost_eq!(sum.variance(), 4.25, 1e-14);
+        assert_eq!(sum.sampler(), SamplerConfig::Ziggurat);
+        let diff = a.sub(&b);
+        assert_eq!(diff.mean(), 5.5);
+        assert_eq!(diff.std_dev(), sum.std_dev());
+        assert_eq!(b.add(&a).sampler(), SamplerConfig::BoxMuller);
+        assert_eq!(b.sub(&a).mean(), -5.5);
+        // the standard deviations add in quadrature without overflowing
+        let big = try_create(0.0, 1e200);
+        assert_almost_eq!(big.add(&big).std_dev(), 1e200 * f64::consts::SQRT_2, 1e186);
+    }
+
+    #[test]
+    fn test_add_sub_sampling() {
+        use rand::distributions::Distribution;
+        use statistics::{GoodnessOfFit, KolmogorovSmirnov};
+
+        let mut r = rng::seeded(0);
+        let a = try_create(1.0, 2.0);
+        let b = try_create(-3.0, 0.5);
+        let sums: Vec<f64> = (0..5000).map(|_| a.sample(&mut r) + b.sample(&mut r)).collect();
+        let diffs: Vec<f64> = (0..5000).map(|_| a.sample(&mut r) - b.sample(&mut r)).collect();
+        assert!(!KolmogorovSmirnov.test(&sums, &a.add(&b)).unwrap().reject(0.01));
+        assert!(!KolmogorovSmirnov.test(&diffs, &a.sub(&b)).unwrap().reject(0.01));
+        // adding the standard deviations instead of the variances is rejected
+        let naive = try_create(-2.0, 2.5);
+        assert!(KolmogorovSmirnov.test(&sums, &naive).unwrap().reject(0.01));
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(try_create(-1.5, 2.0).parameters(), vec![("mean", -1.5), ("std_dev", 2.0)]);
+    }
+
+    #[test]
+    fn test_from_parameters() {
+        let n = try_create(-1.5, 2.0);
+        assert_eq!(Normal::from_parameters(&n.parameters()).unwrap(), n);
+        assert_eq!(Normal::from_parameters(&[("std_dev", 2.0), ("mean", -1.5)]).unwrap(), n);
+        assert!(Normal::from_parameters(&[("mean", -1.5)]).is_err());
+        assert!(Normal::from_parameters(&[("mean", -1.5), ("sigma", 2.0)]).is_err());
+        assert!(Normal::from_parameters(&[("mean", -1.5), ("std_dev", 2.0), ("sigma", 2.0)]).is_err());
+        assert!(Normal::from_parameters(&[("mean", -1.5), ("mean", 2.0)]).is_err());
+        assert!(Normal::from_parameters(&[("mean", -1.5), ("std_dev", -2.0)]).is_err());
+        // the sampling algorithm does not take part in equality
+        let n = Normal::with_sampler(-1.5, 2.0, SamplerConfig::BoxMuller).unwrap();
+        assert_eq!(Normal::from_parameters(&n.parameters()).unwrap(), n);
+        assert_eq!(n, try_create(-1.5, 2.0));
+        assert!(n != try_create(-1.5, 2.5));
+    }
+
+    #[test]
+    fn test_ln_sf_far_tail() {
+        let n = try_create(0.0, 1.0);
+        assert_eq!(n.cdf(40.0), 1.0);
+        let ln_sf = n.ln_sf(40.0);
+        assert!(ln_sf.is_finite());
+        assert_almost_eq!(ln_sf, -804.6084420137538, 1e-12);
+        assert_almost_eq!(n.ln_cdf(-40.0), -804.6084420137538, 1e-12);
+        assert_eq!(n.ln_sf(-40.0), 0.0);
+        assert_eq!(n.ln_sf(f64::INFINITY), f64::NEG_INFINITY);
+        assert_eq!(n.ln_cdf(f64::INFINITY), 0.0);
+        assert!(n.ln_sf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ln_cdf_ln_sf() {
+        let n = try_create(0.0, 1.0);
+        assert_almost_eq!(n.ln_sf(0.0), -f64::consts::LN_2, 1e-15);
+        assert_almost_eq!(n.ln_sf(1.0), n.cdf(-1.0).ln(), 1e-15);
+        assert_almost_eq!(n.ln_sf(10.0), -53.23128515051047, 1e-8);
+        assert_almost_eq!(n.ln_cdf(-5.0), -15.064998393988725, 1e-8);
+        // either side of the switch to the asymptotic expansion
+        assert_almost_eq!(n.ln_sf(11.3), -67.19642501016108, 1e-8);
+        assert_almost_eq!(n.ln_sf(11.32), -67.42436673113029, 1e-12);
+        assert_almost_eq!(n.ln_sf(20.0), -203.91715537109727, 1e-12);
+        let n = try_create(3.0, 2.0);
+        for &x in &[-4.0f64, 0.0, 2.5, 3.0, 7.0] {
+            assert_almost_eq!(n.ln_cdf(x), n.cdf(x).ln(), 1e-13);
+            assert_almost_eq!(n.ln_sf(x), (1.0 - n.cdf(x)).ln(), 1e-13);
+        }
+        assert_almost_eq!(n.ln_sf(83.0), try_create(0.0, 1.0).ln_sf(40.0), 1e-12);
+    }
 }
@@ -1,4 +1,4 @@
-use distribution::{Continuous, Univariate};
+use distribution::{internal, Continuous, Domain, Parametric, Support, Univariate};
 use rand::distributions::Distribution;
 use rand::distributions::OpenClosed01;
 use rand::Rng;
@@ -87,6 +87,19 @@ impl Pareto {
     }
 }
 
+impl Parametric for Pareto {
+    /// Returns the `scale` and `shape` parameters of the pareto distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("scale", self.scale), ("shape", self.shape)]
+    }
+
+    /// Constructs a pareto distribution from its `scale` and `shape` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Pareto> {
+        let p = internal::parameter_values(params, &["scale", "shape"])?;
+        Pareto::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for Pareto {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         // Inverse transform sampling
@@ -117,6 +130,49 @@ impl Univariate<f64, f64> for Pareto {
             1.0 - (self.scale / x).powf(self.shape)
         }
     }
+
+    /// Calculates the log of the cumulative distribution function for the
+    /// Pareto distribution at `x`, which remains accurate just above the
+    /// scale where the cdf is small
+    ///
+    /// # Remarks
+    ///
+    /// `ln(x / x_m)` is computed as `ln_1p((x - x_m) / x_m)`, which avoids
+    /// the rounding of the ratio when `x` is close to `x_m`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - (x_m / x)^α)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            f64::NEG_INFINITY
+        } else {
+            (-(-self.shape * ((x - self.scale) / self.scale).ln_1p()).exp_m1()).ln()
+        }
+    }
+
+    /// Calculates the log of the survival function for the Pareto
+    /// distribution at `x`, which remains finite far in the upper tail
+    /// where the survival function underflows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// α * ln(x_m / x)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            0.0
+        } else {
+            -self.shape * ((x - self.scale) / self.scale).ln_1p()
+        }
+    }
 }
 
 impl Min<f64> for Pareto {
@@ -149,6 +205,24 @@ impl Max<f64> for Pareto {
     }
 }
 
+impl Domain for Pareto {
+    /// Returns the support of the pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [x_m, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.scale,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Pareto {
     /// Returns the mean of the Pareto distribution
     ///
@@ -214,6 +288,71 @@ impl Variance<f64> for Pareto {
     }
 }
 
+impl Dispersion for Pareto {}
+
+impl CheckedMean<f64> for Pareto {
+    /// Returns the mean of the Pareto distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape <= 1.0`, where the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α * x_m)/(α - 1)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn checked_mean(&self) -> Result<f64> {
+        if self.shape <= 1.0 {
+            Err(StatsError::ArgGt("shape", 1.0))
+        } else {
+            Ok(self.mean())
+        }
+    }
+}
+
+impl CheckedVariance<f64> for Pareto {
+    /// Returns the variance of the Pareto distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape <= 2.0`, where the variance is infinite or undefined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (x_m/(α - 1))^2 * (α/(α - 2))
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn checked_variance(&self) -> Result<f64> {
+        if self.shape <= 2.0 {
+            Err(StatsError::ArgGt("shape", 2.0))
+        } else {
+            Ok(self.variance())
+        }
+    }
+
+    /// Returns the standard deviation of the Pareto distribution
+    ///
+    /// # Errors
+    ///
+    /// If `shape <= 2.0`, where the variance is infinite or undefined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt((x_m/(α - 1))^2 * (α/(α - 2)))
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn checked_std_dev(&self) -> Result<f64> {
+        self.checked_variance().map(|x| x.sqrt())
+    }
+}
+
 impl Entropy<f64> for Pareto {
     /// Returns the entropy for the Pareto distribution
     ///
@@ -422,6 +561,15 @@ mod test {
         test_almost(10.0, 10.0, 125.0/81.0, 1e-13, |x| x.variance());
     }
 
+    #[test]
+    fn test_dispersion() {
+        test_almost(1.0, 3.0, 3f64.sqrt() / 3.0, 1e-15, |x| x.coefficient_of_variation());
+        test_almost(1.0, 3.0, 0.5, 1e-15, |x| x.index_of_dispersion());
+        // the mean is undefined for shape <= 1
+        assert!(try_create(1.0, 1.0).coefficient_of_variation().is_nan());
+        assert!(try_create(1.0, 0.5).index_of_dispersion().is_nan());
+    }
+
     #[test]
     fn test_entropy() {
         test_case(0.1, 0.1, -11.0, |x| x.entropy());
@@ -526,4 +674,29 @@ mod test {
         test::check_continuous_distribution(&try_create(1.0, 10.0), 1.0, 10.0);
         test::check_continuous_distribution(&try_create(0.1, 2.0), 0.1, 100.0);
     }
+
+    #[test]
+    fn test_checked_moments() {
+        assert!(try_create(1.0, 0.5).checked_mean().is_err());
+        assert!(try_create(1.0, 1.0).checked_mean().is_err());
+        assert_eq!(try_create(1.0, 2.0).checked_mean().unwrap(), 2.0);
+        assert!(try_create(1.0, 2.0).checked_variance().is_err());
+        assert!(try_create(1.0, 2.0).checked_std_dev().is_err());
+        assert_eq!(try_create(1.0, 3.0).checked_variance().unwrap(), 0.75);
+        assert_eq!(try_create(1.0, 3.0).checked_std_dev().unwrap(), 0.75f64.sqrt());
+    }
+
+    #[test]
+    fn test_ln_cdf_ln_sf() {
+        let n = try_create(1.0, 1.0);
+        assert_eq!(n.ln_cdf(0.5), f64::NEG_INFINITY);
+        assert_eq!(n.ln_sf(0.5), 0.0);
+        assert_almost_eq!(n.ln_cdf(1.0 + 1e-10), -23.025850847300088, 1e-13);
+        let n = try_create(1.0, 2.0);
+        assert_almost_eq!(n.ln_sf(1e300), -1381.5510557964274, 1e-10);
+        let n = try_create(1.0, 2.5);
+        assert_almost_eq!(n.ln_cdf(3.0), -0.06630010371743812, 1e-15);
+        assert_almost_eq!(n.ln_sf(3.0), -2.5 * 3.0f64.ln(), 1e-15);
+        assert_almost_eq!(n.ln_sf(3.0), (1.0 - n.cdf(3.0)).ln(), 1e-14);
+    }
 }
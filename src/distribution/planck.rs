@@ -0,0 +1,375 @@
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
+use rand::distributions::Distribution;
+use rand::distributions::OpenClosed01;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the Planck, or Bose–Einstein, distribution, the distribution
+/// of the occupation number of a single mode of a bosonic system in thermal
+/// equilibrium
+///
+/// # Remarks
+///
+/// The Planck distribution with parameter `a` is a geometric distribution
+/// over `{0, 1, 2, ...}` with a success probability of `1 - e^(-a)`. In
+/// physical terms `a = ħω / kT` is the energy of the mode over the thermal
+/// energy.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Planck, Discrete};
+/// use statrs::statistics::Mean;
+///
+/// let n = Planck::new(2.0_f64.ln()).unwrap();
+/// assert_eq!(n.mean(), 1.0);
+/// assert_eq!(n.pmf(1), 0.25);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Planck {
+    a: f64,
+}
+
+impl Planck {
+    /// Constructs a new Planck distribution with a parameter of `a`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `a` is `NaN` or `a <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Planck;
+    ///
+    /// let mut result = Planck::new(1.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Planck::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(a: f64) -> Result<Planck> {
+        if a.is_nan() || a <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Planck { a: a })
+        }
+    }
+
+    /// Returns the parameter `a` of the Planck distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Planck;
+    ///
+    /// let n = Planck::new(1.5).unwrap();
+    /// assert_eq!(n.a(), 1.5);
+    /// ```
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+impl Parametric for Planck {
+    /// Returns the `a` parameter of the Planck distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("a", self.a)]
+    }
+
+    /// Constructs a Planck distribution from its `a` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Planck> {
+        let p = internal::parameter_values(params, &["a"])?;
+        Planck::new(p[0])
+    }
+}
+
+impl Distribution<f64> for Planck {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let x: f64 = r.sample(OpenClosed01);
+        (-x.ln() / self.a).floor()
+    }
+}
+
+impl Univariate<u64, f64> for Planck {
+    /// Calculates the cumulative distribution function for the Planck
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-a * (floor(x) + 1))
+    /// ```
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            -(-self.a * (x.floor() + 1.0)).exp_m1()
+        }
+    }
+}
+
+impl Min<u64> for Planck {
+    /// Returns the minimum value in the domain of the Planck distribution
+    /// representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl Max<u64> for Planck {
+    /// Returns the maximum value in the domain of the Planck distribution
+    /// representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^63 - 1
+    /// ```
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Domain for Planck {
+    /// Returns the support of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {0, 1, 2, ...}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: 0.0,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
+impl Mean<f64> for Planck {
+    /// Returns the mean of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (e^a - 1)
+    /// ```
+    fn mean(&self) -> f64 {
+        1.0 / self.a.exp_m1()
+    }
+}
+
+impl Variance<f64> for Planck {
+    /// Returns the variance of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^a / (e^a - 1)^2
+    /// ```
+    fn variance(&self) -> f64 {
+        let d = self.a.exp_m1();
+        self.a.exp() / (d * d)
+    }
+
+    /// Returns the standard deviation of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(a / 2) / (e^a - 1)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        (0.5 * self.a).exp() / self.a.exp_m1()
+    }
+}
+
+impl Dispersion for Planck {}
+
+impl Entropy<f64> for Planck {
+    /// Returns the entropy of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a / (e^a - 1) - ln(1 - e^(-a))
+    /// ```
+    fn entropy(&self) -> f64 {
+        self.a / self.a.exp_m1() - (-(-self.a).exp_m1()).ln()
+    }
+}
+
+impl Mode<u64> for Planck {
+    /// Returns the mode of the Planck distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> u64 {
+        0
+    }
+}
+
+impl Discrete<u64, f64> for Planck {
+    /// Calculates the probability mass function for the Planck distribution
+    /// at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - e^(-a)) * e^(-a * x)
+    /// ```
+    fn pmf(&self, x: u64) -> f64 {
+        -(-self.a).exp_m1() * (-self.a * x as f64).exp()
+    }
+
+    /// Calculates the log probability mass function for the Planck
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - e^(-a)) - a * x
+    /// ```
+    fn ln_pmf(&self, x: u64) -> f64 {
+        (-(-self.a).exp_m1()).ln() - self.a * x as f64
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Discrete, Planck, Univariate};
+    use distribution::internal::*;
+    use rng;
+
+    fn try_create(a: f64) -> Planck {
+        let n = Planck::new(a);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_case<T, F>(a: f64, expected: T, eval: F)
+        where T: PartialEq + Debug,
+              F: Fn(Planck) -> T
+    {
+        let x = eval(try_create(a));
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(a: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Planck) -> f64
+    {
+        let x = eval(try_create(a));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(0.5).a(), 0.5);
+        assert_eq!(try_create(20.0).a(), 20.0);
+        assert!(Planck::new(0.0).is_err());
+        assert!(Planck::new(-1.0).is_err());
+        assert!(Planck::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_mean() {
+        for &a in &[1e-3f64, 0.5, 2.0, 10.0] {
+            test_almost(a, 1.0, 1e-12, |x| x.mean() * (a.exp() - 1.0));
+        }
+        test_almost(0.5, 1.5414940825367982, 1e-15, |x| x.mean());
+        test_almost(2.0, 0.15651764274966565, 1e-16, |x| x.mean());
+    }
+
+    #[test]
+    fn test_variance() {
+        test_almost(0.5, 3.917698089032764, 1e-14, |x| x.variance());
+        test_almost(2.0, 0.1810154152415776, 1e-16, |x| x.variance());
+        test_almost(0.5, 3.917698089032764f64.sqrt(), 1e-15, |x| x.std_dev());
+    }
+
+    #[test]
+    fn test_entropy() {
+        test_almost(0.5, 1.7034991708355878, 1e-15, |x| x.entropy());
+        test_almost(2.0, 0.45844874336819036, 1e-15, |x| x.entropy());
+    }
+
+    #[test]
+    fn test_mode() {
+        test_case(0.5, 0, |x| x.mode());
+        test_case(2.0, 0, |x| x.mode());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_case(0.5, 0, |x| x.min());
+        test_case(0.5, u64::MAX, |x| x.max());
+    }
+
+    #[test]
+    fn test_pmf() {
+        test_almost(0.5, 0.3934693402873666, 1e-16, |x| x.pmf(0));
+        test_almost(0.5, 0.08779487691181714, 1e-16, |x| x.pmf(3));
+        test_almost(2.0, 0.8646647167633873, 1e-15, |x| x.pmf(0));
+        test_almost(2.0, 0.0021432895487638465, 1e-17, |x| x.pmf(3));
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        for &a in &[0.05f64, 0.5, 2.0, 10.0] {
+            let n = try_create(a);
+            let sum: f64 = (0..2000).map(|k| n.pmf(k)).sum();
+            assert_almost_eq!(sum, 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        test_almost(0.5, -0.9327521295671886, 1e-15, |x| x.ln_pmf(0));
+        test_almost(0.5, -2.432752129567189, 1e-15, |x| x.ln_pmf(3));
+        test_almost(2.0, -6.145413457868859, 1e-15, |x| x.ln_pmf(3));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_case(0.5, 0.0, |x| x.cdf(-0.5));
+        test_case(0.5, 1.0, |x| x.cdf(f64::INFINITY));
+        test_almost(0.5, 0.8646647167633873, 1e-15, |x| x.cdf(3.0));
+        test_almost(0.5, 0.8646647167633873, 1e-15, |x| x.cdf(3.7));
+        test_almost(2.0, 0.9996645373720975, 1e-15, |x| x.cdf(3.0));
+        test_almost(2.0, 0.8646647167633873, 1e-15, |x| x.cdf(0.0));
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        let mut r = rng::seeded(0);
+        let n = try_create(0.5);
+        let samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0 && x == x.floor()));
+        assert_almost_eq!(samples.iter().mean(), n.mean(), 0.05);
+    }
+
+    #[test]
+    fn test_discrete() {
+        test::check_discrete_distribution(&try_create(0.5), 100);
+        test::check_discrete_distribution(&try_create(3.0), 20);
+    }
+}
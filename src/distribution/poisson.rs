@@ -1,6 +1,6 @@
-use distribution::{Discrete, Univariate};
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
 use function::{factorial, gamma};
-use rand::distributions::Distribution;
+use rand::distributions::{Distribution, Open01};
 use rand::Rng;
 use statistics::*;
 use std::f64;
@@ -66,6 +66,77 @@ impl Poisson {
     pub fn lambda(&self) -> f64 {
         self.lambda
     }
+
+    /// Returns the distribution of the sum of independent poisson random
+    /// variables distributed according to `self` and `other`, i.e. the
+    /// counts of the superposition of two independent poisson processes
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Poisson(λ_1 + λ_2)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let a = Poisson::new(1.5).unwrap();
+    /// let b = Poisson::new(2.0).unwrap();
+    /// assert_eq!(a.superpose(&b).lambda(), 3.5);
+    /// ```
+    pub fn superpose(&self, other: &Poisson) -> Poisson {
+        Poisson {
+            lambda: self.lambda + other.lambda,
+        }
+    }
+
+    /// Returns the distribution of the counts of a poisson process
+    /// distributed according to `self` where each event is independently
+    /// kept with probability `p`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `p` is `NaN` or not in `[0, 1]`. Also returns an
+    /// error if `p` is `0.0` since the rate of the thinned process must be
+    /// positive
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Poisson(λp)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Poisson;
+    ///
+    /// let n = Poisson::new(4.0).unwrap();
+    /// assert_eq!(n.thin(0.25).unwrap().lambda(), 1.0);
+    /// assert!(n.thin(1.5).is_err());
+    /// ```
+    pub fn thin(&self, p: f64) -> Result<Poisson> {
+        if p.is_nan() || p < 0.0 || p > 1.0 {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else {
+            Poisson::new(self.lambda * p)
+        }
+    }
+}
+
+impl Parametric for Poisson {
+    /// Returns the `lambda` parameter of the poisson distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("lambda", self.lambda)]
+    }
+
+    /// Constructs a poisson distribution from its `lambda` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Poisson> {
+        let p = internal::parameter_values(params, &["lambda"])?;
+        Poisson::new(p[0])
+    }
 }
 
 impl Distribution<f64> for Poisson {
@@ -124,6 +195,22 @@ impl Max<u64> for Poisson {
     }
 }
 
+impl Domain for Poisson {
+    /// Returns the support of the poisson distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {0, 1, 2, ...}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: 0.0,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
 impl Mean<f64> for Poisson {
     /// Returns the mean of the poisson distribution
     ///
@@ -167,6 +254,8 @@ impl Variance<f64> for Poisson {
     }
 }
 
+impl Dispersion for Poisson {}
+
 impl Entropy<f64> for Poisson {
     /// Returns the entropy of the poisson distribution
     ///
@@ -218,15 +307,44 @@ impl Median<f64> for Poisson {
 impl Mode<u64> for Poisson {
     /// Returns the mode of the poisson distribution
     ///
+    /// # Remarks
+    ///
+    /// If `λ` is an integer both `λ` and `λ - 1` are modes and the
+    /// smaller one is returned. Use `Modes::modes` to get both.
+    ///
     /// # Formula
     ///
     /// ```ignore
-    /// floor(λ)
+    /// ceil(λ) - 1
     /// ```
     ///
     /// where `λ` is the rate
     fn mode(&self) -> u64 {
-        self.lambda.floor() as u64
+        self.modes()[0]
+    }
+}
+
+impl Modes<u64> for Poisson {
+    /// Returns the modes of the poisson distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if λ is an integer {
+    ///     [λ - 1, λ]
+    /// } else {
+    ///     [floor(λ)]
+    /// }
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn modes(&self) -> Vec<u64> {
+        let floor = self.lambda.floor() as u64;
+        if self.lambda == self.lambda.floor() {
+            vec![floor - 1, floor]
+        } else {
+            vec![floor]
+        }
     }
 }
 
@@ -262,11 +380,10 @@ impl Discrete<u64, f64> for Poisson {
 }
 
 /// Generates one sample from the Poisson distribution either by
-/// Knuth's method if lambda < 30.0 or Rejection method PA by
-/// A. C. Atkinson from the Journal of the Royal Statistical Society
-/// Series C (Applied Statistics) Vol. 28 No. 1. (1979) pp. 29 - 35
-/// otherwise
-fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, lambda: f64) -> f64 {
+/// Knuth's method if lambda < 30.0 or the transformed rejection method
+/// with squeeze (PTRS) by W. Hörmann from Insurance: Mathematics and
+/// Economics Vol. 12 No. 1 (1993) pp. 39 - 45 otherwise
+pub fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, lambda: f64) -> f64 {
     if lambda < 30.0 {
         let limit = (-lambda).exp();
         let mut count = 0.0;
@@ -277,26 +394,28 @@ fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, lambda: f64) -> f64 {
         }
         count
     } else {
-        let c = 0.767 - 3.36 / lambda;
-        let beta = f64::consts::PI / (3.0 * lambda).sqrt();
-        let alpha = beta * lambda;
-        let k = c.ln() - lambda - beta.ln();
+        let ln_lambda = lambda.ln();
+        let b = 0.931 + 2.53 * lambda.sqrt();
+        let a = -0.059 + 0.02483 * b;
+        let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let v_r = 0.9277 - 3.6224 / (b - 2.0);
 
         loop {
-            let u: f64 = r.gen();
-            let x = (alpha - ((1.0 - u) / u).ln()) / beta;
-            let n = (x + 0.5).floor();
-            if n < 0.0 {
+            let u = r.gen::<f64>() - 0.5;
+            let v: f64 = r.sample(Open01);
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+            if us >= 0.07 && v <= v_r {
+                return k;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
                 continue;
             }
 
-            let v: f64 = r.gen();
-            let y = alpha - beta * x;
-            let temp = 1.0 + y.exp();
-            let lhs = y + (v / (temp * temp)).ln();
-            let rhs = k + n * lambda.ln() - factorial::ln_factorial(n as u64);
+            let lhs = (v * inv_alpha / (a / (us * us) + b)).ln();
+            let rhs = -lambda + k * ln_lambda - factorial::ln_factorial(k as u64);
             if lhs <= rhs {
-                return n;
+                return k;
             }
         }
     }
@@ -309,8 +428,9 @@ mod test {
     use std::f64;
     use std::u64;
     use statistics::*;
-    use distribution::{Univariate, Discrete, Poisson};
+    use distribution::{Univariate, Discrete, Poisson, Domain, Support};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(lambda: f64) -> Poisson {
         let n = Poisson::new(lambda);
@@ -379,6 +499,14 @@ mod test {
         test_case(10.8, 10.8, |x| x.variance());
     }
 
+    #[test]
+    fn test_dispersion() {
+        test_case(1.5, 1.0, |x| x.index_of_dispersion());
+        test_case(5.4, 1.0, |x| x.index_of_dispersion());
+        test_case(10.8, 1.0, |x| x.index_of_dispersion());
+        test_almost(4.0, 0.5, 1e-15, |x| x.coefficient_of_variation());
+    }
+
     #[test]
     fn test_std_dev() {
         test_case(1.5, (1.5f64).sqrt(), |x| x.std_dev());
@@ -412,6 +540,20 @@ mod test {
         test_case(1.5, 1, |x| x.mode());
         test_case(5.4, 5, |x| x.mode());
         test_case(10.8, 10, |x| x.mode());
+        test_case(1.0, 0, |x| x.mode());
+        test_case(4.0, 3, |x| x.mode());
+    }
+
+    #[test]
+    fn test_modes() {
+        test_case(0.5, vec![0], |x| x.modes());
+        test_case(5.4, vec![5], |x| x.modes());
+        test_case(1.0, vec![0, 1], |x| x.modes());
+        test_case(4.0, vec![3, 4], |x| x.modes());
+        let n = try_create(4.0);
+        assert_almost_eq!(n.pmf(3), n.pmf(4), 1e-15);
+        assert!(n.pmf(4) > n.pmf(5));
+        assert!(n.pmf(3) > n.pmf(2));
     }
 
     #[test]
@@ -473,4 +615,72 @@ mod test {
         test::check_discrete_distribution(&try_create(0.3), 10);
         test::check_discrete_distribution(&try_create(4.5), 30);
     }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        for &lambda in &[2.5f64, 29.9, 30.0, 1000.0, 1e7] {
+            let n = try_create(lambda);
+            let samples: Vec<f64> = (0..100000).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0 && x == x.floor()));
+            assert_almost_eq!((&samples).mean() / lambda, 1.0, 0.01);
+            assert_almost_eq!((&samples).variance() / lambda, 1.0, 0.03);
+        }
+    }
+
+    #[test]
+    fn test_sample_frequencies() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let n = try_create(50.0);
+        let count = 200000;
+        let mut freq = vec![0.0; 200];
+        for _ in 0..count {
+            freq[n.sample(&mut r) as usize] += 1.0;
+        }
+        for x in 30..70 {
+            let expected = n.pmf(x) * count as f64;
+            assert!((freq[x as usize] - expected).abs() < 5.0 * expected.sqrt());
+        }
+    }
+
+    #[test]
+    fn test_support() {
+        let s = try_create(1.5).support();
+        assert_eq!(s, Support::DiscreteRange { lo: 0.0, hi: f64::INFINITY });
+        assert!(s.contains(1e6));
+        assert!(!s.contains(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_superpose() {
+        let a = try_create(1.5);
+        let b = try_create(5.4);
+        assert_eq!(a.superpose(&b).lambda(), 1.5 + 5.4);
+        assert_eq!(a.superpose(&b), b.superpose(&a));
+    }
+
+    #[test]
+    fn test_thin() {
+        let n = try_create(5.4);
+        assert_eq!(n.thin(1.0).unwrap(), n);
+        assert_almost_eq!(n.thin(0.3).unwrap().lambda(), 1.62, 1e-15);
+        assert!(n.thin(0.0).is_err());
+        assert!(n.thin(-0.1).is_err());
+        assert!(n.thin(1.1).is_err());
+        assert!(n.thin(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_thin_superpose_recovers_rate() {
+        let n = try_create(10.8);
+        for &p in &[0.1f64, 0.25, 0.5, 0.9] {
+            let kept = n.thin(p).unwrap();
+            let dropped = n.thin(1.0 - p).unwrap();
+            assert_almost_eq!(kept.superpose(&dropped).lambda(), n.lambda(), 1e-14);
+        }
+    }
 }
@@ -0,0 +1,243 @@
+use distribution::InverseCDF;
+use {Result, StatsError};
+
+/// Implements a precomputed lookup table of the inverse cumulative
+/// distribution function of a continuous distribution, trading a
+/// controllable amount of accuracy for fast repeated quantile queries
+///
+/// # Remarks
+///
+/// `build` evaluates `inverse_cdf` at `resolution + 1` probabilities
+/// between `1 / resolution` and `1 - 1 / resolution`, equally spaced on the
+/// logit scale `ln(p / (1 - p))` so that the grid is denser towards the
+/// tails where the quantile function changes fastest. `quantile`
+/// interpolates between them with a monotone (Fritsch-Carlson) cubic
+/// Hermite spline, so the tabulated quantile function is non-decreasing
+/// whenever the exact one is. Probabilities outside the tabulated range
+/// fall back to the exact `inverse_cdf` of the distribution.
+///
+/// The interpolation error shrinks faster than the square of the grid
+/// spacing, so each tenfold increase in `resolution` buys two to three more
+/// digits at the price of a tenfold larger table and build time. As a
+/// guide, a resolution of `100` tabulates the standard normal quantile to
+/// within `1e-6` and a resolution of `1000` to within `5e-9`. Accuracy is
+/// worst near points where the density vanishes or is discontinuous. The
+/// table is not suitable for discrete distributions, whose quantile
+/// functions are step functions.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::distribution::{InverseCDF, Normal, QuantileTable};
+///
+/// # fn main() {
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let table = QuantileTable::build(&n, 1000).unwrap();
+/// assert_almost_eq!(table.quantile(0.975), n.inverse_cdf(0.975), 5e-9);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantileTable<D> {
+    dist: D,
+    resolution: usize,
+    logit_min: f64,
+    step: f64,
+    values: Vec<f64>,
+    slopes: Vec<f64>,
+}
+
+impl<D> QuantileTable<D>
+where
+    D: InverseCDF<f64> + Clone,
+{
+    /// Builds a quantile table for `dist` by evaluating its inverse
+    /// cumulative distribution function on a grid of `resolution + 1`
+    /// probabilities
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `resolution < 4` or if `inverse_cdf` returns
+    /// a non-finite or decreasing value on the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, QuantileTable};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert!(QuantileTable::build(&n, 100).is_ok());
+    /// assert!(QuantileTable::build(&n, 3).is_err());
+    /// ```
+    pub fn build(dist: &D, resolution: usize) -> Result<QuantileTable<D>> {
+        if resolution < 4 {
+            return Err(StatsError::ArgGte("resolution", 4.0));
+        }
+        let logit_min = -((resolution - 1) as f64).ln();
+        let step = -2.0 * logit_min / resolution as f64;
+        let values: Vec<f64> = (0..resolution + 1)
+            .map(|i| dist.inverse_cdf(logistic(logit_min + i as f64 * step)))
+            .collect();
+        if values.iter().any(|x| !x.is_finite()) || values.windows(2).any(|w| w[1] < w[0]) {
+            return Err(StatsError::SpecialCase(
+                "Inverse cdf must be finite and non-decreasing on the grid",
+            ));
+        }
+
+        // Fritsch-Carlson slopes, the harmonic mean of the neighbouring
+        // secants which preserves monotonicity
+        let secants: Vec<f64> = values.windows(2).map(|w| (w[1] - w[0]) / step).collect();
+        let mut slopes = vec![0.0; resolution + 1];
+        slopes[0] = end_slope(secants[0], secants[1]);
+        slopes[resolution] = end_slope(secants[resolution - 1], secants[resolution - 2]);
+        for i in 1..resolution {
+            let (s0, s1) = (secants[i - 1], secants[i]);
+            if s0 > 0.0 && s1 > 0.0 {
+                slopes[i] = 2.0 / (1.0 / s0 + 1.0 / s1);
+            }
+        }
+
+        Ok(QuantileTable {
+            dist: dist.clone(),
+            resolution: resolution,
+            logit_min: logit_min,
+            step: step,
+            values: values,
+            slopes: slopes,
+        })
+    }
+
+    /// Returns the number of cells of the grid the table was built on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, QuantileTable};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let table = QuantileTable::build(&n, 100).unwrap();
+    /// assert_eq!(table.resolution(), 100);
+    /// ```
+    pub fn resolution(&self) -> usize {
+        self.resolution
+    }
+
+    /// Returns the tabulated inverse cumulative distribution function
+    /// at `p`, deferring to the exact `inverse_cdf` of the underlying
+    /// distribution outside of the tabulated range
+    ///
+    /// # Panics
+    ///
+    /// If the underlying distribution's `inverse_cdf` panics for `p`,
+    /// e.g. for `p` outside `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::distribution::{Normal, QuantileTable};
+    ///
+    /// # fn main() {
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let table = QuantileTable::build(&n, 1000).unwrap();
+    /// assert_almost_eq!(table.quantile(0.5), 0.0, 1e-15);
+    /// # }
+    /// ```
+    pub fn quantile(&self, p: f64) -> f64 {
+        // position of p in units of the grid spacing, relative to the
+        // first tabulated point
+        let x = ((p / (1.0 - p)).ln() - self.logit_min) / self.step;
+        if !(x >= 0.0 && x <= self.resolution as f64) {
+            return self.dist.inverse_cdf(p);
+        }
+
+        let i = (x.floor() as usize).min(self.resolution - 1);
+        let t = x - i as f64;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        (2.0 * t3 - 3.0 * t2 + 1.0) * self.values[i]
+            + (t3 - 2.0 * t2 + t) * self.step * self.slopes[i]
+            + (-2.0 * t3 + 3.0 * t2) * self.values[i + 1]
+            + (t3 - t2) * self.step * self.slopes[i + 1]
+    }
+}
+
+/// Returns the one-sided three point estimate of the slope at an end of
+/// the grid given the `outer` and `inner` secants next to it, limited so
+/// that monotonicity is preserved
+fn end_slope(outer: f64, inner: f64) -> f64 {
+    let slope = (3.0 * outer - inner) / 2.0;
+    if slope <= 0.0 || outer <= 0.0 {
+        0.0
+    } else if slope > 3.0 * outer {
+        3.0 * outer
+    } else {
+        slope
+    }
+}
+
+/// Returns the inverse of the logit function, `1 / (1 + e^-x)`
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use distribution::{Beta, Gamma, InverseCDF, Normal, QuantileTable};
+
+    fn max_error<D: InverseCDF<f64> + Clone>(dist: &D, resolution: usize) -> f64 {
+        let table = QuantileTable::build(dist, resolution).unwrap();
+        (1..100000)
+            .map(|i| i as f64 / 100000.0)
+            .map(|p| (table.quantile(p) - dist.inverse_cdf(p)).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_quantile_accuracy() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(max_error(&n, 100) < 1e-6);
+        assert!(max_error(&n, 1000) < 5e-9);
+        assert!(max_error(&Normal::new(-3.0, 0.1).unwrap(), 1000) < 5e-10);
+        assert!(max_error(&Gamma::new(3.0, 2.0).unwrap(), 1000) < 1e-8);
+        assert!(max_error(&Beta::new(2.0, 5.0).unwrap(), 1000) < 5e-9);
+    }
+
+    #[test]
+    fn test_quantile_nodes_and_tails() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let table = QuantileTable::build(&n, 100).unwrap();
+        assert_eq!(table.resolution(), 100);
+        for &p in &[1.0 / 100.0, 0.5, 99.0 / 100.0] {
+            assert_almost_eq!(table.quantile(p), n.inverse_cdf(p), 1e-12);
+        }
+        assert_eq!(table.quantile(1e-5), n.inverse_cdf(1e-5));
+        assert_eq!(table.quantile(1.0 - 1e-5), n.inverse_cdf(1.0 - 1e-5));
+        assert_eq!(table.quantile(0.0), n.inverse_cdf(0.0));
+    }
+
+    #[test]
+    fn test_quantile_monotone() {
+        let n = Gamma::new(0.8, 1.0).unwrap();
+        let table = QuantileTable::build(&n, 50).unwrap();
+        let mut prev = table.quantile(0.02);
+        for i in 201..9801 {
+            let q = table.quantile(i as f64 / 10000.0);
+            assert!(q >= prev);
+            prev = q;
+        }
+    }
+
+    #[test]
+    fn test_build_bad_resolution() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(QuantileTable::build(&n, 0).is_err());
+        assert!(QuantileTable::build(&n, 3).is_err());
+        assert!(QuantileTable::build(&n, 4).is_ok());
+    }
+}
@@ -0,0 +1,404 @@
+use distribution::{internal, poisson, Discrete, Domain, Parametric, Support, Univariate};
+use function::bessel;
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [Skellam](https://en.wikipedia.org/wiki/Skellam_distribution)
+/// distribution, the distribution of the difference of two independent
+/// poisson random variables
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Skellam, Discrete};
+/// use statrs::statistics::{Mean, Variance};
+///
+/// let n = Skellam::new(3.0, 1.0).unwrap();
+/// assert_eq!(n.mean(), 2.0);
+/// assert_eq!(n.variance(), 4.0);
+/// assert!(n.pmf(2) > n.pmf(-2));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Skellam {
+    mu1: f64,
+    mu2: f64,
+}
+
+impl Skellam {
+    /// Constructs a new Skellam distribution of the difference of poisson
+    /// random variables with rates `mu1` and `mu2`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mu1` or `mu2` are `NaN`, infinite or not
+    /// positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Skellam;
+    ///
+    /// let mut result = Skellam::new(1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Skellam::new(1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(mu1: f64, mu2: f64) -> Result<Skellam> {
+        if !mu1.is_finite() || !mu2.is_finite() || mu1 <= 0.0 || mu2 <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Skellam { mu1: mu1, mu2: mu2 })
+        }
+    }
+
+    /// Returns the rate of the minuend of the Skellam distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Skellam;
+    ///
+    /// let n = Skellam::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.mu1(), 1.0);
+    /// ```
+    pub fn mu1(&self) -> f64 {
+        self.mu1
+    }
+
+    /// Returns the rate of the subtrahend of the Skellam distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Skellam;
+    ///
+    /// let n = Skellam::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.mu2(), 2.0);
+    /// ```
+    pub fn mu2(&self) -> f64 {
+        self.mu2
+    }
+
+    /// Sums the pmf from `start` in steps of `step` until the terms become
+    /// negligible. Since the pmf is unimodal this sums the tail beyond
+    /// `start` if `start` lies on the same side of the mode as the tail
+    fn tail_sum(&self, start: i64, step: i64) -> f64 {
+        let mut sum = 0.0;
+        let mut k = start;
+        loop {
+            let p = self.pmf(k);
+            sum += p;
+            if p <= sum * 1e-17 {
+                return sum;
+            }
+            k += step;
+        }
+    }
+}
+
+impl Parametric for Skellam {
+    /// Returns the `mu1` and `mu2` parameters of the Skellam distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("mu1", self.mu1), ("mu2", self.mu2)]
+    }
+
+    /// Constructs a Skellam distribution from its `mu1` and `mu2` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Skellam> {
+        let p = internal::parameter_values(params, &["mu1", "mu2"])?;
+        Skellam::new(p[0], p[1])
+    }
+}
+
+impl Distribution<f64> for Skellam {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        poisson::sample_unchecked(r, self.mu1) - poisson::sample_unchecked(r, self.mu2)
+    }
+}
+
+impl Univariate<i64, f64> for Skellam {
+    /// Calculates the cumulative distribution function for the Skellam
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is the sum of the pmf over the lower tail if `x` lies below
+    /// the mean and one minus the sum over the upper tail otherwise, so
+    /// that the smaller tail is always summed directly
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_(k <= floor(x)) pmf(k)
+    /// ```
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            f64::NAN
+        } else if x < i64::MIN as f64 {
+            0.0
+        } else if x >= i64::MAX as f64 {
+            1.0
+        } else {
+            let k = x.floor() as i64;
+            if (k as f64) < self.mean() {
+                self.tail_sum(k, -1)
+            } else {
+                1.0 - self.tail_sum(k + 1, 1)
+            }
+        }
+    }
+}
+
+impl Min<i64> for Skellam {
+    /// Returns the minimum value in the domain of the Skellam distribution
+    /// representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -2^63
+    /// ```
+    fn min(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+impl Max<i64> for Skellam {
+    /// Returns the maximum value in the domain of the Skellam distribution
+    /// representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^63 - 1
+    /// ```
+    fn max(&self) -> i64 {
+        i64::MAX
+    }
+}
+
+impl Domain for Skellam {
+    /// Returns the support of the Skellam distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {..., -1, 0, 1, ...}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
+impl Mean<f64> for Skellam {
+    /// Returns the mean of the Skellam distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ_1 - μ_2
+    /// ```
+    fn mean(&self) -> f64 {
+        self.mu1 - self.mu2
+    }
+}
+
+impl Variance<f64> for Skellam {
+    /// Returns the variance of the Skellam distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ_1 + μ_2
+    /// ```
+    fn variance(&self) -> f64 {
+        self.mu1 + self.mu2
+    }
+
+    /// Returns the standard deviation of the Skellam distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(μ_1 + μ_2)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for Skellam {}
+
+impl Skewness<f64> for Skellam {
+    /// Returns the skewness of the Skellam distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (μ_1 - μ_2) / (μ_1 + μ_2)^(3 / 2)
+    /// ```
+    fn skewness(&self) -> f64 {
+        self.mean() / self.variance().powf(1.5)
+    }
+}
+
+impl Discrete<i64, f64> for Skellam {
+    /// Calculates the probability mass function for the Skellam
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-(μ_1 + μ_2)) * (μ_1 / μ_2)^(x / 2) * I_|x|(2 * sqrt(μ_1 * μ_2))
+    /// ```
+    ///
+    /// where `I_n` is the modified Bessel function of the first kind
+    fn pmf(&self, x: i64) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Calculates the log probability mass function for the Skellam
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(μ_1 + μ_2) + x / 2 * ln(μ_1 / μ_2) + ln(I_|x|(2 * sqrt(μ_1 * μ_2)))
+    /// ```
+    ///
+    /// where `I_n` is the modified Bessel function of the first kind
+    fn ln_pmf(&self, x: i64) -> f64 {
+        let arg = 2.0 * (self.mu1 * self.mu2).sqrt();
+        -(self.mu1 + self.mu2)
+            + 0.5 * x as f64 * (self.mu1 / self.mu2).ln()
+            + bessel::ln_bessel_i(x.unsigned_abs(), arg)
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Discrete, Poisson, Skellam, Univariate};
+    use rng;
+
+    fn try_create(mu1: f64, mu2: f64) -> Skellam {
+        let n = Skellam::new(mu1, mu2);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(mu1: f64, mu2: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Skellam) -> f64
+    {
+        let x = eval(try_create(mu1, mu2));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(1.5, 0.3);
+        assert_eq!(n.mu1(), 1.5);
+        assert_eq!(n.mu2(), 0.3);
+        assert!(Skellam::new(0.0, 1.0).is_err());
+        assert!(Skellam::new(1.0, -1.0).is_err());
+        assert!(Skellam::new(f64::NAN, 1.0).is_err());
+        assert!(Skellam::new(1.0, f64::NAN).is_err());
+        assert!(Skellam::new(f64::INFINITY, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(3.5, 1.2);
+        assert_almost_eq!(n.mean(), 2.3, 1e-15);
+        assert_almost_eq!(n.variance(), 4.7, 1e-15);
+        assert_almost_eq!(n.std_dev(), 4.7f64.sqrt(), 1e-15);
+        assert_almost_eq!(n.skewness(), 2.3 / 4.7f64.powf(1.5), 1e-15);
+        assert_eq!(try_create(2.0, 2.0).skewness(), 0.0);
+        assert_eq!(n.min(), i64::MIN);
+        assert_eq!(n.max(), i64::MAX);
+    }
+
+    #[test]
+    fn test_pmf() {
+        test_almost(1.0, 1.0, 0.028791222639470898, 1e-16, |x| x.pmf(-3));
+        test_almost(1.0, 1.0, 0.30850832255367104, 1e-15, |x| x.pmf(0));
+        test_almost(1.0, 1.0, 0.09323903330473338, 1e-16, |x| x.pmf(2));
+        test_almost(3.5, 1.2, 0.006839592206618135, 1e-17, |x| x.pmf(-3));
+        test_almost(3.5, 1.2, 0.1883808517321633, 1e-15, |x| x.pmf(2));
+        test_almost(3.5, 1.2, 0.07768301175383288, 1e-16, |x| x.pmf(5));
+        test_almost(20.0, 25.0, 0.057154443167191586, 1e-15, |x| x.pmf(-3));
+        test_almost(20.0, 25.0, 0.019541054115286154, 1e-15, |x| x.pmf(5));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        test_almost(1.0, 1.0, -1.1760064585170438, 1e-14, |x| x.ln_pmf(0));
+        test_almost(3.5, 1.2, -4.985027168040434, 1e-14, |x| x.ln_pmf(-3));
+        test_almost(20.0, 25.0, -3.935237687287959, 1e-13, |x| x.ln_pmf(5));
+    }
+
+    #[test]
+    fn test_pmf_is_poisson_difference() {
+        let a = Poisson::new(3.5).unwrap();
+        let b = Poisson::new(1.2).unwrap();
+        let n = try_create(3.5, 1.2);
+        for k in -5i64..8 {
+            let expected: f64 = (0..100u64)
+                .filter(|&j| j as i64 + k >= 0)
+                .map(|j| a.pmf((j as i64 + k) as u64) * b.pmf(j))
+                .sum();
+            assert_almost_eq!(n.pmf(k), expected, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        for &(mu1, mu2) in &[(1.0f64, 1.0f64), (3.5, 1.2), (0.05, 8.0), (20.0, 25.0), (300.0, 200.0)] {
+            let n = try_create(mu1, mu2);
+            let sum: f64 = (-500i64..500).map(|k| n.pmf(k)).sum();
+            assert_almost_eq!(sum, 1.0, 1e-12);
+            let mean: f64 = (-500i64..500).map(|k| k as f64 * n.pmf(k)).sum();
+            assert_almost_eq!(mean, n.mean(), 1e-9);
+            let variance: f64 = (-500i64..500).map(|k| (k as f64 - mean).powi(2) * n.pmf(k)).sum();
+            assert_almost_eq!(variance, n.variance(), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(1.0, 1.0, 0.03723751616949344, 1e-15, |x| x.cdf(-3.0));
+        test_almost(1.0, 1.0, 0.6542541612768356, 1e-15, |x| x.cdf(0.5));
+        test_almost(3.5, 1.2, 0.009017323263027396, 1e-16, |x| x.cdf(-3.0));
+        test_almost(3.5, 1.2, 0.5541966346243301, 1e-15, |x| x.cdf(2.0));
+        test_almost(20.0, 25.0, 0.6449074243758613, 1e-14, |x| x.cdf(-3.0));
+        test_almost(20.0, 25.0, 0.8689868568453082, 1e-14, |x| x.cdf(2.0));
+        let n = try_create(2.0, 3.0);
+        assert_eq!(n.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+        assert!(n.cdf(f64::NAN).is_nan());
+        let mut sum = 0.0;
+        for k in -60i64..60 {
+            sum += n.pmf(k);
+            assert_almost_eq!(n.cdf(k as f64), sum, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = rng::seeded(0);
+        let n = try_create(3.5, 1.2);
+        let samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x == x.floor()));
+        assert_almost_eq!(samples.iter().mean(), n.mean(), 0.05);
+        assert_almost_eq!(samples.iter().variance(), n.variance(), 0.15);
+    }
+}
@@ -0,0 +1,252 @@
+use distribution::internal;
+use distribution::{InverseCDF, StudentsT, Univariate};
+use rand::distributions::Distribution;
+use rand::Rng;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [Student's t
+/// copula](https://en.wikipedia.org/wiki/Copula_(probability_theory)#Elliptical_copulas),
+/// the joint distribution of uniform marginals whose dependence is that of
+/// a multivariate Student's t-distribution with a given correlation matrix
+/// and degrees of freedom
+///
+/// # Remarks
+///
+/// Unlike the Gaussian copula, whose coordinates become independent
+/// in the joint extremes for any correlation below one, the t copula
+/// exhibits tail dependence: extreme values of one coordinate tend to
+/// coincide with extreme values of the others. The dependence in the
+/// tails grows as the degrees of freedom decrease, and the copula
+/// approaches the Gaussian copula as they tend to infinity.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate rand;
+/// # extern crate statrs;
+/// use rand::distributions::Distribution;
+/// use statrs::distribution::StudentTCopula;
+///
+/// # fn main() {
+/// let c = StudentTCopula::new(&[1.0, 0.8, 0.8, 1.0], 3.0).unwrap();
+/// let mut r = rand::thread_rng();
+/// let u = c.sample(&mut r);
+/// assert_eq!(u.len(), 2);
+/// assert!(u.iter().all(|&x| x >= 0.0 && x <= 1.0));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudentTCopula {
+    dim: usize,
+    correlation: Vec<f64>,
+    chol: Vec<f64>,
+    marginal: StudentsT,
+}
+
+impl StudentTCopula {
+    /// Constructs a new Student's t copula from a correlation matrix stored
+    /// in row-major order and the degrees of freedom `freedom`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `correlation` is empty, not square, contains
+    /// `NaN` or infinite entries, or is not a symmetric positive-definite
+    /// matrix with a unit diagonal. Returns an error if `freedom` is `NaN`
+    /// or `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentTCopula;
+    ///
+    /// let mut result = StudentTCopula::new(&[1.0, 0.5, 0.5, 1.0], 4.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = StudentTCopula::new(&[1.0, 0.5, 0.5, 1.0], 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(correlation: &[f64], freedom: f64) -> Result<StudentTCopula> {
+        if freedom.is_nan() || freedom <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let (dim, chol) = internal::correlation_cholesky(correlation)?;
+        Ok(StudentTCopula {
+            dim: dim,
+            correlation: correlation.to_vec(),
+            chol: chol,
+            marginal: StudentsT::new(0.0, 1.0, freedom)?,
+        })
+    }
+
+    /// Returns the number of dimensions of the copula
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentTCopula;
+    ///
+    /// let c = StudentTCopula::new(&[1.0, 0.5, 0.5, 1.0], 4.0).unwrap();
+    /// assert_eq!(c.dim(), 2);
+    /// ```
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the correlation matrix of the copula in row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentTCopula;
+    ///
+    /// let c = StudentTCopula::new(&[1.0, 0.5, 0.5, 1.0], 4.0).unwrap();
+    /// assert_eq!(c.correlation(), [1.0, 0.5, 0.5, 1.0]);
+    /// ```
+    pub fn correlation(&self) -> &[f64] {
+        &self.correlation
+    }
+
+    /// Returns the degrees of freedom of the copula
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentTCopula;
+    ///
+    /// let c = StudentTCopula::new(&[1.0, 0.5, 0.5, 1.0], 4.0).unwrap();
+    /// assert_eq!(c.freedom(), 4.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.marginal.freedom()
+    }
+
+    /// Draws a sample whose `i`th coordinate is distributed according to
+    /// `marginals[i]`, with the dependence between the coordinates given
+    /// by the copula
+    ///
+    /// # Panics
+    ///
+    /// If the number of marginals differs from the dimension of the copula
+    pub fn sample_marginals<R: Rng + ?Sized>(
+        &self,
+        r: &mut R,
+        marginals: &[&dyn InverseCDF<f64>],
+    ) -> Vec<f64> {
+        assert_eq!(marginals.len(), self.dim);
+        self.sample(r)
+            .into_iter()
+            .zip(marginals)
+            .map(|(u, d)| d.inverse_cdf(u))
+            .collect()
+    }
+}
+
+impl Distribution<Vec<f64>> for StudentTCopula {
+    /// Draws a vector of dependent uniforms by dividing a correlated
+    /// multivariate normal sample by the square root of an independent
+    /// chi-squared sample over its degrees of freedom, and applying the
+    /// cdf of the univariate Student's t-distribution to each coordinate
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> Vec<f64> {
+        let freedom = self.marginal.freedom();
+        let w = if freedom == f64::INFINITY {
+            1.0
+        } else {
+            super::gamma::sample_unchecked(r, freedom / 2.0, freedom / 2.0)
+        };
+        let scale = w.sqrt();
+        internal::sample_correlated_normals(r, &self.chol, self.dim)
+            .into_iter()
+            .map(|z| self.marginal.cdf(z / scale))
+            .collect()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Beta, GaussianCopula, InverseCDF, Normal, StudentTCopula};
+    use rng;
+
+    fn samples<D: Distribution<Vec<f64>>>(dist: &D, count: usize) -> Vec<Vec<f64>> {
+        let mut r = rng::seeded(0);
+        (0..count).map(|_| dist.sample(&mut r)).collect()
+    }
+
+    // counts the samples whose first two coordinates lie jointly in the same
+    // upper or lower tail beyond the quantile `q`
+    fn joint_tail_count(samples: &[Vec<f64>], q: f64) -> usize {
+        samples.iter().filter(|s| {
+            (s[0] > q && s[1] > q) || (s[0] < 1.0 - q && s[1] < 1.0 - q)
+        }).count()
+    }
+
+    #[test]
+    fn test_create() {
+        let corr = [1.0, 0.5, 0.5, 1.0];
+        assert!(StudentTCopula::new(&corr, 1.0).is_ok());
+        assert!(StudentTCopula::new(&corr, 0.5).is_ok());
+        assert!(StudentTCopula::new(&corr, f64::INFINITY).is_ok());
+        assert!(StudentTCopula::new(&corr, 0.0).is_err());
+        assert!(StudentTCopula::new(&corr, -1.0).is_err());
+        assert!(StudentTCopula::new(&corr, f64::NAN).is_err());
+        assert!(StudentTCopula::new(&[], 3.0).is_err());
+        assert!(StudentTCopula::new(&[1.0, 0.5, 0.4, 1.0], 3.0).is_err());
+        assert!(StudentTCopula::new(&[1.0, 1.2, 1.2, 1.0], 3.0).is_err());
+    }
+
+    #[test]
+    fn test_uniform_marginals() {
+        let c = StudentTCopula::new(&[1.0, 0.3, 0.3, 1.0], 2.5).unwrap();
+        let samples = samples(&c, 20000);
+        for i in 0..2 {
+            let u: Vec<f64> = samples.iter().map(|s| s[i]).collect();
+            assert!(u.iter().all(|&x| x >= 0.0 && x <= 1.0));
+            assert_almost_eq!(u.iter().mean(), 0.5, 0.01);
+            assert_almost_eq!(u.iter().variance(), 1.0 / 12.0, 0.002);
+        }
+    }
+
+    #[test]
+    fn test_tail_dependence() {
+        let corr = [1.0, 0.5, 0.5, 1.0];
+        let t = samples(&StudentTCopula::new(&corr, 3.0).unwrap(), 20000);
+        let g = samples(&GaussianCopula::new(&corr).unwrap(), 20000);
+        // under independence about 4 samples would be jointly in the tails
+        let t_count = joint_tail_count(&t, 0.99);
+        let g_count = joint_tail_count(&g, 0.99);
+        assert!(g_count > 10);
+        assert!(t_count > 2 * g_count);
+    }
+
+    #[test]
+    fn test_infinite_freedom_is_gaussian() {
+        let corr = [1.0, 0.5, 0.5, 1.0];
+        let t = samples(&StudentTCopula::new(&corr, f64::INFINITY).unwrap(), 100);
+        let g = samples(&GaussianCopula::new(&corr).unwrap(), 100);
+        for (a, b) in t.iter().zip(g.iter()) {
+            assert_almost_eq!(a[0], b[0], 1e-9);
+            assert_almost_eq!(a[1], b[1], 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_sample_marginals() {
+        let mut r = rng::seeded(0);
+        let c = StudentTCopula::new(&[1.0, 0.6, 0.6, 1.0], 4.0).unwrap();
+        let b = Beta::new(2.0, 5.0).unwrap();
+        let n = Normal::new(3.0, 1.5).unwrap();
+        let marginals: [&dyn InverseCDF<f64>; 2] = [&b, &n];
+        let samples: Vec<Vec<f64>> = (0..20000).map(|_| c.sample_marginals(&mut r, &marginals)).collect();
+        let xs: Vec<f64> = samples.iter().map(|s| s[0]).collect();
+        let ys: Vec<f64> = samples.iter().map(|s| s[1]).collect();
+        assert_almost_eq!(xs.iter().mean(), 2.0 / 7.0, 0.005);
+        assert_almost_eq!(xs.iter().variance(), 10.0 / 392.0, 0.001);
+        assert_almost_eq!(ys.iter().mean(), 3.0, 0.03);
+        assert_almost_eq!(ys.iter().std_dev(), 1.5, 0.03);
+        assert!(xs.iter().covariance(ys.iter()) > 0.0);
+    }
+}
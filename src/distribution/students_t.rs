@@ -1,5 +1,8 @@
-use distribution::{Continuous, Univariate};
-use function::{beta, gamma};
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Normal, Parametric, Support,
+    Univariate,
+};
+use function::{beta, gamma, optimization};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -104,6 +107,25 @@ impl StudentsT {
     }
 }
 
+impl Parametric for StudentsT {
+    /// Returns the `location`, `scale` and `freedom` parameters of the
+    /// student's t-distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("location", self.location),
+            ("scale", self.scale),
+            ("freedom", self.freedom),
+        ]
+    }
+
+    /// Constructs a student's t-distribution from its `location`, `scale` and
+    /// `freedom` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<StudentsT> {
+        let p = internal::parameter_values(params, &["location", "scale", "freedom"])?;
+        StudentsT::new(p[0], p[1], p[2])
+    }
+}
+
 impl Distribution<f64> for StudentsT {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         // based on method 2, section 5 in chapter 9 of L. Devroye's
@@ -152,6 +174,74 @@ impl Univariate<f64, f64> for StudentsT {
     }
 }
 
+impl InverseCDF<f64> for StudentsT {
+    /// Calculates the inverse cumulative distribution function for the
+    /// student's t-distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution for general freedom, the root of
+    /// `cdf(y) - x` is found numerically with
+    /// `function::optimization::find_root`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for StudentsT {
+    /// Calculates the inverse cumulative distribution function for the
+    /// student's t-distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form solution for general freedom, the root of
+    /// `cdf(y) - x` is found numerically with
+    /// `function::optimization::find_root`. The root is searched for in the
+    /// lower tail, using the symmetry about the location for `x > 0.5`, to
+    /// avoid the cancellation of `1 - cdf(y)` in the upper tail
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            return Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0));
+        }
+        if x == 0.0 {
+            return Ok(f64::NEG_INFINITY);
+        }
+        if x == 1.0 {
+            return Ok(f64::INFINITY);
+        }
+        if x == 0.5 {
+            return Ok(self.location);
+        }
+        if self.freedom == f64::INFINITY {
+            return Normal::new(self.location, self.scale)?.checked_inverse_cdf(x);
+        }
+
+        // probability of the lower tail below the standardized value k <= 0
+        let v = self.freedom;
+        let lower_tail = |k: f64| 0.5 * beta::beta_reg(v / 2.0, 0.5, v / (v + k * k));
+        let p = x.min(1.0 - x);
+        // grow the bracket until it contains the quantile
+        let mut lower = -1.0;
+        while lower_tail(lower) > p {
+            if lower.is_infinite() {
+                return Err(StatsError::ComputationFailedToConverge);
+            }
+            lower *= 2.0;
+        }
+        let k = optimization::checked_find_root(|k| lower_tail(k) - p, lower, 0.0, 0.0)?;
+        let k = if x < 0.5 { k } else { -k };
+        Ok(self.location + self.scale * k)
+    }
+}
+
 impl Min<f64> for StudentsT {
     /// Returns the minimum value in the domain of the student's t-distribution
     /// representable by a double precision float
@@ -180,6 +270,24 @@ impl Max<f64> for StudentsT {
     }
 }
 
+impl Domain for StudentsT {
+    /// Returns the support of the student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (-∞, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+            lo_closed: false,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for StudentsT {
     /// Returns the mean of the student's t-distribution
     ///
@@ -271,6 +379,26 @@ impl Variance<f64> for StudentsT {
     }
 }
 
+impl Dispersion for StudentsT {
+    /// Returns the coefficient of variation of the distribution, or `NaN`
+    /// if its mean is zero or its mean or variance is undefined
+    fn coefficient_of_variation(&self) -> f64 {
+        match (self.checked_mean(), self.checked_std_dev()) {
+            (Ok(mean), Ok(std_dev)) if mean != 0.0 && mean.is_finite() => std_dev / mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Returns the index of dispersion of the distribution, or `NaN` if its
+    /// mean is zero or its mean or variance is undefined
+    fn index_of_dispersion(&self) -> f64 {
+        match (self.checked_mean(), self.checked_variance()) {
+            (Ok(mean), Ok(variance)) if mean != 0.0 && mean.is_finite() => variance / mean,
+            _ => f64::NAN,
+        }
+    }
+}
+
 impl CheckedVariance<f64> for StudentsT {
     /// Returns the variance of the student's t-distribution
     ///
@@ -514,7 +642,7 @@ mod test {
     use std::f64;
     use std::panic;
     use statistics::*;
-    use distribution::{Univariate, Continuous, StudentsT};
+    use distribution::{Univariate, Continuous, Normal, Parametric, StudentsT, InverseCDF, CheckedInverseCDF};
     use distribution::internal::*;
 
     fn try_create(location: f64, scale: f64, freedom: f64) -> StudentsT {
@@ -556,6 +684,13 @@ mod test {
         assert_almost_eq!(expected, x, acc);
     }
 
+    fn test_is_nan<F>(location: f64, scale: f64, freedom: f64, eval: F)
+        where F: Fn(StudentsT) -> f64
+    {
+        let x = get_value(location, scale, freedom, eval);
+        assert!(x.is_nan());
+    }
+
     fn test_panic<F>(location: f64, scale: f64, freedom: f64, eval: F)
         where F : Fn(StudentsT) -> f64,
               F : panic::UnwindSafe
@@ -596,6 +731,18 @@ mod test {
         test_panic(0.0, f64::INFINITY, 1.0, |x| x.mean());
     }
 
+    #[test]
+    fn test_dispersion() {
+        test_almost(2.0, 1.0, 4.0, 0.5f64.sqrt(), 1e-15, |x| x.coefficient_of_variation());
+        test_almost(2.0, 1.0, 4.0, 1.0, 1e-15, |x| x.index_of_dispersion());
+        // an infinite variance gives an infinite ratio
+        test_case(2.0, 1.0, 2.0, f64::INFINITY, |x| x.coefficient_of_variation());
+        test_case(2.0, 1.0, 1.5, f64::INFINITY, |x| x.index_of_dispersion());
+        // the mean is undefined
+        test_is_nan(2.0, 1.0, 1.0, |x| x.coefficient_of_variation());
+        test_is_nan(2.0, 1.0, 0.5, |x| x.index_of_dispersion());
+    }
+
     #[test]
     #[should_panic]
     fn test_mean_freedom_lte_1() {
@@ -630,6 +777,7 @@ mod test {
     #[test]
     fn test_checked_variance_freedom_lte1() {
         assert!(try_create(1.0, 1.0, 0.5).checked_variance().is_err());
+        assert!(try_create(1.0, 1.0, 1.0).checked_variance().is_err());
     }
 
     #[test]
@@ -786,10 +934,85 @@ mod test {
         test_almost(0.0, 1.0, f64::INFINITY, 0.977249868051821, 1e-11, |x| x.cdf(2.0));
     }
 
+    #[test]
+    fn test_cdf_huge_freedom() {
+        // the continued fraction of beta_reg is bounded for huge shapes
+        let n = try_create(0.0, 1.0, 1e300);
+        let x = n.cdf(1.0);
+        assert!(x >= 0.5 && x <= 1.0);
+        let x = n.cdf(-1.0);
+        assert!(x >= 0.0 && x <= 0.5);
+        test_almost(0.0, 1.0, 1e12, 0.8413447460685429, 1e-2, |x| x.cdf(1.0));
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(0.0, 1.0, 3.0), -30.0, 30.0);
         test::check_continuous_distribution(&try_create(0.0, 1.0, 10.0), -10.0, 10.0);
         test::check_continuous_distribution(&try_create(20.0, 0.5, 10.0), 10.0, 30.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(0.0, 1.0, 5.0, 2.5705818356363155, 1e-13, |x| x.inverse_cdf(0.975));
+        test_almost(0.0, 1.0, 5.0, -2.5705818356363155, 1e-13, |x| x.inverse_cdf(0.025));
+        test_almost(0.0, 1.0, 2.5, -1.7302509288071766, 1e-13, |x| x.inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 30.0, 3.385184866829305, 1e-12, |x| x.inverse_cdf(0.999));
+        test_almost(1.0, 2.0, 5.0, 1.0 + 2.0 * 2.5705818356363155, 1e-12, |x| x.inverse_cdf(0.975));
+        test_case(3.0, 2.0, 5.0, 3.0, |x| x.inverse_cdf(0.5));
+        test_case(3.0, 2.0, 5.0, f64::NEG_INFINITY, |x| x.inverse_cdf(0.0));
+        test_case(3.0, 2.0, 5.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        // closed forms for one and two degrees of freedom
+        for &p in &[0.001f64, 0.2, 0.6, 0.95] {
+            let cauchy = (f64::consts::PI * (p - 0.5)).tan();
+            test_almost(0.0, 1.0, 1.0, 1.0, 1e-12, |x| x.inverse_cdf(p) / cauchy);
+            test_almost(0.0, 1.0, 2.0, (2.0 * p - 1.0) / (2.0 * p * (1.0 - p)).sqrt(), 1e-12, |x| x.inverse_cdf(p));
+            test_almost(-1.0, 0.5, f64::INFINITY, Normal::new(-1.0, 0.5).unwrap().inverse_cdf(p), 1e-15, |x| x.inverse_cdf(p));
+        }
+        let n = try_create(0.0, 1.0, 3.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        for &(location, scale, freedom) in &[(0.0f64, 1.0f64, 1.0f64), (2.0, 0.5, 3.5), (-1.0, 3.0, 0.5), (0.0, 1.0, 100.0)] {
+            let n = try_create(location, scale, freedom);
+            for &p in &[1e-8f64, 0.01, 0.3, 0.5, 0.7, 0.99, 1.0 - 1e-8] {
+                let x = n.inverse_cdf(p);
+                assert_almost_eq!(n.cdf(x), p, 1e-12 * p.min(1.0 - p).max(1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_location_scale() {
+        // the location-scale density is the standardized density shifted
+        // and scaled
+        for &freedom in &[1.0f64, 2.5, 7.0, f64::INFINITY] {
+            let std = try_create(0.0, 1.0, freedom);
+            let n = try_create(-2.0, 3.0, freedom);
+            for &x in &[-10.0f64, -2.0, 0.0, 1.5, 8.0] {
+                let z = (x + 2.0) / 3.0;
+                assert_almost_eq!(n.pdf(x), std.pdf(z) / 3.0, 1e-15);
+                assert_almost_eq!(n.ln_pdf(x), std.ln_pdf(z) - 3f64.ln(), 1e-14);
+                assert_almost_eq!(n.cdf(x), std.cdf(z), 1e-15);
+            }
+        }
+        assert_eq!(try_create(-2.0, 3.0, 5.0).mean(), -2.0);
+        assert_almost_eq!(try_create(-2.0, 3.0, 5.0).variance(), 9.0 * 5.0 / 3.0, 1e-14);
+        assert_almost_eq!(try_create(1.0, 0.5, 4.0).variance(), 0.25 * 2.0, 1e-15);
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(try_create(1.0, 0.5, 3.0).parameters(), vec![("location", 1.0), ("scale", 0.5), ("freedom", 3.0)]);
+    }
+
+    #[test]
+    fn test_from_parameters() {
+        let n = try_create(1.0, 0.5, 3.0);
+        assert_eq!(StudentsT::from_parameters(&n.parameters()).unwrap(), n);
+        assert!(StudentsT::from_parameters(&[("location", 1.0), ("scale", 0.5), ("dof", 3.0)]).is_err());
+    }
 }
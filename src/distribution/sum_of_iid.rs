@@ -0,0 +1,324 @@
+use function::erf;
+use rand::distributions::Distribution;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the distribution of the sum of `n` independent draws from a
+/// distribution `D`
+///
+/// # Remarks
+///
+/// Samples are drawn by summing `n` samples of `D`, while the cdf is the
+/// normal approximation given by the central limit theorem. The
+/// approximation is exact only if `D` is normal and can be poor for small
+/// `n`, particularly in the tails and for skewed or discrete `D`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Exponential, SumOfIid};
+/// use statrs::statistics::{Mean, Variance};
+///
+/// let n = SumOfIid::new(Exponential::new(2.0).unwrap(), 10).unwrap();
+/// assert_eq!(n.mean(), 5.0);
+/// assert_eq!(n.variance(), 2.5);
+/// assert_eq!(n.cdf(5.0), 0.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SumOfIid<D> {
+    dist: D,
+    n: u64,
+}
+
+impl<D> SumOfIid<D> {
+    /// Constructs a new distribution of the sum of `n` independent draws
+    /// from `dist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n == 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{SumOfIid, Uniform};
+    ///
+    /// let u = Uniform::new(0.0, 1.0).unwrap();
+    /// let mut result = SumOfIid::new(u, 12);
+    /// assert!(result.is_ok());
+    ///
+    /// result = SumOfIid::new(u, 0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, n: u64) -> Result<SumOfIid<D>> {
+        if n == 0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(SumOfIid { dist: dist, n: n })
+        }
+    }
+
+    /// Returns the distribution of each summand
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{SumOfIid, Uniform};
+    ///
+    /// let u = Uniform::new(0.0, 1.0).unwrap();
+    /// let n = SumOfIid::new(u, 12).unwrap();
+    /// assert_eq!(*n.distribution(), u);
+    /// ```
+    pub fn distribution(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the number of summands
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{SumOfIid, Uniform};
+    ///
+    /// let n = SumOfIid::new(Uniform::new(0.0, 1.0).unwrap(), 12).unwrap();
+    /// assert_eq!(n.n(), 12);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl<D> SumOfIid<D>
+where
+    D: Mean<f64> + Variance<f64>,
+{
+    /// Calculates the normal approximation to the cumulative distribution
+    /// function of the sum at `x`
+    ///
+    /// # Remarks
+    ///
+    /// By the central limit theorem the error of the approximation vanishes
+    /// as `n` grows if `D` has a finite variance, but for small `n` it is
+    /// only approximate. A summand without variability gives the step at
+    /// the sum of its constant value, and `NaN` is returned if the mean or
+    /// variance of `D` is not finite.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * (1 + erf((x - nμ) / sqrt(2nσ^2)))
+    /// ```
+    ///
+    /// where `μ` and `σ^2` are the mean and variance of `D`
+    pub fn cdf(&self, x: f64) -> f64 {
+        let mean = self.mean();
+        let std_dev = self.std_dev();
+        if !mean.is_finite() || !std_dev.is_finite() {
+            f64::NAN
+        } else if std_dev == 0.0 {
+            if x < mean {
+                0.0
+            } else {
+                1.0
+            }
+        } else {
+            0.5 * erf::erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
+        }
+    }
+}
+
+impl<D> Distribution<f64> for SumOfIid<D>
+where
+    D: Distribution<f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        (0..self.n).map(|_| self.dist.sample(r)).sum()
+    }
+}
+
+impl<D> Min<f64> for SumOfIid<D>
+where
+    D: Min<f64>,
+{
+    /// Returns the minimum value in the domain of the sum representable by a
+    /// double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * min(X)
+    /// ```
+    fn min(&self) -> f64 {
+        self.n as f64 * self.dist.min()
+    }
+}
+
+impl<D> Max<f64> for SumOfIid<D>
+where
+    D: Max<f64>,
+{
+    /// Returns the maximum value in the domain of the sum representable by a
+    /// double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * max(X)
+    /// ```
+    fn max(&self) -> f64 {
+        self.n as f64 * self.dist.max()
+    }
+}
+
+impl<D> Mean<f64> for SumOfIid<D>
+where
+    D: Mean<f64>,
+{
+    /// Returns the mean of the sum
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// nμ
+    /// ```
+    ///
+    /// where `μ` is the mean of `D`
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.dist.mean()
+    }
+}
+
+impl<D> Variance<f64> for SumOfIid<D>
+where
+    D: Variance<f64>,
+{
+    /// Returns the variance of the sum
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// nσ^2
+    /// ```
+    ///
+    /// where `σ^2` is the variance of `D`
+    fn variance(&self) -> f64 {
+        self.n as f64 * self.dist.variance()
+    }
+
+    /// Returns the standard deviation of the sum
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(nσ^2)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl<D> Dispersion for SumOfIid<D> where D: Variance<f64> {}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Binomial, Exponential, Gamma, Normal, Pareto, SumOfIid, Uniform, Univariate};
+    use rng;
+
+    #[test]
+    fn test_create() {
+        let u = Uniform::new(0.0, 1.0).unwrap();
+        let n = SumOfIid::new(u, 3).unwrap();
+        assert_eq!(n.n(), 3);
+        assert_eq!(*n.distribution(), u);
+        assert!(SumOfIid::new(u, 0).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = SumOfIid::new(Uniform::new(1.0, 3.0).unwrap(), 6).unwrap();
+        assert_eq!(n.mean(), 12.0);
+        assert_almost_eq!(n.variance(), 2.0, 1e-15);
+        assert_almost_eq!(n.std_dev(), 2f64.sqrt(), 1e-15);
+        assert_eq!(n.min(), 6.0);
+        assert_eq!(n.max(), 18.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        let mut r = rng::seeded(0);
+        let e = Exponential::new(2.0).unwrap();
+        for &n in &[1u64, 5, 40] {
+            let d = SumOfIid::new(e, n).unwrap();
+            let samples: Vec<f64> = (0..20000).map(|_| d.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            // both scale linearly with the number of summands
+            assert_almost_eq!(samples.iter().mean() / (n as f64 * e.mean()), 1.0, 0.03);
+            assert_almost_eq!(samples.iter().variance() / (n as f64 * e.variance()), 1.0, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_cdf_normal_summands() {
+        // a sum of normals is normal and the approximation is exact
+        let n = SumOfIid::new(Normal::new(1.0, 2.0).unwrap(), 4).unwrap();
+        let z = Normal::new(4.0, 4.0).unwrap();
+        for &x in &[-5.0f64, 0.0, 4.0, 6.5, 15.0] {
+            assert_almost_eq!(n.cdf(x), z.cdf(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_cdf_approaches_exact() {
+        // the sum of n unit exponentials is a gamma with shape n, and the
+        // largest error of the normal approximation shrinks like 1 / sqrt(n)
+        let e = Exponential::new(1.0).unwrap();
+        let mut last = f64::INFINITY;
+        for &n in &[1u64, 10, 100, 1000] {
+            let d = SumOfIid::new(e, n).unwrap();
+            let g = Gamma::new(n as f64, 1.0).unwrap();
+            let error = (0..=200).map(|i| {
+                let x = d.mean() + d.std_dev() * (i as f64 / 20.0 - 5.0);
+                (d.cdf(x) - g.cdf(x.max(0.0))).abs()
+            }).fold(0.0, f64::max);
+            assert!(error < last / 2.0);
+            assert!(error * (n as f64).sqrt() < 0.2);
+            last = error;
+        }
+        assert!(last < 0.005);
+    }
+
+    #[test]
+    fn test_cdf_limits() {
+        let n = SumOfIid::new(Uniform::new(0.0, 1.0).unwrap(), 12).unwrap();
+        assert_eq!(n.cdf(6.0), 0.5);
+        assert_eq!(n.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_cdf_degenerate() {
+        // every summand is the constant 1, so the sum is a step at 5
+        let n = SumOfIid::new(Binomial::new(1.0, 1).unwrap(), 5).unwrap();
+        assert_eq!(n.std_dev(), 0.0);
+        assert_eq!(n.cdf(4.999), 0.0);
+        assert_eq!(n.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(n.cdf(5.0), 1.0);
+        assert_eq!(n.cdf(7.5), 1.0);
+    }
+
+    #[test]
+    fn test_cdf_non_finite_moments() {
+        // the variance of a pareto distribution with shape <= 2 is infinite
+        let n = SumOfIid::new(Pareto::new(1.0, 1.5).unwrap(), 10).unwrap();
+        assert!(n.cdf(20.0).is_nan());
+        // and its mean too with shape <= 1
+        let n = SumOfIid::new(Pareto::new(1.0, 0.5).unwrap(), 10).unwrap();
+        assert!(n.cdf(20.0).is_nan());
+    }
+}
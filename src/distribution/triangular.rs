@@ -1,4 +1,7 @@
-use distribution::{Continuous, Univariate};
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Moments, Parametric, Support,
+    Univariate,
+};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -67,6 +70,21 @@ impl Triangular {
     }
 }
 
+impl Parametric for Triangular {
+    /// Returns the `min`, `max` and `mode` parameters of the
+    /// triangular distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("min", self.min), ("max", self.max), ("mode", self.mode)]
+    }
+
+    /// Constructs a triangular distribution from its `min`, `max` and `mode`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Triangular> {
+        let p = internal::parameter_values(params, &["min", "max", "mode"])?;
+        Triangular::new(p[0], p[1], p[2])
+    }
+}
+
 impl Distribution<f64> for Triangular {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         sample_unchecked(r, self.min, self.max, self.mode)
@@ -107,6 +125,54 @@ impl Univariate<f64, f64> for Triangular {
     }
 }
 
+impl InverseCDF<f64> for Triangular {
+    /// Calculates the inverse cumulative distribution function for the
+    /// triangular distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x <= (mode - min) / (max - min) {
+    ///     min + sqrt(x * (max - min) * (mode - min))
+    /// } else {
+    ///     max - sqrt((1 - x) * (max - min) * (max - mode))
+    /// }
+    /// ```
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Triangular {
+    /// Calculates the inverse cumulative distribution function for the
+    /// triangular distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x <= (mode - min) / (max - min) {
+    ///     min + sqrt(x * (max - min) * (mode - min))
+    /// } else {
+    ///     max - sqrt((1 - x) * (max - min) * (max - mode))
+    /// }
+    /// ```
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else {
+            Ok(inverse_cdf_unchecked(x, self.min, self.max, self.mode))
+        }
+    }
+}
+
 impl Min<f64> for Triangular {
     /// Returns the minimum value in the domain of the
     /// triangular distribution representable by a double precision float
@@ -131,6 +197,24 @@ impl Max<f64> for Triangular {
     }
 }
 
+impl Domain for Triangular {
+    /// Returns the support of the triangular distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [min, max]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.min,
+            hi: self.max,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
 impl Mean<f64> for Triangular {
     /// Returns the mean of the triangular distribution
     ///
@@ -172,6 +256,8 @@ impl Variance<f64> for Triangular {
     }
 }
 
+impl Dispersion for Triangular {}
+
 impl Entropy<f64> for Triangular {
     /// Returns the entropy of the triangular distribution
     ///
@@ -296,12 +382,19 @@ impl Continuous<f64, f64> for Triangular {
     }
 }
 
+impl Moments for Triangular {}
+
 fn sample_unchecked<R: Rng + ?Sized>(r: &mut R, min: f64, max: f64, mode: f64) -> f64 {
-    let f: f64 = r.gen();
-    if f < (mode - min) / (max - min) {
-        min + (f * (max - min) * (mode - min)).sqrt()
+    inverse_cdf_unchecked(r.gen(), min, max, mode)
+}
+
+/// Inverts the two quadratic pieces of the cdf, which both evaluate to
+/// `mode` at the split `(mode - min) / (max - min)`
+fn inverse_cdf_unchecked(x: f64, min: f64, max: f64, mode: f64) -> f64 {
+    if x <= (mode - min) / (max - min) {
+        min + (x * (max - min) * (mode - min)).sqrt()
     } else {
-        max - ((1.0 - f) * (max - min) * (max - mode)).sqrt()
+        max - ((1.0 - x) * (max - min) * (max - mode)).sqrt()
     }
 }
 
@@ -311,8 +404,10 @@ mod test {
     use std::fmt::Debug;
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Triangular};
+    use rand::distributions::Distribution;
+    use distribution::{CheckedInverseCDF, InverseCDF, Univariate, Continuous, Triangular};
     use distribution::internal::*;
+    use rng;
 
     fn try_create(min: f64, max: f64, mode: f64) -> Triangular {
         let n = Triangular::new(min, max, mode);
@@ -501,6 +596,41 @@ mod test {
         test_case(0.0, 3.0, 1.5, 1.0, |x| x.cdf(5.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        for &(a, b, c) in &[(0.0f64, 1.0f64, 0.5f64), (-5.0, 8.0, -3.5), (0.0, 2.0, 0.0), (0.0, 2.0, 2.0)] {
+            let n = try_create(a, b, c);
+            for i in 0..=40 {
+                let x = a + (b - a) * i as f64 / 40.0;
+                assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+            }
+            assert_eq!(n.inverse_cdf(0.0), a);
+            assert_eq!(n.inverse_cdf(1.0), b);
+            // both pieces meet at the mode
+            assert_almost_eq!(n.inverse_cdf((c - a) / (b - a)), c, 1e-15);
+            assert!(n.checked_inverse_cdf(-0.1).is_err());
+            assert!(n.checked_inverse_cdf(1.1).is_err());
+        }
+        test_almost(0.0, 1.0, 0.5, 0.5f64.sqrt() / 2.0, 1e-15, |x| x.inverse_cdf(0.25));
+        test_almost(0.0, 1.0, 0.5, 1.0 - 0.5f64.sqrt() / 2.0, 1e-15, |x| x.inverse_cdf(0.75));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = rng::seeded(0);
+        let n = try_create(-2.0, 6.0, 0.0);
+        let samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= -2.0 && x <= 6.0));
+        assert_almost_eq!(samples.iter().mean(), n.mean(), 0.05);
+        // the unit on either side of the mode holds twenty times the mass of
+        // the last unit below the maximum
+        let near_mode = samples.iter().filter(|&&x| x.abs() <= 1.0).count() as f64;
+        let near_max = samples.iter().filter(|&&x| x >= 5.0).count() as f64;
+        assert_almost_eq!(near_mode / 20000.0, n.cdf(1.0) - n.cdf(-1.0), 0.01);
+        assert_almost_eq!(near_max / 20000.0, 1.0 - n.cdf(5.0), 0.005);
+        assert!(near_mode > 10.0 * near_max);
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(-5.0, 5.0, 0.0), -5.0, 5.0);
@@ -0,0 +1,609 @@
+use distribution::{
+    internal, CheckedInverseCDF, Continuous, Domain, InverseCDF, Parametric, Support, Univariate,
+};
+use function::{gamma, logistic, optimization};
+use rand::distributions::{Distribution, Open01};
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the [Tukey
+/// lambda](https://en.wikipedia.org/wiki/Tukey_lambda_distribution)
+/// distribution
+///
+/// # Remarks
+///
+/// The distribution is defined through its quantile function. Depending on
+/// the shape `λ` it approximates the Cauchy distribution (`λ = -1`), the
+/// logistic distribution (`λ = 0`), the normal distribution (`λ ≈ 0.14`) and
+/// the uniform distribution (`λ = 1` and `λ = 2`). The cdf has no closed
+/// form and is computed by numerically inverting the quantile function.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, InverseCDF, TukeyLambda};
+///
+/// let n = TukeyLambda::new(1.0).unwrap();
+/// assert_eq!(n.inverse_cdf(0.75), 0.5);
+/// assert_eq!(n.pdf(0.3), 0.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TukeyLambda {
+    lambda: f64,
+}
+
+impl TukeyLambda {
+    /// Constructs a new Tukey lambda distribution with a shape of `lambda`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lambda` is `NaN` or infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TukeyLambda;
+    ///
+    /// let mut result = TukeyLambda::new(0.14);
+    /// assert!(result.is_ok());
+    ///
+    /// result = TukeyLambda::new(f64::NAN);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(lambda: f64) -> Result<TukeyLambda> {
+        if !lambda.is_finite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(TukeyLambda { lambda: lambda })
+        }
+    }
+
+    /// Returns the shape of the Tukey lambda distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TukeyLambda;
+    ///
+    /// let n = TukeyLambda::new(0.14).unwrap();
+    /// assert_eq!(n.lambda(), 0.14);
+    /// ```
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    // the quantile function, using exp_m1 to stay accurate for small lambda
+    fn quantile(&self, p: f64) -> f64 {
+        if self.lambda == 0.0 {
+            (p / (1.0 - p)).ln()
+        } else {
+            ((self.lambda * p.ln()).exp_m1() - (self.lambda * (1.0 - p).ln()).exp_m1())
+                / self.lambda
+        }
+    }
+
+    // the derivative of the quantile function
+    fn quantile_density(&self, p: f64) -> f64 {
+        p.powf(self.lambda - 1.0) + (1.0 - p).powf(self.lambda - 1.0)
+    }
+}
+
+impl Parametric for TukeyLambda {
+    /// Returns the `lambda` parameter of the Tukey lambda distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("lambda", self.lambda)]
+    }
+
+    /// Constructs a Tukey lambda distribution from its `lambda` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<TukeyLambda> {
+        let p = internal::parameter_values(params, &["lambda"])?;
+        TukeyLambda::new(p[0])
+    }
+}
+
+impl Distribution<f64> for TukeyLambda {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.quantile(r.sample(Open01))
+    }
+}
+
+impl Univariate<f64, f64> for TukeyLambda {
+    /// Calculates the cumulative distribution function for the Tukey lambda
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// For `λ != 0` the cdf is computed by finding the root of
+    /// `Q(p) - x` with Brent's method and is accurate to a few ulps of the
+    /// smaller of `F(x)` and `1 - F(x)`. For `λ > 1` the quantile function
+    /// is flat near the bounds of the support, where the cdf only has an
+    /// absolute accuracy of a few ulps of `1 / λ`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q^-1(x)
+    /// ```
+    ///
+    /// where `Q` is the quantile function and `Q^-1(x) = 1 / (1 + e^-x)` for
+    /// `λ = 0`
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else if self.lambda == 0.0 {
+            logistic::logistic(x)
+        } else if x > 0.0 {
+            // invert the lower tail, where p has the most precision
+            1.0 - self.cdf(-x)
+        } else if x == 0.0 {
+            0.5
+        } else {
+            // shrink the bracket [lower, upper] towards zero until it
+            // contains the root, keeping the quantiles finite
+            let mut upper = 0.5;
+            let mut lower = 0.25;
+            while self.quantile(lower) > x {
+                upper = lower;
+                lower /= 2.0;
+                if lower == 0.0 {
+                    return 0.0;
+                }
+            }
+            optimization::find_root(|p| self.quantile(p) - x, lower, upper, 0.0)
+        }
+    }
+}
+
+impl InverseCDF<f64> for TukeyLambda {
+    /// Calculates the inverse cumulative distribution function for the
+    /// Tukey lambda distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (x^λ - (1 - x)^λ) / λ     if λ != 0
+    /// ln(x / (1 - x))           if λ == 0
+    /// ```
+    ///
+    /// where `λ` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for TukeyLambda {
+    /// Calculates the inverse cumulative distribution function for the
+    /// Tukey lambda distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (x^λ - (1 - x)^λ) / λ     if λ != 0
+    /// ln(x / (1 - x))           if λ == 0
+    /// ```
+    ///
+    /// where `λ` is the shape
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+        } else {
+            Ok(self.quantile(x))
+        }
+    }
+}
+
+impl Min<f64> for TukeyLambda {
+    /// Returns the minimum value in the domain of the Tukey lambda
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -1 / λ    if λ > 0
+    /// -INF      otherwise
+    /// ```
+    fn min(&self) -> f64 {
+        if self.lambda > 0.0 {
+            -1.0 / self.lambda
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for TukeyLambda {
+    /// Returns the maximum value in the domain of the Tukey lambda
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / λ    if λ > 0
+    /// INF      otherwise
+    /// ```
+    fn max(&self) -> f64 {
+        if self.lambda > 0.0 {
+            1.0 / self.lambda
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Domain for TukeyLambda {
+    /// Returns the support of the Tukey lambda distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [-1 / λ, 1 / λ]    if λ > 0
+    /// (-INF, INF)        otherwise
+    /// ```
+    fn support(&self) -> Support {
+        let bounded = self.lambda > 0.0;
+        Support::Continuous {
+            lo: self.min(),
+            hi: self.max(),
+            lo_closed: bounded,
+            hi_closed: bounded,
+        }
+    }
+}
+
+impl Mean<f64> for TukeyLambda {
+    /// Returns the mean of the Tukey lambda distribution
+    ///
+    /// # Panics
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mean(&self) -> f64 {
+        self.checked_mean().unwrap()
+    }
+}
+
+impl CheckedMean<f64> for TukeyLambda {
+    /// Returns the mean of the Tukey lambda distribution
+    ///
+    /// # Errors
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn checked_mean(&self) -> Result<f64> {
+        if self.lambda <= -1.0 {
+            Err(StatsError::ArgGt("lambda", -1.0))
+        } else {
+            Ok(0.0)
+        }
+    }
+}
+
+impl Variance<f64> for TukeyLambda {
+    /// Returns the variance of the Tukey lambda distribution
+    ///
+    /// # Panics
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if λ == 0 {
+    ///     π^2 / 3
+    /// } else if λ > -1 / 2 {
+    ///     (2 / λ^2) * (1 / (1 + 2λ) - Γ(λ + 1)^2 / Γ(2λ + 2))
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `λ` is the shape and `Γ` is the gamma function
+    fn variance(&self) -> f64 {
+        self.checked_variance().unwrap()
+    }
+
+    /// Returns the standard deviation of the Tukey lambda distribution
+    ///
+    /// # Panics
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(variance)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.checked_std_dev().unwrap()
+    }
+}
+
+impl Dispersion for TukeyLambda {
+    /// Returns the coefficient of variation of the distribution, or `NaN`
+    /// if its mean is zero or its mean or variance is undefined
+    fn coefficient_of_variation(&self) -> f64 {
+        match (self.checked_mean(), self.checked_std_dev()) {
+            (Ok(mean), Ok(std_dev)) if mean != 0.0 && mean.is_finite() => std_dev / mean,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Returns the index of dispersion of the distribution, or `NaN` if its
+    /// mean is zero or its mean or variance is undefined
+    fn index_of_dispersion(&self) -> f64 {
+        match (self.checked_mean(), self.checked_variance()) {
+            (Ok(mean), Ok(variance)) if mean != 0.0 && mean.is_finite() => variance / mean,
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl CheckedVariance<f64> for TukeyLambda {
+    /// Returns the variance of the Tukey lambda distribution
+    ///
+    /// # Remarks
+    ///
+    /// The closed form cancels catastrophically for small `|λ|`, where its
+    /// Taylor series about `λ = 0` is used instead
+    ///
+    /// # Errors
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if λ == 0 {
+    ///     π^2 / 3
+    /// } else if λ > -1 / 2 {
+    ///     (2 / λ^2) * (1 / (1 + 2λ) - Γ(λ + 1)^2 / Γ(2λ + 2))
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `λ` is the shape and `Γ` is the gamma function
+    fn checked_variance(&self) -> Result<f64> {
+        let l = self.lambda;
+        if l <= -1.0 {
+            Err(StatsError::ArgGt("lambda", -1.0))
+        } else if l <= -0.5 {
+            Ok(f64::INFINITY)
+        } else if l.abs() < 1e-2 {
+            Ok(VARIANCE_SERIES
+                .iter()
+                .rev()
+                .fold(0.0, |acc, &c| acc * l + c))
+        } else {
+            let ln_ratio = 2.0 * gamma::ln_gamma(l + 1.0) - gamma::ln_gamma(2.0 * l + 2.0);
+            Ok(2.0 / (l * l) * (1.0 / (1.0 + 2.0 * l) - ln_ratio.exp()))
+        }
+    }
+
+    /// Returns the standard deviation of the Tukey lambda distribution
+    ///
+    /// # Errors
+    ///
+    /// If `λ <= -1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(variance)
+    /// ```
+    fn checked_std_dev(&self) -> Result<f64> {
+        self.checked_variance().map(|x| x.sqrt())
+    }
+}
+
+// Taylor coefficients of the variance about `λ = 0`
+const VARIANCE_SERIES: [f64; 7] = [
+    3.289868133696453,
+    -11.387963880031283,
+    27.646382311762686,
+    -59.82668028405663,
+    123.91986011147516,
+    -251.96303370635772,
+    507.98261521905386,
+];
+
+impl Median<f64> for TukeyLambda {
+    /// Returns the median of the Tukey lambda distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn median(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Continuous<f64, f64> for TukeyLambda {
+    /// Calculates the probability density function for the Tukey lambda
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / q(F(x))
+    /// ```
+    ///
+    /// where `F` is the cdf and `q(p) = p^(λ - 1) + (1 - p)^(λ - 1)` is the
+    /// derivative of the quantile function
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            0.0
+        } else {
+            1.0 / self.quantile_density(self.cdf(x))
+        }
+    }
+
+    /// Calculates the log probability density function for the Tukey lambda
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(q(F(x)))
+    /// ```
+    ///
+    /// where `F` is the cdf and `q(p) = p^(λ - 1) + (1 - p)^(λ - 1)` is the
+    /// derivative of the quantile function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use statistics::*;
+    use distribution::{CheckedInverseCDF, Continuous, Domain, InverseCDF, Support, TukeyLambda, Univariate};
+    use distribution::internal::*;
+
+    fn try_create(lambda: f64) -> TukeyLambda {
+        let n = TukeyLambda::new(lambda);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        for &lambda in &[-2.0, -1.0, 0.0, 1e-300, 0.14, 1.0, 5.0] {
+            try_create(lambda);
+        }
+        assert!(TukeyLambda::new(f64::NAN).is_err());
+        assert!(TukeyLambda::new(f64::INFINITY).is_err());
+        assert!(TukeyLambda::new(f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_logistic_quantiles() {
+        let n = try_create(0.0);
+        for &p in &[1e-10f64, 0.01, 0.25, 0.5, 0.8, 0.999] {
+            assert_eq!(n.inverse_cdf(p), (p / (1.0 - p)).ln());
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-15);
+            // the quantiles are continuous in lambda
+            assert_almost_eq!(try_create(1e-12).inverse_cdf(p), (p / (1.0 - p)).ln(), 1e-9);
+        }
+        assert_eq!(n.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        assert_almost_eq!(n.pdf(0.0), 0.25, 1e-15);
+        assert_almost_eq!(n.pdf(2.0), (-2f64).exp() / (1.0 + (-2f64).exp()).powi(2), 1e-15);
+    }
+
+    #[test]
+    fn test_uniform() {
+        let n = try_create(1.0);
+        assert_eq!(n.min(), -1.0);
+        assert_eq!(n.max(), 1.0);
+        for &x in &[-0.9f64, -0.5, 0.0, 0.3, 0.75] {
+            assert_almost_eq!(n.cdf(x), (x + 1.0) / 2.0, 1e-15);
+            assert_almost_eq!(n.pdf(x), 0.5, 1e-15);
+            assert_almost_eq!(n.inverse_cdf((x + 1.0) / 2.0), x, 1e-15);
+        }
+        let n = try_create(2.0);
+        assert_almost_eq!(n.inverse_cdf(0.9), 0.4, 1e-15);
+        assert_almost_eq!(n.cdf(0.4), 0.9, 1e-15);
+        assert_almost_eq!(n.pdf(-0.2), 1.0, 1e-15);
+        assert_eq!(n.pdf(-0.6), 0.0);
+        assert_eq!(n.cdf(-0.6), 0.0);
+        assert_eq!(n.cdf(0.6), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        for &lambda in &[-1.0, -0.5, -0.1, 0.0, 0.14, 0.5, 1.5, 3.0] {
+            let n = try_create(lambda);
+            for &p in &[1e-8f64, 1e-3, 0.1, 0.3, 0.5, 0.7, 0.95, 0.9999] {
+                let x = n.inverse_cdf(p);
+                assert_almost_eq!(n.cdf(x), p, 1e-14);
+                // for lambda > 1 the quantile function is flat near the bounds,
+                // so x can only resolve p to an absolute precision
+                if p <= 0.5 && lambda <= 1.0 {
+                    assert!(((n.cdf(x) - p) / p).abs() < 1e-12);
+                }
+            }
+            assert_eq!(n.inverse_cdf(0.5), 0.0);
+            assert_eq!(n.cdf(0.0), 0.5);
+        }
+    }
+
+    #[test]
+    fn test_checked_inverse_cdf() {
+        let n = try_create(0.5);
+        assert_eq!(n.checked_inverse_cdf(0.0).unwrap(), -2.0);
+        assert_eq!(n.checked_inverse_cdf(1.0).unwrap(), 2.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_input_high() {
+        try_create(0.5).inverse_cdf(1.5);
+    }
+
+    #[test]
+    fn test_moments() {
+        assert_eq!(try_create(0.5).mean(), 0.0);
+        assert_eq!(try_create(-0.9).mean(), 0.0);
+        assert!(try_create(-1.0).checked_mean().is_err());
+        assert!(try_create(-1.5).checked_variance().is_err());
+        assert_eq!(try_create(-0.5).variance(), f64::INFINITY);
+        assert_eq!(try_create(-0.75).variance(), f64::INFINITY);
+        assert_almost_eq!(try_create(0.0).variance(), f64::consts::PI * f64::consts::PI / 3.0, 1e-15);
+        assert_almost_eq!(try_create(1e-3).variance(), 3.2785077564957215, 1e-14);
+        assert_almost_eq!(try_create(-1e-3).variance(), 3.3012838039096484, 1e-14);
+        assert_almost_eq!(try_create(0.14).variance(), 2.110297022214484, 1e-12);
+        assert_almost_eq!(try_create(-0.3).variance(), 13.354712585021803, 1e-12);
+        assert_almost_eq!(try_create(0.5).variance(), 0.8584073464102068, 1e-14);
+        assert_almost_eq!(try_create(1.0).variance(), 1.0 / 3.0, 1e-14);
+        assert_almost_eq!(try_create(2.0).std_dev(), (1.0f64 / 12.0).sqrt(), 1e-14);
+        // the series and the closed form agree where they meet
+        assert_almost_eq!(try_create(0.00999999).variance(), try_create(0.01).variance(), 1e-6);
+        assert_eq!(try_create(3.0).median(), 0.0);
+    }
+
+    #[test]
+    fn test_support() {
+        assert_eq!(try_create(0.5).support(), Support::Continuous { lo: -2.0, hi: 2.0, lo_closed: true, hi_closed: true });
+        assert_eq!(try_create(-0.5).support(), Support::Continuous {
+            lo: f64::NEG_INFINITY, hi: f64::INFINITY, lo_closed: false, hi_closed: false
+        });
+        assert_eq!(try_create(0.0).min(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(0.5), -2.0, 2.0);
+        test::check_continuous_distribution(&try_create(1.5), -1.0 / 1.5, 1.0 / 1.5);
+        test::check_continuous_distribution(&try_create(0.14), -10.0, 10.0);
+        test::check_continuous_distribution(&try_create(0.0), -50.0, 50.0);
+    }
+}
@@ -1,4 +1,6 @@
-use distribution::{Continuous, Univariate};
+use distribution::{
+    internal, Continuous, Domain, LocationScale, Moments, Parametric, Support, Univariate,
+};
 use rand::distributions::Distribution;
 use rand::distributions::Uniform as RandUniform;
 use rand::Rng;
@@ -55,6 +57,19 @@ impl Uniform {
     }
 }
 
+impl Parametric for Uniform {
+    /// Returns the `min` and `max` parameters of the uniform distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("min", self.min), ("max", self.max)]
+    }
+
+    /// Constructs an uniform distribution from its `min` and `max` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Uniform> {
+        let p = internal::parameter_values(params, &["min", "max"])?;
+        Uniform::new(p[0], p[1])
+    }
+}
+
 impl Distribution<f64> for Uniform {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
         let d = RandUniform::new_inclusive(self.min, self.max);
@@ -62,6 +77,24 @@ impl Distribution<f64> for Uniform {
     }
 }
 
+impl LocationScale for Uniform {
+    /// Returns the uniform distribution of `loc + scale * X`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// U(a + b * min, a + b * max)
+    /// ```
+    ///
+    /// where `a` is `loc` and `b` is `scale`
+    fn transform(&self, loc: f64, scale: f64) -> Result<Uniform> {
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Uniform::new(loc + scale * self.min, loc + scale * self.max)
+    }
+}
+
 impl Univariate<f64, f64> for Uniform {
     /// Calculates the cumulative distribution function for the uniform
     /// distribution
@@ -95,6 +128,24 @@ impl Max<f64> for Uniform {
     }
 }
 
+impl Domain for Uniform {
+    /// Returns the support of the uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [min, max]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: self.min,
+            hi: self.max,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
 impl Mean<f64> for Uniform {
     /// Returns the mean for the continuous uniform distribution
     ///
@@ -132,6 +183,8 @@ impl Variance<f64> for Uniform {
     }
 }
 
+impl Dispersion for Uniform {}
+
 impl Entropy<f64> for Uniform {
     /// Returns the entropy for the continuous uniform distribution
     ///
@@ -189,6 +242,24 @@ impl Mode<f64> for Uniform {
     }
 }
 
+impl CheckedMode<f64> for Uniform {
+    /// Returns the mode for the continuous uniform distribution
+    ///
+    /// # Remarks
+    ///
+    /// Every element of `[min, max]` is a mode. Unlike `mode`, which returns
+    /// the middle element, this does not pick one
+    ///
+    /// # Errors
+    ///
+    /// Always, since the continuous uniform distribution has no unique mode
+    fn checked_mode(&self) -> Result<f64> {
+        Err(StatsError::SpecialCase(
+            "Continuous uniform distribution has no unique mode",
+        ))
+    }
+}
+
 impl Continuous<f64, f64> for Uniform {
     /// Calculates the probability density function for the continuous uniform
     /// distribution at `x`
@@ -232,12 +303,43 @@ impl Continuous<f64, f64> for Uniform {
     }
 }
 
+impl Moments for Uniform {
+    /// Returns the `k`-th raw moment of the uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max^(k + 1) - min^(k + 1)) / ((k + 1) * (max - min))
+    /// ```
+    fn raw_moment(&self, k: u32) -> f64 {
+        let n = k as i32 + 1;
+        (self.max.powi(n) - self.min.powi(n)) / (f64::from(n) * (self.max - self.min))
+    }
+
+    /// Returns the `k`-th central moment of the uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ((max - min) / 2)^k / (k + 1)
+    /// ```
+    ///
+    /// for even `k` and `0` for odd `k`
+    fn central_moment(&self, k: u32) -> f64 {
+        if k % 2 == 1 {
+            0.0
+        } else {
+            ((self.max - self.min) / 2.0).powi(k as i32) / f64::from(k + 1)
+        }
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Uniform};
+    use distribution::{Univariate, Continuous, Moments, Uniform, LocationScale};
     use distribution::internal::*;
 
     fn try_create(min: f64, max: f64) -> Uniform {
@@ -455,4 +557,31 @@ mod test {
             .all(|v| (min <= v) && (v < max))
         );
     }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(-1.0, 3.0);
+        assert_almost_eq!(n.central_moment(2), n.variance(), 1e-15);
+        assert_almost_eq!(n.raw_moment(1), n.mean(), 1e-15);
+        assert_almost_eq!(n.raw_moment(2), 7.0 / 3.0, 1e-15);
+        assert_eq!(n.central_moment(3), 0.0);
+    }
+
+    #[test]
+    fn test_checked_mode() {
+        assert!(try_create(0.0, 1.0).checked_mode().is_err());
+        assert!(try_create(-5.0, 5.0).checked_mode().is_err());
+    }
+
+    #[test]
+    fn test_location_scale() {
+        let n = try_create(1.0, 3.0);
+        let t = n.transform(-1.0, 2.0).unwrap();
+        assert_eq!(t.min(), 1.0);
+        assert_eq!(t.max(), 5.0);
+        assert_eq!(t.mean(), -1.0 + 2.0 * n.mean());
+        assert_eq!(t.std_dev(), 2.0 * n.std_dev());
+        assert_eq!(n.transform(0.0, 1.0).unwrap(), n);
+        assert!(n.transform(0.0, 0.0).is_err());
+    }
 }
@@ -1,6 +1,6 @@
 use std::f64;
 
-use distribution::Univariate;
+use distribution::{internal, Domain, Parametric, Support, Univariate};
 use rgsl::{bessel, Value};
 use statistics::{Max, Min};
 use {Result, StatsError};
@@ -9,6 +9,7 @@ use {Result, StatsError};
 pub struct VonMises {
     location: f64,
     concentration: f64,
+    i0_concentration: f64,
 }
 
 impl VonMises {
@@ -38,11 +39,30 @@ impl VonMises {
             Ok(VonMises {
                 location,
                 concentration,
+                i0_concentration: bessel::I0(concentration),
             })
         }
     }
 }
 
+impl Parametric for VonMises {
+    /// Returns the `location` and `concentration` parameters of the
+    /// von Mises distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("location", self.location),
+            ("concentration", self.concentration),
+        ]
+    }
+
+    /// Constructs a von Mises distribution from its `location` and
+    /// `concentration` parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<VonMises> {
+        let p = internal::parameter_values(params, &["location", "concentration"])?;
+        VonMises::new(p[0], p[1])
+    }
+}
+
 impl Min<f64> for VonMises {
     /// Returns the minimum value in the domain of the
     /// von Mises distribution representable by a double precision float
@@ -71,20 +91,63 @@ impl Max<f64> for VonMises {
     }
 }
 
-impl Univariate<f64, f64> for VonMises {
-    fn cdf(&self, x: f64) -> f64 {
-        let d = x - self.location;
+impl Domain for VonMises {
+    /// Returns the support of the von mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [-π, π]
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: -f64::consts::PI,
+            hi: f64::consts::PI,
+            lo_closed: true,
+            hi_closed: true,
+        }
+    }
+}
+
+impl VonMises {
+    /// Returns the modified Bessel functions of the first kind of orders
+    /// `1` to `100` evaluated at the concentration, which are the
+    /// coefficients of the series for the cdf
+    fn bessel_coefficients(&self) -> [f64; 100] {
         let mut results: [f64; 100] = [0.0; 100];
         match bessel::In_array(1, 100, self.concentration, &mut results) {
             Value::Success => {}
             other => panic!(other),
         };
-        let sum: f64 = results
-            .into_iter()
+        results
+    }
+
+    /// Calculates the cumulative distribution function at `x` from the
+    /// precomputed Bessel coefficients
+    fn cdf_with_coefficients(&self, coefficients: &[f64; 100], x: f64) -> f64 {
+        let d = x - self.location;
+        let sum: f64 = coefficients
+            .iter()
             .enumerate()
             .map(|(j, i_j)| i_j * ((j + 1) as f64 * d).sin() / (j + 1) as f64)
             .sum();
-        0.5 + (d + (2.0 * sum / bessel::I0(self.concentration))) / (2.0 * f64::consts::PI)
+        0.5 + (d + (2.0 * sum / self.i0_concentration)) / (2.0 * f64::consts::PI)
+    }
+}
+
+impl Univariate<f64, f64> for VonMises {
+    fn cdf(&self, x: f64) -> f64 {
+        self.cdf_with_coefficients(&self.bessel_coefficients(), x)
+    }
+
+    /// Calculates the cumulative distribution function at each of `xs`,
+    /// computing the Bessel coefficients of the series once for the whole
+    /// grid rather than once per point
+    fn cdf_grid(&self, xs: &[f64]) -> Vec<f64> {
+        let coefficients = self.bessel_coefficients();
+        xs.iter()
+            .map(|&x| self.cdf_with_coefficients(&coefficients, x))
+            .collect()
     }
 }
 
@@ -127,4 +190,57 @@ mod test {
         assert_almost_eq!(vm.cdf(2.0), 0.7943553074346887, 0.000001);
         assert_almost_eq!(vm.cdf(3.0), 0.9342409558899928, 0.000001);
     }
+
+    #[test]
+    fn test_cdf_matches_uncached_normalization() {
+        // the cached normalization gives the same cdf as evaluating
+        // I0(concentration) afresh on every call
+        for &concentration in &[0.1, 0.5, 1.0, 2.5, 4.0, 10.0, 50.0] {
+            let vm = VonMises::new(0.5, concentration).unwrap();
+            let mut coefficients: [f64; 100] = [0.0; 100];
+            match bessel::In_array(1, 100, concentration, &mut coefficients) {
+                Value::Success => {}
+                other => panic!(other),
+            };
+            for &x in &[-3.0, -1.5, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0] {
+                let d: f64 = x - 0.5;
+                let sum: f64 = coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(j, i_j)| i_j * ((j + 1) as f64 * d).sin() / (j + 1) as f64)
+                    .sum();
+                let expected =
+                    0.5 + (d + (2.0 * sum / bessel::I0(concentration))) / (2.0 * f64::consts::PI);
+                assert_eq!(vm.cdf(x), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parameters() {
+        let vm = VonMises::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            vm.parameters(),
+            vec![("location", 0.0), ("concentration", 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_from_parameters() {
+        let vm = VonMises::new(0.5, 2.0).unwrap();
+        assert_eq!(VonMises::from_parameters(&vm.parameters()).unwrap(), vm);
+        assert!(VonMises::from_parameters(&[("location", 0.5), ("kappa", 2.0)]).is_err());
+        assert!(VonMises::from_parameters(&[("location", 0.5), ("concentration", 0.0)]).is_err());
+    }
+
+    #[test]
+    fn test_cdf_grid() {
+        let vm = VonMises::new(0.5, 2.0).unwrap();
+        let xs = [-3.0, -1.0, 0.0, 0.5, 2.0, 1.0, 3.0];
+        let grid = vm.cdf_grid(&xs);
+        assert_eq!(grid.len(), xs.len());
+        for (&x, &c) in xs.iter().zip(grid.iter()) {
+            assert_eq!(c, vm.cdf(x));
+        }
+    }
 }
@@ -1,5 +1,5 @@
-use distribution::{Continuous, Univariate};
-use function::gamma;
+use distribution::{internal, Continuous, Domain, Moments, Parametric, Support, Univariate};
+use function::{gamma, integration};
 use rand::distributions::Distribution;
 use rand::Rng;
 use statistics::*;
@@ -28,6 +28,27 @@ pub struct Weibull {
     scale_pow_shape_inv: f64,
 }
 
+/// The shape of the hazard function of a distribution over time, which
+/// for the weibull distribution is determined by its shape parameter
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HazardShape, Weibull};
+///
+/// let n = Weibull::new(0.5, 1.0).unwrap();
+/// assert_eq!(n.hazard_shape(), HazardShape::Decreasing);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HazardShape {
+    /// The failure rate falls over time, as from early failures
+    Decreasing,
+    /// The failure rate is constant, as from random failures
+    Constant,
+    /// The failure rate rises over time, as from wear-out failures
+    Increasing,
+}
+
 impl Weibull {
     /// Constructs a new weibull distribution with a shape (k) of `shape`
     /// and a scale (λ) of `scale`
@@ -88,6 +109,179 @@ impl Weibull {
     pub fn scale(&self) -> f64 {
         self.scale
     }
+
+    /// Returns the shape of the hazard function of the weibull
+    /// distribution, which is decreasing if `shape < 1`, constant if
+    /// `shape == 1` and increasing if `shape > 1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{HazardShape, Weibull};
+    ///
+    /// let n = Weibull::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.hazard_shape(), HazardShape::Constant);
+    /// ```
+    pub fn hazard_shape(&self) -> HazardShape {
+        if self.shape < 1.0 {
+            HazardShape::Decreasing
+        } else if self.shape > 1.0 {
+            HazardShape::Increasing
+        } else {
+            HazardShape::Constant
+        }
+    }
+
+    /// Returns the mean residual life of the weibull distribution at `t`,
+    /// i.e. the expected remaining lifetime `E[X - t | X > t]` of an item
+    /// that has survived to time `t`
+    ///
+    /// # Remarks
+    ///
+    /// Integrates the survival function from `t` numerically. For
+    /// `t <= 0` every item survives to `t`, so the result is `mean - t`.
+    /// The survival function is integrated relative to its value at `t`,
+    /// so the result remains finite far in the tail where the survival
+    /// probability itself underflows. Returns `NaN` if `t` is `NaN`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫_t^∞ e^((t/λ)^k - (x/λ)^k) dx
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::distribution::Weibull;
+    ///
+    /// # fn main() {
+    /// // the exponential distribution is memoryless
+    /// let n = Weibull::new(1.0, 2.0).unwrap();
+    /// assert_almost_eq!(n.mean_residual_life(5.0), 2.0, 1e-8);
+    /// # }
+    /// ```
+    pub fn mean_residual_life(&self, t: f64) -> f64 {
+        if t.is_nan() {
+            f64::NAN
+        } else if t <= 0.0 {
+            self.mean() - t
+        } else {
+            let ln_sf_t = t.powf(self.shape) * self.scale_pow_shape_inv;
+            integration::adaptive_quadrature_infinite(
+                |x| (ln_sf_t - x.powf(self.shape) * self.scale_pow_shape_inv).exp(),
+                t,
+                f64::INFINITY,
+                internal::INTEGRATION_TOLERANCE,
+            )
+        }
+    }
+
+    /// Estimates a weibull distribution from `samples` by maximum
+    /// likelihood. The shape is found by Newton iteration on the
+    /// profile likelihood equation and the scale then follows in closed
+    /// form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `samples` is empty, if any sample is not finite
+    /// or `<= 0.0`, if all samples are equal (the maximum likelihood shape
+    /// is unbounded) or if the iteration fails to converge
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(x_i^k * ln(x_i)) / sum(x_i^k) - 1 / k - sum(ln(x_i)) / n = 0
+    /// λ = (sum(x_i^k) / n)^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the scale and `n` is the number of
+    /// samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Weibull;
+    ///
+    /// let n = Weibull::fit_mle(&[0.8, 1.1, 1.4, 2.0, 2.3, 3.1]).unwrap();
+    /// assert!(n.shape() > 0.0);
+    ///
+    /// assert!(Weibull::fit_mle(&[]).is_err());
+    /// assert!(Weibull::fit_mle(&[1.0, -1.0]).is_err());
+    /// ```
+    pub fn fit_mle(samples: &[f64]) -> Result<Weibull> {
+        if samples.is_empty() {
+            return Err(StatsError::SpecialCase(
+                "Cannot fit a Weibull distribution to an empty sample",
+            ));
+        }
+        if samples.iter().any(|&x| !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        if samples.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("samples"));
+        }
+
+        // the shape equation is invariant to rescaling the data, so work
+        // with samples in (0, 1] to keep x^k from overflowing
+        let max = samples.iter().fold(0.0f64, |acc, &x| acc.max(x));
+        let ln_y: Vec<f64> = samples.iter().map(|&x| (x / max).ln()).collect();
+        let n = ln_y.len() as f64;
+        let mean_ln = ln_y.iter().sum::<f64>() / n;
+        let var_ln = ln_y
+            .iter()
+            .map(|&l| (l - mean_ln) * (l - mean_ln))
+            .sum::<f64>()
+            / n;
+        if var_ln <= 0.0 {
+            return Err(StatsError::SpecialCase(
+                "Cannot fit a Weibull distribution to samples that are all equal",
+            ));
+        }
+
+        // Menon's moment estimate as a starting point
+        let mut k = f64::consts::PI / (6.0 * var_ln).sqrt();
+        for _ in 0..100 {
+            let (mut s0, mut s1, mut s2) = (0.0, 0.0, 0.0);
+            for &l in &ln_y {
+                let w = (k * l).exp();
+                s0 += w;
+                s1 += w * l;
+                s2 += w * l * l;
+            }
+            let g = s1 / s0 - 1.0 / k - mean_ln;
+            let dg = (s2 * s0 - s1 * s1) / (s0 * s0) + 1.0 / (k * k);
+            let mut next = k - g / dg;
+            if next <= 0.0 {
+                next = k / 2.0;
+            }
+            if (next - k).abs() <= 1e-12 * k {
+                let mean_pow = ln_y.iter().map(|&l| (next * l).exp()).sum::<f64>() / n;
+                return Weibull::new(next, max * mean_pow.powf(1.0 / next));
+            }
+            k = next;
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
+impl Parametric for Weibull {
+    /// Returns the `shape` and `scale` parameters of the weibull distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("shape", self.shape), ("scale", self.scale)]
+    }
+
+    /// Constructs a weibull distribution from its `shape` and `scale`
+    /// parameters
+    fn from_parameters(params: &[(&str, f64)]) -> Result<Weibull> {
+        let p = internal::parameter_values(params, &["shape", "scale"])?;
+        Weibull::new(p[0], p[1])
+    }
 }
 
 impl Distribution<f64> for Weibull {
@@ -145,6 +339,24 @@ impl Max<f64> for Weibull {
     }
 }
 
+impl Domain for Weibull {
+    /// Returns the support of the weibull distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [0, ∞)
+    /// ```
+    fn support(&self) -> Support {
+        Support::Continuous {
+            lo: 0.0,
+            hi: f64::INFINITY,
+            lo_closed: true,
+            hi_closed: false,
+        }
+    }
+}
+
 impl Mean<f64> for Weibull {
     /// Returns the mean of the weibull distribution
     ///
@@ -191,6 +403,8 @@ impl Variance<f64> for Weibull {
     }
 }
 
+impl Dispersion for Weibull {}
+
 impl Entropy<f64> for Weibull {
     /// Returns the entropy of the weibull distribution
     ///
@@ -320,13 +534,18 @@ impl Continuous<f64, f64> for Weibull {
     }
 }
 
+impl Moments for Weibull {}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
     use std::f64;
     use statistics::*;
-    use distribution::{Univariate, Continuous, Weibull};
+    use distribution::{Univariate, Continuous, HazardShape, Moments, Weibull};
+    use function::gamma;
     use distribution::internal::*;
+    use rng;
+    use StatsError;
 
     fn try_create(shape: f64, scale: f64) -> Weibull {
         let n = Weibull::new(shape, scale);
@@ -497,8 +716,98 @@ mod test {
         test_case(10.0, 1.0, 1.0, |x| x.cdf(10.0));
     }
 
+    #[test]
+    fn test_cdf_small_x() {
+        test_almost(1.0, 1.0, 1e-10, 1e-20, |x| x.cdf(1e-10));
+        test_almost(2.0, 1.0, 1e-20, 1e-30, |x| x.cdf(1e-10));
+        test_almost(0.5, 4.0, 4.999999999875e-11, 1e-24, |x| x.cdf(1e-20));
+    }
+
     #[test]
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(1.0, 0.2), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_fit_mle() {
+        use rand::distributions::Distribution;
+
+        let mut r = rng::seeded(0);
+        let n = try_create(1.5, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let fit = Weibull::fit_mle(&samples).unwrap();
+        assert_almost_eq!(fit.shape(), 1.5, 0.02);
+        assert_almost_eq!(fit.scale(), 2.0, 0.02);
+    }
+
+    #[test]
+    fn test_fit_mle_rescaled_data() {
+        let data = [0.8, 1.1, 1.4, 2.0, 2.3, 3.1];
+        let scaled: Vec<f64> = data.iter().map(|x| x * 1000.0).collect();
+        let fit = Weibull::fit_mle(&data).unwrap();
+        let scaled_fit = Weibull::fit_mle(&scaled).unwrap();
+        assert_almost_eq!(fit.shape(), scaled_fit.shape(), 1e-9);
+        assert_almost_eq!(fit.scale() * 1000.0, scaled_fit.scale(), 1e-6);
+    }
+
+    #[test]
+    fn test_fit_mle_bad_samples() {
+        assert!(Weibull::fit_mle(&[]).is_err());
+        assert!(Weibull::fit_mle(&[1.0, 0.0, 2.0]).is_err());
+        assert!(Weibull::fit_mle(&[1.0, -2.0]).is_err());
+        assert!(Weibull::fit_mle(&[1.0, f64::NAN]).is_err());
+        // rejected up front rather than by the iteration failing to converge
+        for samples in &[[1.0, f64::INFINITY], [f64::NEG_INFINITY, 1.0]] {
+            match Weibull::fit_mle(samples) {
+                Err(StatsError::BadParams) => {}
+                other => panic!("unexpected result {:?}", other),
+            }
+        }
+        assert!(Weibull::fit_mle(&[2.5, 2.5, 2.5, 2.5]).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(1.5, 2.0);
+        assert_almost_eq!(n.raw_moment(1), n.mean(), 1e-10);
+        assert_almost_eq!(n.central_moment(2), n.variance(), 1e-10);
+        assert_almost_eq!(n.central_moment(3) / n.variance().powf(1.5), n.skewness(), 1e-9);
+    }
+
+    #[test]
+    fn test_hazard_shape() {
+        assert_eq!(try_create(0.5, 1.0).hazard_shape(), HazardShape::Decreasing);
+        assert_eq!(try_create(1.0, 1.0).hazard_shape(), HazardShape::Constant);
+        assert_eq!(try_create(1.0, 10.0).hazard_shape(), HazardShape::Constant);
+        assert_eq!(try_create(1.5, 1.0).hazard_shape(), HazardShape::Increasing);
+    }
+
+    #[test]
+    fn test_mean_residual_life() {
+        for &(shape, scale) in &[(0.5f64, 1.0f64), (1.0, 2.0), (2.0, 1.0), (5.0, 3.0)] {
+            let n = try_create(shape, scale);
+            assert_almost_eq!(n.mean_residual_life(0.0), n.mean(), 1e-14);
+            assert_almost_eq!(n.mean_residual_life(-1.5), n.mean() + 1.5, 1e-14);
+            // closed form in terms of the upper incomplete gamma function
+            for &t in &[0.1f64, 1.0, 2.5] {
+                let s = (t / scale).powf(shape);
+                let expected = scale / shape * gamma::gamma(1.0 / shape) * gamma::gamma_ur(1.0 / shape, s) / (-s).exp();
+                assert_almost_eq!(n.mean_residual_life(t), expected, 1e-9 * expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean_residual_life_memoryless() {
+        let n = try_create(1.0, 2.0);
+        for &t in &[0.5f64, 3.0, 100.0] {
+            assert_almost_eq!(n.mean_residual_life(t), 2.0, 1e-9);
+        }
+        // the survival function underflows but the residual life does not
+        let n = try_create(2.0, 1.0);
+        let mrl = n.mean_residual_life(50.0);
+        assert!(mrl.is_finite());
+        assert!(mrl > 0.0 && mrl < 0.01);
+        assert!(n.mean_residual_life(f64::NAN).is_nan());
+    }
 }
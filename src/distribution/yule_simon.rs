@@ -0,0 +1,457 @@
+use distribution::{internal, Discrete, Domain, Parametric, Support, Univariate};
+use function::beta;
+use rand::distributions::Distribution;
+use rand::distributions::OpenClosed01;
+use rand::Rng;
+use statistics::*;
+use std::f64;
+use {Result, StatsError};
+
+/// Implements the
+/// [Yule-Simon](https://en.wikipedia.org/wiki/Yule%E2%80%93Simon_distribution)
+/// distribution
+///
+/// # Remarks
+///
+/// The Yule-Simon distribution is the limiting distribution of the number
+/// of links of a node in preferential attachment models. Its tail decays
+/// as the power law `k^(-(ρ + 1))`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{YuleSimon, Discrete};
+/// use statrs::statistics::Mean;
+///
+/// let n = YuleSimon::new(3.0).unwrap();
+/// assert_eq!(n.mean(), 1.5);
+/// assert!((n.pmf(1) - 0.75).abs() < 1e-12);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+impl YuleSimon {
+    /// Constructs a new Yule-Simon distribution with a shape (ρ) of `rho`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rho` is `NaN` or `rho <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::YuleSimon;
+    ///
+    /// let mut result = YuleSimon::new(2.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = YuleSimon::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(rho: f64) -> Result<YuleSimon> {
+        if rho.is_nan() || rho <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(YuleSimon { rho: rho })
+        }
+    }
+
+    /// Returns the shape (ρ) of the Yule-Simon distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::YuleSimon;
+    ///
+    /// let n = YuleSimon::new(2.5).unwrap();
+    /// assert_eq!(n.rho(), 2.5);
+    /// ```
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+}
+
+impl Parametric for YuleSimon {
+    /// Returns the `rho` parameter of the Yule-Simon distribution
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![("rho", self.rho)]
+    }
+
+    /// Constructs a Yule-Simon distribution from its `rho` parameter
+    fn from_parameters(params: &[(&str, f64)]) -> Result<YuleSimon> {
+        let p = internal::parameter_values(params, &["rho"])?;
+        YuleSimon::new(p[0])
+    }
+}
+
+impl Distribution<f64> for YuleSimon {
+    /// Draws a sample as a geometric random variable whose success
+    /// probability `e^(-W)` is given by an exponential random variable `W`
+    /// with a rate of `ρ`
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(OpenClosed01);
+        let v: f64 = r.sample(OpenClosed01);
+        let w = -u.ln() / self.rho;
+        // ln(1 - e^(-w)) without cancellation for small w
+        let ln_fail = (-(-w).exp_m1()).ln();
+        (v.ln() / ln_fail).ceil().max(1.0)
+    }
+}
+
+impl Univariate<u64, f64> for YuleSimon {
+    /// Calculates the cumulative distribution function for the Yule-Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - floor(x) * B(floor(x), ρ + 1)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            let k = x.floor();
+            -(k.ln() + beta::ln_beta(k, self.rho + 1.0)).exp_m1()
+        }
+    }
+}
+
+impl Min<u64> for YuleSimon {
+    /// Returns the minimum value in the domain of the Yule-Simon
+    /// distribution representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn min(&self) -> u64 {
+        1
+    }
+}
+
+impl Max<u64> for YuleSimon {
+    /// Returns the maximum value in the domain of the Yule-Simon
+    /// distribution representable by a 64-bit integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^63 - 1
+    /// ```
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+}
+
+impl Domain for YuleSimon {
+    /// Returns the support of the Yule-Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// {1, 2, ...}
+    /// ```
+    fn support(&self) -> Support {
+        Support::DiscreteRange {
+            lo: 1.0,
+            hi: f64::INFINITY,
+        }
+    }
+}
+
+impl Mean<f64> for YuleSimon {
+    /// Returns the mean of the Yule-Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ρ <= 1 {
+    ///     INF
+    /// } else {
+    ///     ρ / (ρ - 1)
+    /// }
+    /// ```
+    fn mean(&self) -> f64 {
+        if self.rho <= 1.0 {
+            f64::INFINITY
+        } else {
+            self.rho / (self.rho - 1.0)
+        }
+    }
+}
+
+impl CheckedMean<f64> for YuleSimon {
+    /// Returns the mean of the Yule-Simon distribution
+    ///
+    /// # Errors
+    ///
+    /// If `ρ <= 1.0`, where the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ρ / (ρ - 1)
+    /// ```
+    fn checked_mean(&self) -> Result<f64> {
+        if self.rho <= 1.0 {
+            Err(StatsError::ArgGt("rho", 1.0))
+        } else {
+            Ok(self.mean())
+        }
+    }
+}
+
+impl Variance<f64> for YuleSimon {
+    /// Returns the variance of the Yule-Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ρ <= 2 {
+    ///     INF
+    /// } else {
+    ///     ρ^2 / ((ρ - 1)^2 * (ρ - 2))
+    /// }
+    /// ```
+    fn variance(&self) -> f64 {
+        if self.rho <= 2.0 {
+            f64::INFINITY
+        } else {
+            let a = self.rho / (self.rho - 1.0);
+            a * a / (self.rho - 2.0)
+        }
+    }
+
+    /// Returns the standard deviation of the Yule-Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// let variance = if ρ <= 2 {
+    ///     INF
+    /// } else {
+    ///     ρ^2 / ((ρ - 1)^2 * (ρ - 2))
+    /// };
+    /// sqrt(variance)
+    /// ```
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Dispersion for YuleSimon {}
+
+impl CheckedVariance<f64> for YuleSimon {
+    /// Returns the variance of the Yule-Simon distribution
+    ///
+    /// # Errors
+    ///
+    /// If `ρ <= 2.0`, where the variance is infinite or undefined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ρ^2 / ((ρ - 1)^2 * (ρ - 2))
+    /// ```
+    fn checked_variance(&self) -> Result<f64> {
+        if self.rho <= 2.0 {
+            Err(StatsError::ArgGt("rho", 2.0))
+        } else {
+            Ok(self.variance())
+        }
+    }
+
+    /// Returns the standard deviation of the Yule-Simon distribution
+    ///
+    /// # Errors
+    ///
+    /// If `ρ <= 2.0`, where the variance is infinite or undefined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(ρ^2 / ((ρ - 1)^2 * (ρ - 2)))
+    /// ```
+    fn checked_std_dev(&self) -> Result<f64> {
+        self.checked_variance().map(|x| x.sqrt())
+    }
+}
+
+impl Mode<u64> for YuleSimon {
+    /// Returns the mode of the Yule-Simon distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn mode(&self) -> u64 {
+        1
+    }
+}
+
+impl Discrete<u64, f64> for YuleSimon {
+    /// Calculates the probability mass function for the Yule-Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ρ * B(x, ρ + 1)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.ln_pmf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability mass function for the Yule-Simon
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(ρ * B(x, ρ + 1))
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.rho.ln() + beta::ln_beta(x as f64, self.rho + 1.0)
+        }
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Discrete, Univariate, YuleSimon};
+    use distribution::internal::*;
+    use rng;
+
+    fn try_create(rho: f64) -> YuleSimon {
+        let n = YuleSimon::new(rho);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(rho: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(YuleSimon) -> f64
+    {
+        let x = eval(try_create(rho));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(0.5).rho(), 0.5);
+        assert_eq!(try_create(30.0).rho(), 30.0);
+        assert!(YuleSimon::new(0.0).is_err());
+        assert!(YuleSimon::new(-1.0).is_err());
+        assert!(YuleSimon::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(try_create(3.0).mean(), 1.5);
+        assert_eq!(try_create(3.0).checked_mean().unwrap(), 1.5);
+        assert_eq!(try_create(1.0).mean(), f64::INFINITY);
+        assert_eq!(try_create(0.5).mean(), f64::INFINITY);
+        assert!(try_create(1.0).checked_mean().is_err());
+        // the mean of the pmf for rho = 3, whose tail beyond k is about 3 / k^2
+        let n = try_create(3.0);
+        let mean: f64 = (1..100000u64).map(|k| k as f64 * n.pmf(k)).sum();
+        assert_almost_eq!(mean, 1.5, 1e-8);
+    }
+
+    #[test]
+    fn test_variance() {
+        assert_eq!(try_create(3.0).variance(), 2.25);
+        assert_eq!(try_create(3.0).std_dev(), 1.5);
+        assert_eq!(try_create(2.0).variance(), f64::INFINITY);
+        assert!(try_create(2.0).checked_variance().is_err());
+        assert!(try_create(1.5).checked_std_dev().is_err());
+        assert_eq!(try_create(3.0).checked_variance().unwrap(), 2.25);
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(try_create(0.5).mode(), 1);
+        assert_eq!(try_create(3.0).mode(), 1);
+        assert_eq!(try_create(3.0).min(), 1);
+    }
+
+    #[test]
+    fn test_pmf() {
+        test_almost(0.5, 0.3333333333333333, 1e-13, |x| x.pmf(1));
+        test_almost(0.5, 0.03694083694083694, 1e-14, |x| x.pmf(5));
+        test_almost(0.5, 0.0017353499238036596, 1e-15, |x| x.pmf(40));
+        test_almost(3.0, 0.75, 1e-13, |x| x.pmf(1));
+        test_almost(3.0, 0.15, 1e-13, |x| x.pmf(2));
+        test_almost(3.0, 6.077303297949923e-6, 1e-17, |x| x.pmf(40));
+        test_almost(4.5, 0.1258741258741259, 1e-13, |x| x.pmf(2));
+        assert_eq!(try_create(3.0).pmf(0), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        test_almost(0.5, -3.2984376477043864, 1e-12, |x| x.ln_pmf(5));
+        test_almost(3.0, -12.010949496899011, 1e-12, |x| x.ln_pmf(40));
+        test_almost(4.5, -0.20067069546215116, 1e-12, |x| x.ln_pmf(1));
+        assert_eq!(try_create(3.0).ln_pmf(0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        for &rho in &[3.0f64, 4.5, 10.0] {
+            let n = try_create(rho);
+            let sum: f64 = (1..100000u64).map(|k| n.pmf(k)).sum();
+            assert_almost_eq!(sum, 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.5, 0.3333333333333333, 1e-13, |x| x.cdf(1.0));
+        test_almost(0.5, 0.5428571428571428, 1e-13, |x| x.cdf(3.5));
+        test_almost(0.5, 0.7297398164271229, 1e-13, |x| x.cdf(10.0));
+        test_almost(3.0, 0.95, 1e-13, |x| x.cdf(3.0));
+        test_almost(4.5, 0.999432737245874, 1e-14, |x| x.cdf(10.0));
+        assert_eq!(try_create(3.0).cdf(0.5), 0.0);
+        assert_eq!(try_create(3.0).cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = rng::seeded(0);
+        let n = try_create(4.5);
+        let samples: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 1.0 && x == x.floor()));
+        let ones = samples.iter().filter(|&&x| x == 1.0).count() as f64;
+        assert_almost_eq!(ones / 20000.0, n.pmf(1), 0.01);
+        assert_almost_eq!(samples.iter().mean(), n.mean(), 0.03);
+    }
+
+    #[test]
+    fn test_discrete() {
+        test::check_discrete_distribution(&try_create(3.0), 1000);
+        test::check_discrete_distribution(&try_create(10.0), 100);
+    }
+}
@@ -44,7 +44,8 @@ pub fn sample_exp_1<R: Rng + ?Sized>(r: &mut R) -> f64 {
 
     #[inline]
     fn zero_case<R: Rng + ?Sized>(r: &mut R, _u: f64) -> f64 {
-        ziggurat_tables::ZIG_EXP_R - r.gen::<f64>().ln()
+        let u: f64 = r.sample(Open01);
+        ziggurat_tables::ZIG_EXP_R - u.ln()
     }
 
     ziggurat(
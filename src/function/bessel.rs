@@ -0,0 +1,114 @@
+//! Provides modified Bessel functions of the first kind
+
+use function::factorial;
+use std::f64;
+
+/// Computes the modified Bessel function of the first kind `I_n(x)` of
+/// integer order `n`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is negative or `NaN`. The result overflows to
+/// `INF` for `x` above about `713`; use `ln_bessel_i` for large arguments.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel;
+///
+/// assert_eq!(bessel::bessel_i(0, 0.0), 1.0);
+/// assert_eq!(bessel::bessel_i(2, 0.0), 0.0);
+/// assert!((bessel::bessel_i(1, 1.0) - 0.565159103992485027).abs() < 1e-15);
+/// ```
+pub fn bessel_i(n: u64, x: f64) -> f64 {
+    ln_bessel_i(n, x).exp()
+}
+
+/// Computes the natural logarithm of the modified Bessel function of the
+/// first kind `I_n(x)` of integer order `n`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is negative or `NaN`. The power series
+/// `I_n(x) = Σ (x / 2)^(2m + n) / (m! (m + n)!)` is summed in log space,
+/// which keeps every term representable and, since all terms are positive,
+/// is free of cancellation. The number of terms grows linearly with `x`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel;
+///
+/// assert_eq!(bessel::ln_bessel_i(0, 0.0), 0.0);
+/// assert!((bessel::ln_bessel_i(0, 700.0) - 695.805699998443449).abs() < 1e-10);
+/// ```
+pub fn ln_bessel_i(n: u64, x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return if n == 0 { 0.0 } else { f64::NEG_INFINITY };
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+
+    let order = n as f64;
+    let ln_half_x = (0.5 * x).ln();
+    let mut ln_term = order * ln_half_x - factorial::ln_factorial(n);
+    // the sum of the terms relative to the largest term seen so far
+    let mut ln_max = ln_term;
+    let mut sum = 1.0;
+    let mut m: f64 = 0.0;
+    loop {
+        m += 1.0;
+        ln_term += 2.0 * ln_half_x - m.ln() - (m + order).ln();
+        if ln_term > ln_max {
+            sum = sum * (ln_max - ln_term).exp() + 1.0;
+            ln_max = ln_term;
+        } else {
+            let term = (ln_term - ln_max).exp();
+            sum += term;
+            // the terms are unimodal in m, so once below the largest term
+            // they only decrease
+            if term < 1e-17 {
+                break;
+            }
+        }
+    }
+    ln_max + sum.ln()
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+
+    #[test]
+    fn test_bessel_i() {
+        assert_eq!(super::bessel_i(0, 0.0), 1.0);
+        assert_eq!(super::bessel_i(3, 0.0), 0.0);
+        assert_almost_eq!(super::bessel_i(0, 0.5), 1.0634833707413236, 1e-15);
+        assert_almost_eq!(super::bessel_i(0, 10.0), 2815.7166284662544, 1e-11);
+        assert_almost_eq!(super::bessel_i(1, 1.0), 0.565159103992485, 1e-15);
+        assert_almost_eq!(super::bessel_i(3, 2.5), 0.4743704087780356, 1e-15);
+        assert_almost_eq!(super::bessel_i(5, 0.01), 2.6041775173804877e-14, 1e-27);
+        assert_almost_eq!(super::bessel_i(10, 50.0) / 1.0715971594776371e20, 1.0, 1e-13);
+        assert!(super::bessel_i(0, -1.0).is_nan());
+        assert!(super::bessel_i(0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ln_bessel_i() {
+        assert_eq!(super::ln_bessel_i(0, 0.0), 0.0);
+        assert_eq!(super::ln_bessel_i(1, 0.0), f64::NEG_INFINITY);
+        assert_eq!(super::ln_bessel_i(1, f64::INFINITY), f64::INFINITY);
+        assert_almost_eq!(super::ln_bessel_i(0, 0.5), 0.06154971918548131, 1e-15);
+        assert_almost_eq!(super::ln_bessel_i(3, 2.5), -0.7457668093443535, 1e-15);
+        assert_almost_eq!(super::ln_bessel_i(5, 0.01), -31.279074408856804, 1e-13);
+        assert_almost_eq!(super::ln_bessel_i(10, 50.0), 46.12085206783563, 1e-13);
+        assert_almost_eq!(super::ln_bessel_i(0, 700.0), 695.8056999984434, 1e-10);
+        assert_almost_eq!(super::ln_bessel_i(2, 1000.0), 995.6253078894531, 1e-10);
+        assert!(super::ln_bessel_i(2, -0.5).is_nan());
+    }
+}
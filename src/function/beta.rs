@@ -144,6 +144,10 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
         let qam = a - 1.0;
         let mut c = 1.0;
         let mut d = 1.0 - qab * x / qap;
+        // the number of terms needed grows with the square root of the
+        // larger shape parameter, capped so that huge or infinite shapes do
+        // not overflow the bound
+        let max_iterations = (140.0 + 10.0 * a.max(b).sqrt()).min(1e6) as u32;
 
         if d.abs() < fpmin {
             d = fpmin;
@@ -151,7 +155,7 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
         d = 1.0 / d;
         let mut h = d;
 
-        for m in 1..141 {
+        for m in 1..max_iterations + 1 {
             let m = f64::from(m);
             let m2 = m * 2.0;
             let mut aa = m * (b - m) * x / ((qam + m2) * (a + m2));
@@ -185,6 +189,11 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
             let del = d * c;
             h *= del;
 
+            // a NaN term never converges, so stop rather than run out the
+            // iteration bound of a huge shape
+            if del.is_nan() {
+                break;
+            }
             if (del - 1.0).abs() <= eps {
                 return if symm_transform {
                     Ok(1.0 - bt * h / a)
@@ -361,6 +370,15 @@ mod test {
         assert_eq!(super::beta_reg(1.0, 2.5, 1.0), 1.0);
         assert_almost_eq!(super::beta_reg(2.5, 2.5, 0.5), 0.5, 1e-15);
         assert_eq!(super::beta_reg(2.5, 2.5, 1.0), 1.0);
+        // large shapes need more terms of the continued fraction
+        assert_almost_eq!(super::beta_reg(1e5, 1e5, 0.5), 0.5, 1e-8);
+        assert_almost_eq!(super::beta_reg(1e6, 1e6, 0.5), 0.5, 1e-8);
+    }
+
+    #[test]
+    fn test_beta_reg_huge_shape() {
+        assert!(super::checked_beta_reg(f64::INFINITY, 1.0, 0.5).unwrap().is_nan());
+        assert!(super::checked_beta_reg(1.0, f64::INFINITY, 0.5).unwrap().is_nan());
     }
 
     #[test]
@@ -1,5 +1,6 @@
 //! Provides functions related to exponential calculations
 
+use std::f64;
 use {consts, Result, StatsError};
 
 /// Computes the generalized Exponential Integral function
@@ -92,9 +93,40 @@ pub fn integral(x: f64, n: u64) -> Result<f64> {
     }
 }
 
+/// Computes `ln(Σ exp(x_i))` over the elements of `values` without
+/// overflowing or underflowing for large or small elements
+///
+/// # Remarks
+///
+/// The sum is shifted by the largest element `m` as
+/// `m + ln(Σ exp(x_i - m))`, so every exponent is at most `0`. Returns
+/// `-INF` if `values` is empty or all its elements are `-INF`, `INF` if any
+/// element is `INF` and `NaN` if any element is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::logsumexp;
+///
+/// assert_eq!(logsumexp(&[0.0, 0.0]), 2f64.ln());
+/// assert_eq!(logsumexp(&[1000.0, 1000.0]), 1000.0 + 2f64.ln());
+/// ```
+pub fn logsumexp(values: &[f64]) -> f64 {
+    let m = values.iter().fold(f64::NEG_INFINITY, |acc, &x| acc.max(x));
+    if values.iter().any(|x| x.is_nan()) {
+        f64::NAN
+    } else if m.is_infinite() {
+        m
+    } else {
+        m + values.iter().map(|&x| (x - m).exp()).sum::<f64>().ln()
+    }
+}
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
+    use std::f64;
+
     #[test]
     fn test_integral() {
         assert_eq!(super::integral(0.001, 1).unwrap(), 6.33153936413614904);
@@ -114,4 +146,24 @@ mod test {
         assert_eq!(super::integral(2.0, 0).unwrap(), 0.06766764161830635);
         assert_eq!(super::integral(10.0, 0).unwrap(), 4.539992976248485e-06);
     }
+
+    #[test]
+    fn test_logsumexp() {
+        for values in &[vec![0.0f64], vec![1.0, 2.0, 3.0], vec![-5.0, 0.5, 10.0, -0.25], vec![-700.0, -710.0]] {
+            let naive = values.iter().map(|x| x.exp()).sum::<f64>().ln();
+            assert_almost_eq!(super::logsumexp(values), naive, 1e-13);
+        }
+        assert_eq!(super::logsumexp(&[1000.0, 1001.0]), 1001.0 + (1.0 + (-1f64).exp()).ln());
+        assert_eq!(super::logsumexp(&[-1000.0, -1001.0]), -1000.0 + (1.0 + (-1f64).exp()).ln());
+        assert_eq!(super::logsumexp(&[5.0, f64::NEG_INFINITY]), 5.0);
+    }
+
+    #[test]
+    fn test_logsumexp_special_values() {
+        assert_eq!(super::logsumexp(&[]), f64::NEG_INFINITY);
+        assert_eq!(super::logsumexp(&[f64::NEG_INFINITY, f64::NEG_INFINITY]), f64::NEG_INFINITY);
+        assert_eq!(super::logsumexp(&[1.0, f64::INFINITY]), f64::INFINITY);
+        assert!(super::logsumexp(&[1.0, f64::NAN]).is_nan());
+        assert!(super::logsumexp(&[f64::INFINITY, f64::NAN]).is_nan());
+    }
 }
@@ -1,6 +1,7 @@
 //! Provides a host of special statistical functions (e.g. the beta function or
 //! the error function)
 
+pub mod bessel;
 pub mod beta;
 pub mod erf;
 pub mod evaluate;
@@ -8,4 +9,8 @@ pub mod exponential;
 pub mod factorial;
 pub mod gamma;
 pub mod harmonic;
+pub mod integration;
 pub mod logistic;
+pub mod optimization;
+
+pub use self::exponential::logsumexp;
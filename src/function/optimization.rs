@@ -0,0 +1,313 @@
+//! Provides numerical root finding and maximization for real valued
+//! functions of a single variable
+
+use std::f64;
+use {Result, StatsError};
+
+// Maximum number of iterations used by `find_root` and `golden_section_max`
+const MAX_ITERATIONS: usize = 200;
+
+/// Finds a root of `f` in the bracket `[lower, upper]` using Brent's method,
+/// combining bisection, the secant method and inverse quadratic
+/// interpolation
+///
+/// # Panics
+///
+/// If the bracket is invalid, does not contain a sign change or the
+/// iteration fails to converge, see `checked_find_root`
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::function::optimization::find_root;
+///
+/// # fn main() {
+/// let x = find_root(|x| x * x - 2.0, 0.0, 2.0, 1e-15);
+/// assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+/// # }
+/// ```
+pub fn find_root<F>(f: F, lower: f64, upper: f64, tol: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    checked_find_root(f, lower, upper, tol).unwrap()
+}
+
+/// Finds a root of `f` in the bracket `[lower, upper]` using Brent's method,
+/// combining bisection, the secant method and inverse quadratic
+/// interpolation
+///
+/// # Remarks
+///
+/// Iteration stops once the bracket around the root is narrower than
+/// `tol` plus a few ulps of the current estimate, or when an exact root
+/// is found
+///
+/// # Errors
+///
+/// If `lower`, `upper` or `tol` is `NaN`, if `lower > upper`, if `tol < 0.0`
+/// or if `f(lower)` and `f(upper)` have the same sign so the bracket is not
+/// known to contain a root. Also returns an error if the iteration does not
+/// converge within `200` steps
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::optimization::checked_find_root;
+///
+/// assert!(checked_find_root(|x| x * x + 1.0, -1.0, 1.0, 1e-15).is_err());
+/// ```
+pub fn checked_find_root<F>(f: F, lower: f64, upper: f64, tol: f64) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    if lower.is_nan() || upper.is_nan() {
+        return Err(StatsError::BadParams);
+    }
+    if lower > upper {
+        return Err(StatsError::ArgLteArg("lower", "upper"));
+    }
+    if tol.is_nan() || tol < 0.0 {
+        return Err(StatsError::ArgNotNegative("tol"));
+    }
+
+    let (mut a, mut b) = (lower, upper);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.is_nan() || fb.is_nan() || (fa > 0.0) == (fb > 0.0) {
+        return Err(StatsError::SpecialCase(
+            "Bracket does not contain a sign change",
+        ));
+    }
+
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+    for _ in 0..MAX_ITERATIONS {
+        if (fb > 0.0) == (fc > 0.0) {
+            // keep the root bracketed between b and c
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0.0 {
+            return Ok(b);
+        }
+
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // attempt interpolation, secant if only two points are
+            // distinct and inverse quadratic otherwise
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = 3.0 * xm * q - (tol1 * q).abs();
+            let min2 = (e * q).abs();
+            if 2.0 * p < min1.min(min2) {
+                e = d;
+                d = p / q;
+            } else {
+                // interpolation failed, fall back to bisection
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        if d.abs() > tol1 {
+            b += d;
+        } else {
+            b += tol1.copysign(xm);
+        }
+        fb = f(b);
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// Finds the maximizer of `f` in `[lower, upper]` by golden section search,
+/// assuming `f` is unimodal in the interval
+///
+/// # Panics
+///
+/// If the interval or tolerance is invalid or the iteration fails to
+/// converge, see `checked_golden_section_max`
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::function::optimization::golden_section_max;
+///
+/// # fn main() {
+/// let x = golden_section_max(|x| -(x - 1.5) * (x - 1.5), 0.0, 4.0, 1e-12);
+/// assert_almost_eq!(x, 1.5, 1e-10);
+/// # }
+/// ```
+pub fn golden_section_max<F>(f: F, lower: f64, upper: f64, tol: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    checked_golden_section_max(f, lower, upper, tol).unwrap()
+}
+
+/// Finds the maximizer of `f` in `[lower, upper]` by golden section search,
+/// assuming `f` is unimodal in the interval
+///
+/// # Remarks
+///
+/// Each step shrinks the interval by the golden ratio, keeping the larger
+/// of the two interior values, and iteration stops once it is narrower
+/// than `tol` plus a few ulps of its bounds. The midpoint of the final
+/// interval is returned. If `f` is not unimodal a local maximum is found.
+///
+/// # Errors
+///
+/// If `lower`, `upper` or `tol` is `NaN`, if `lower > upper` or if
+/// `tol < 0.0`. Also returns an error if the iteration does not converge
+/// within `200` steps
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::optimization::checked_golden_section_max;
+///
+/// assert!(checked_golden_section_max(|x| -x * x, 1.0, -1.0, 1e-12).is_err());
+/// ```
+pub fn checked_golden_section_max<F>(f: F, lower: f64, upper: f64, tol: f64) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    if lower.is_nan() || upper.is_nan() {
+        return Err(StatsError::BadParams);
+    }
+    if lower > upper {
+        return Err(StatsError::ArgLteArg("lower", "upper"));
+    }
+    if tol.is_nan() || tol < 0.0 {
+        return Err(StatsError::ArgNotNegative("tol"));
+    }
+
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (lower, upper);
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    for _ in 0..MAX_ITERATIONS {
+        if b - a <= tol + 4.0 * f64::EPSILON * a.abs().max(b.abs()) {
+            return Ok(0.5 * (a + b));
+        }
+        if fc >= fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - ratio * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + ratio * (b - a);
+            fd = f(d);
+        }
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use super::*;
+
+    #[test]
+    fn test_find_root() {
+        assert_almost_eq!(find_root(|x| x * x - 2.0, 0.0, 2.0, 1e-15), 2f64.sqrt(), 1e-15);
+        assert_almost_eq!(find_root(|x| x.powi(3) - 2.0 * x - 5.0, 2.0, 3.0, 1e-15), 2.0945514815423265, 1e-15);
+        assert_almost_eq!(find_root(|x| x.cos() - x, 0.0, 1.0, 1e-15), 0.7390851332151607, 1e-15);
+        assert_almost_eq!(find_root(|x| x.exp() - 10.0, -10.0, 10.0, 1e-15), 10f64.ln(), 1e-14);
+        assert_almost_eq!(find_root(|x| (x - 0.25).powi(3), -1.0, 1.0, 1e-15), 0.25, 1e-5);
+        assert_almost_eq!(find_root(|x| 1.0 - x, 0.0, 3.0, 1e-15), 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_find_root_endpoints() {
+        assert_eq!(find_root(|x| x, 0.0, 1.0, 1e-15), 0.0);
+        assert_eq!(find_root(|x| x - 1.0, 0.0, 1.0, 1e-15), 1.0);
+        assert_eq!(find_root(|x| x - 1.0, 1.0, 1.0, 1e-15), 1.0);
+    }
+
+    #[test]
+    fn test_checked_find_root_bad_bracket() {
+        assert!(checked_find_root(|x| x * x + 1.0, -1.0, 1.0, 1e-15).is_err());
+        assert!(checked_find_root(|x| x - 0.5, 1.0, 0.0, 1e-15).is_err());
+        assert!(checked_find_root(|x| x - 0.5, f64::NAN, 1.0, 1e-15).is_err());
+        assert!(checked_find_root(|x| x - 0.5, 0.0, 1.0, -1.0).is_err());
+        assert!(checked_find_root(|x| x - 0.5, 0.0, 1.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_find_root_bad_bracket() {
+        find_root(|x| x * x + 1.0, -1.0, 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_golden_section_max() {
+        assert_almost_eq!(golden_section_max(|x| -(x - 1.5) * (x - 1.5), 0.0, 4.0, 1e-12), 1.5, 1e-10);
+        assert_almost_eq!(golden_section_max(|x| x.sin(), 0.0, 3.0, 1e-12), f64::consts::FRAC_PI_2, 1e-7);
+        assert_almost_eq!(golden_section_max(|x| -x.exp() + 2.0 * x, -5.0, 5.0, 1e-12), 2f64.ln(), 1e-7);
+        // a maximum at a bound and an empty interval
+        assert_almost_eq!(golden_section_max(|x| x, 0.0, 1.0, 1e-12), 1.0, 1e-11);
+        assert_eq!(golden_section_max(|x| x, 2.0, 2.0, 1e-12), 2.0);
+    }
+
+    #[test]
+    fn test_checked_golden_section_max_bad_interval() {
+        assert!(checked_golden_section_max(|x| -x * x, 1.0, -1.0, 1e-12).is_err());
+        assert!(checked_golden_section_max(|x| -x * x, f64::NAN, 1.0, 1e-12).is_err());
+        assert!(checked_golden_section_max(|x| -x * x, -1.0, 1.0, -1.0).is_err());
+        assert!(checked_golden_section_max(|x| -x * x, -1.0, 1.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_golden_section_max_bad_interval() {
+        golden_section_max(|x| -x * x, 1.0, -1.0, 1e-12);
+    }
+}
@@ -48,6 +48,7 @@ pub mod euclid;
 pub mod function;
 pub mod generate;
 pub mod prec;
+pub mod rng;
 pub mod statistics;
 
 mod error;
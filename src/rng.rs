@@ -0,0 +1,124 @@
+//! Provides a seedable random number generator for reproducible sampling
+//! without depending on `rand` directly
+
+use rand::{Error, RngCore};
+
+pub use rand::distributions::Distribution;
+pub use rand::Rng;
+
+/// Returns a random number generator whose output is fully determined by
+/// `seed`
+///
+/// # Remarks
+///
+/// The generator is xoshiro256** with its state initialized from four
+/// successive outputs of SplitMix64 started at `seed`, as recommended by the
+/// authors of xoshiro. Unlike the generators of `rand` its sequence is fixed
+/// and does not change between versions, but it is not cryptographically
+/// secure.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::rng::{self, Distribution};
+///
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let mut r = rng::seeded(42);
+/// let x = n.sample(&mut r);
+/// assert_eq!(x, n.sample(&mut rng::seeded(42)));
+/// ```
+pub fn seeded(seed: u64) -> impl Rng {
+    let mut split_mix = seed;
+    let mut next = || {
+        split_mix = split_mix.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = split_mix;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    Xoshiro256StarStar {
+        s: [next(), next(), next(), next()],
+    }
+}
+
+/// The xoshiro256** generator of Blackman and Vigna
+#[derive(Debug, Clone)]
+struct Xoshiro256StarStar {
+    s: [u64; 4],
+}
+
+impl RngCore for Xoshiro256StarStar {
+    fn next_u32(&mut self) -> u32 {
+        // the upper bits are of the better quality
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use distribution::{Exponential, Normal};
+    use rand::RngCore;
+    use super::{seeded, Distribution};
+
+    #[test]
+    fn test_reference_sequence() {
+        let mut r = seeded(0);
+        assert_eq!(r.next_u64(), 0x99ec5f36cb75f2b4);
+        assert_eq!(r.next_u64(), 0xbf6e1f784956452a);
+        assert_eq!(r.next_u64(), 0x1a5f849d4933e6e0);
+        let mut r = seeded(42);
+        assert_eq!(r.next_u64(), 0x15780b2e0c2ec716);
+        assert_eq!(r.next_u32(), 0x6104d986);
+    }
+
+    #[test]
+    fn test_fill_bytes() {
+        let mut bytes = [0u8; 11];
+        seeded(0).fill_bytes(&mut bytes);
+        assert_eq!(bytes, [0xb4, 0xf2, 0x75, 0xcb, 0x36, 0x5f, 0xec, 0x99, 0x2a, 0x45, 0x56]);
+    }
+
+    #[test]
+    fn test_same_seed_same_samples() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let e = Exponential::new(0.5).unwrap();
+        let mut r1 = seeded(7);
+        let mut r2 = seeded(7);
+        let x1: Vec<f64> = (0..100).map(|_| n.sample(&mut r1)).collect();
+        let x2: Vec<f64> = (0..100).map(|_| n.sample(&mut r2)).collect();
+        assert_eq!(x1, x2);
+        let y1: Vec<f64> = (0..100).map(|_| e.sample(&mut r1)).collect();
+        let y2: Vec<f64> = (0..100).map(|_| e.sample(&mut r2)).collect();
+        assert_eq!(y1, y2);
+        let mut r3 = seeded(8);
+        let z: Vec<f64> = (0..100).map(|_| n.sample(&mut r3)).collect();
+        assert!(z.iter().zip(x1.iter()).all(|(a, b)| a != b));
+        assert!(z.windows(2).all(|w| w[0] != w[1]));
+    }
+}
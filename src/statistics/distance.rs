@@ -0,0 +1,152 @@
+//! Provides distances between empirical and theoretical distributions
+
+use distribution::{Discrete, Univariate};
+use {Result, StatsError};
+
+/// Returns the total variation distance between the empirical distribution
+/// of `total` observations, of which `observed_counts[k]` took the value
+/// `k`, and the discrete distribution `dist`
+///
+/// # Panics
+///
+/// If `total == 0` or the observed counts do not sum to `total`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Bernoulli;
+/// use statrs::statistics::total_variation;
+///
+/// let n = Bernoulli::new(0.5).unwrap();
+/// assert_eq!(total_variation(&[5, 5], &n, 10), 0.0);
+/// assert_eq!(total_variation(&[10], &n, 10), 0.5);
+/// ```
+pub fn total_variation<D>(observed_counts: &[u64], dist: &D, total: u64) -> f64
+where
+    D: Discrete<u64, f64> + Univariate<u64, f64>,
+{
+    checked_total_variation(observed_counts, dist, total).unwrap()
+}
+
+/// Returns the total variation distance between the empirical distribution
+/// of `total` observations, of which `observed_counts[k]` took the value
+/// `k`, and the discrete distribution `dist`
+///
+/// # Remarks
+///
+/// The probability mass of `dist` at values beyond the end of
+/// `observed_counts` is compared against an observed proportion of zero,
+/// and observed counts outside the support of `dist` are compared against
+/// a probability of zero. The distance lies in `[0, 1]`.
+///
+/// # Formula
+///
+/// ```ignore
+/// (1 / 2) * Σ |O_k / n - p_k|
+/// ```
+///
+/// where `O_k` is the observed count of `k`, `n` is `total`, `p_k` is the
+/// probability mass of `dist` at `k` and the sum runs over all `k >= 0`
+///
+/// # Errors
+///
+/// If `total == 0` or the observed counts do not sum to `total`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Bernoulli;
+/// use statrs::statistics::checked_total_variation;
+///
+/// let n = Bernoulli::new(0.5).unwrap();
+/// assert_eq!(checked_total_variation(&[5, 5], &n, 10).unwrap(), 0.0);
+/// assert!(checked_total_variation(&[5, 4], &n, 10).is_err());
+/// assert!(checked_total_variation(&[], &n, 0).is_err());
+/// ```
+pub fn checked_total_variation<D>(observed_counts: &[u64], dist: &D, total: u64) -> Result<f64>
+where
+    D: Discrete<u64, f64> + Univariate<u64, f64>,
+{
+    if total == 0 {
+        return Err(StatsError::ArgMustBePositive("total"));
+    }
+    let sum = observed_counts
+        .iter()
+        .try_fold(0u64, |acc, &count| acc.checked_add(count));
+    if sum != Some(total) {
+        return Err(StatsError::SpecialCase("observed counts must sum to total"));
+    }
+
+    let n = total as f64;
+    let (min, max) = (dist.min(), dist.max());
+    let mut sum = 0.0;
+    let mut covered = 0.0;
+    for (k, &count) in observed_counts.iter().enumerate() {
+        let k = k as u64;
+        let p = if k < min || k > max { 0.0 } else { dist.pmf(k) };
+        covered += p;
+        sum += (count as f64 / n - p).abs();
+    }
+    // mass of the distribution beyond the observed values, where the
+    // observed proportion is zero
+    let tail = (1.0 - covered).max(0.0);
+    Ok(0.5 * (sum + tail))
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use distribution::{Binomial, Discrete, Geometric, Poisson};
+    use super::{checked_total_variation, total_variation};
+
+    #[test]
+    fn test_total_variation_identical() {
+        // counts exactly proportional to the pmf
+        let n = Binomial::new(0.5, 4).unwrap();
+        assert_almost_eq!(total_variation(&[1, 4, 6, 4, 1], &n, 16), 0.0, 1e-15);
+        let counts: Vec<u64> = (0..5).map(|k| (n.pmf(k) * 1600.0).round() as u64).collect();
+        assert_almost_eq!(total_variation(&counts, &n, 1600), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn test_total_variation_disjoint() {
+        // all observations far in the tail
+        let n = Poisson::new(1.0).unwrap();
+        let mut counts = vec![0; 60];
+        counts[59] = 100;
+        assert_almost_eq!(total_variation(&counts, &n, 100), 1.0, 1e-12);
+        // observations outside the support of the distribution
+        let g = Geometric::new(0.5).unwrap();
+        assert_almost_eq!(total_variation(&[100], &g, 100), 1.0, 1e-15);
+        let b = Binomial::new(0.5, 2).unwrap();
+        assert_almost_eq!(total_variation(&[0, 0, 0, 0, 100], &b, 100), 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_total_variation_tail() {
+        // the unobserved mass of the distribution counts towards the distance
+        let n = Binomial::new(0.5, 2).unwrap();
+        // 0.5 * (|0.25 - 0.25| + |0.75 - 0.5| + 0.25)
+        assert_almost_eq!(total_variation(&[1, 3], &n, 4), 0.25, 1e-15);
+        // 0.5 * (|0.5 - 0.25| + |0.5 - 0.5| + 0.25)
+        assert_almost_eq!(total_variation(&[5, 5], &n, 10), 0.25, 1e-15);
+    }
+
+    #[test]
+    fn test_checked_total_variation_bad_counts() {
+        let n = Binomial::new(0.5, 2).unwrap();
+        assert!(checked_total_variation(&[], &n, 0).is_err());
+        assert!(checked_total_variation(&[0, 0], &n, 0).is_err());
+        assert!(checked_total_variation(&[], &n, 4).is_err());
+        assert!(checked_total_variation(&[1, 2], &n, 4).is_err());
+        assert!(checked_total_variation(&[3, 2], &n, 4).is_err());
+        assert!(checked_total_variation(&[u64::MAX, 2], &n, 1).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_total_variation_zero_total() {
+        let n = Binomial::new(0.5, 2).unwrap();
+        total_variation(&[0, 0], &n, 0);
+    }
+}
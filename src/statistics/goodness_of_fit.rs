@@ -0,0 +1,374 @@
+//! Provides goodness-of-fit tests of samples against distributions
+
+use distribution::Univariate;
+use function::gamma;
+use std::cmp::Ordering;
+use std::f64;
+use {Result, StatsError};
+
+/// The outcome of a statistical hypothesis test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TestResult {
+    /// The value of the test statistic
+    pub statistic: f64,
+    /// The probability under the null hypothesis of a test statistic at
+    /// least as extreme as the observed one
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// Returns whether the null hypothesis is rejected at the significance
+    /// level `alpha`, i.e. whether `p_value < alpha`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::TestResult;
+    ///
+    /// let result = TestResult { statistic: 2.5, p_value: 0.03 };
+    /// assert!(result.reject(0.05));
+    /// assert!(!result.reject(0.01));
+    /// ```
+    pub fn reject(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+/// The `GoodnessOfFit` trait specifies a statistical test of the null
+/// hypothesis that samples were drawn from a distribution `D`. The type
+/// parameter `T` is the domain type of the distribution's `Univariate`
+/// implementation and only needs to be named for discrete distributions.
+pub trait GoodnessOfFit<D, T = f64> {
+    /// Tests whether `samples` are consistent with the distribution `dist`
+    ///
+    /// # Errors
+    ///
+    /// Depending on the test, e.g. if there are too few samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Normal;
+    /// use statrs::statistics::{GoodnessOfFit, KolmogorovSmirnov, TestResult};
+    ///
+    /// fn fits<G: GoodnessOfFit<Normal>>(test: &G, samples: &[f64]) -> bool {
+    ///     let n = Normal::new(0.0, 1.0).unwrap();
+    ///     !test.test(samples, &n).unwrap().reject(0.05)
+    /// }
+    ///
+    /// assert!(fits(&KolmogorovSmirnov, &[-1.2, -0.4, 0.1, 0.3, 1.5]));
+    /// ```
+    fn test(&self, samples: &[f64], dist: &D) -> Result<TestResult>;
+}
+
+/// The one-sample Kolmogorov-Smirnov test of a continuous distribution
+///
+/// # Remarks
+///
+/// The statistic is the largest absolute difference between the empirical
+/// cdf of the samples and the cdf of the distribution. The p-value uses the
+/// asymptotic Kolmogorov distribution with Stephens' small sample
+/// correction, which is accurate to about two digits for as few as five
+/// samples and whenever the p-value is below `0.2`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KolmogorovSmirnov;
+
+impl<D> GoodnessOfFit<D> for KolmogorovSmirnov
+where
+    D: Univariate<f64, f64>,
+{
+    /// Performs the Kolmogorov-Smirnov test of `samples` against `dist`
+    ///
+    /// # Errors
+    ///
+    /// If `samples` is empty or contains `NaN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Uniform;
+    /// use statrs::statistics::{GoodnessOfFit, KolmogorovSmirnov};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// let result = KolmogorovSmirnov.test(&[0.1, 0.2, 0.3], &n).unwrap();
+    /// assert_eq!(result.statistic, 0.7);
+    /// ```
+    fn test(&self, samples: &[f64], dist: &D) -> Result<TestResult> {
+        if samples.is_empty() {
+            return Err(StatsError::ArgMustBePositive("samples.len()"));
+        }
+        if samples.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let n = sorted.len() as f64;
+        let statistic = sorted.iter().enumerate().fold(0.0, |acc: f64, (i, &x)| {
+            let cdf = dist.cdf(x);
+            let above = (i + 1) as f64 / n - cdf;
+            let below = cdf - i as f64 / n;
+            acc.max(above).max(below)
+        });
+        let sqrt_n = n.sqrt();
+        let lambda = (sqrt_n + 0.12 + 0.11 / sqrt_n) * statistic;
+        Ok(TestResult {
+            statistic: statistic,
+            p_value: kolmogorov_sf(lambda),
+        })
+    }
+}
+
+/// Returns the survival function of the Kolmogorov distribution at `x`,
+/// using the series in `exp(-2k^2x^2)` for large `x` and its Jacobi theta
+/// transformation for small `x` where the former converges slowly
+fn kolmogorov_sf(x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < 1.18 {
+        let c = f64::consts::PI * f64::consts::PI / (8.0 * x * x);
+        let cdf = (1..20)
+            .map(|k| {
+                let j = (2 * k - 1) as f64;
+                (-j * j * c).exp()
+            })
+            .sum::<f64>()
+            * (2.0 * f64::consts::PI).sqrt()
+            / x;
+        1.0 - cdf
+    } else {
+        let sf = (1..20)
+            .map(|k| {
+                let k = k as f64;
+                let sign = if k % 2.0 == 1.0 { 1.0 } else { -1.0 };
+                sign * (-2.0 * k * k * x * x).exp()
+            })
+            .sum::<f64>();
+        (2.0 * sf).clamp(0.0, 1.0)
+    }
+}
+
+/// Pearson's chi-squared goodness-of-fit test over bins bounded by a
+/// sorted list of edges
+///
+/// # Remarks
+///
+/// The edges `e_1 < ... < e_m` define the `m + 1` bins `(-INF, e_1]`,
+/// `(e_1, e_2]`, ..., `(e_m, INF)`, whose expected probabilities are
+/// computed from the cdf of the distribution. For a discrete distribution
+/// on the integers the edges `0, 1, ..., k - 1` give one bin per value
+/// below `k` and one bin for all values from `k` onwards. The statistic is
+/// compared to a chi-squared distribution with one degree of freedom less
+/// than the number of bins with a positive expected probability. The
+/// approximation requires an expected count of at least about `5` in
+/// every bin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChiSquaredTest {
+    edges: Vec<f64>,
+}
+
+impl ChiSquaredTest {
+    /// Constructs a new chi-squared goodness-of-fit test over the bins
+    /// bounded by `edges`
+    ///
+    /// # Errors
+    ///
+    /// If `edges` is empty, contains `NaN` or is not strictly increasing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::ChiSquaredTest;
+    ///
+    /// assert!(ChiSquaredTest::new(&[0.0, 1.0, 2.0]).is_ok());
+    /// assert!(ChiSquaredTest::new(&[1.0, 0.0]).is_err());
+    /// ```
+    pub fn new(edges: &[f64]) -> Result<ChiSquaredTest> {
+        if edges.is_empty()
+            || edges.iter().any(|x| x.is_nan())
+            || edges.windows(2).any(|w| w[0] >= w[1])
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(ChiSquaredTest {
+                edges: edges.to_vec(),
+            })
+        }
+    }
+}
+
+impl<D, T> GoodnessOfFit<D, T> for ChiSquaredTest
+where
+    D: Univariate<T, f64>,
+{
+    /// Performs Pearson's chi-squared test of `samples` against `dist`
+    ///
+    /// # Errors
+    ///
+    /// If `samples` is empty or contains `NaN`, or if fewer than two bins
+    /// have a positive expected probability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Binomial;
+    /// use statrs::statistics::{ChiSquaredTest, GoodnessOfFit};
+    ///
+    /// let n = Binomial::new(0.5, 2).unwrap();
+    /// let test = ChiSquaredTest::new(&[0.0, 1.0]).unwrap();
+    /// let result = test.test(&[0.0, 1.0, 1.0, 2.0], &n).unwrap();
+    /// assert!(result.statistic < 1e-12);
+    /// assert!(!result.reject(0.05));
+    /// ```
+    fn test(&self, samples: &[f64], dist: &D) -> Result<TestResult> {
+        if samples.is_empty() {
+            return Err(StatsError::ArgMustBePositive("samples.len()"));
+        }
+        if samples.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+
+        let bins = self.edges.len() + 1;
+        let mut observed = vec![0.0; bins];
+        for &x in samples {
+            // index of the first edge not below x
+            let i = self.edges.iter().take_while(|&&e| e < x).count();
+            observed[i] += 1.0;
+        }
+
+        let n = samples.len() as f64;
+        let mut statistic = 0.0;
+        let mut dof = 0.0;
+        let mut prev = 0.0;
+        for (i, &o) in observed.iter().enumerate() {
+            let next = if i < self.edges.len() {
+                dist.cdf(self.edges[i])
+            } else {
+                1.0
+            };
+            let expected = (next - prev) * n;
+            prev = next;
+            if expected > 0.0 {
+                statistic += (o - expected) * (o - expected) / expected;
+                dof += 1.0;
+            } else if o > 0.0 {
+                statistic = f64::INFINITY;
+            }
+        }
+        if dof < 2.0 {
+            return Err(StatsError::SpecialCase(
+                "At least two bins must have a positive expected probability",
+            ));
+        }
+        dof -= 1.0;
+        let p_value = if statistic == f64::INFINITY {
+            0.0
+        } else if statistic == 0.0 {
+            1.0
+        } else {
+            gamma::gamma_ur(dof / 2.0, statistic / 2.0)
+        };
+        Ok(TestResult {
+            statistic: statistic,
+            p_value: p_value,
+        })
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Binomial, Exponential, Normal, Poisson, Uniform};
+    use rng;
+
+    fn samples<D: Distribution<f64>>(dist: &D, count: usize) -> Vec<f64> {
+        let mut r = rng::seeded(0);
+        (0..count).map(|_| dist.sample(&mut r)).collect()
+    }
+
+    #[test]
+    fn test_reject() {
+        let result = TestResult { statistic: 1.0, p_value: 0.04 };
+        assert!(result.reject(0.05));
+        assert!(!result.reject(0.04));
+        assert!(!result.reject(0.01));
+        let result = TestResult { statistic: 1.0, p_value: 0.5 };
+        assert!(!result.reject(0.05));
+        assert!(result.reject(0.6));
+    }
+
+    #[test]
+    fn test_kolmogorov_sf() {
+        // reference values of the Kolmogorov distribution
+        assert_almost_eq!(super::kolmogorov_sf(0.5), 0.9639452436648751, 1e-12);
+        assert_almost_eq!(super::kolmogorov_sf(1.0), 0.26999967167735456, 1e-12);
+        assert_almost_eq!(super::kolmogorov_sf(1.18), 0.1234538094297657, 1e-12);
+        assert_almost_eq!(super::kolmogorov_sf(1.36), 0.04948587675537788, 1e-12);
+        assert_almost_eq!(super::kolmogorov_sf(2.0), 0.0006709252557796953, 1e-12);
+        assert_eq!(super::kolmogorov_sf(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_kolmogorov_smirnov() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let result = KolmogorovSmirnov.test(&samples(&n, 1000), &n).unwrap();
+        assert!(result.statistic < 0.05);
+        assert!(!result.reject(0.05));
+
+        let result = KolmogorovSmirnov.test(&samples(&n, 1000), &Normal::new(1.5, 2.0).unwrap()).unwrap();
+        assert!(result.reject(0.001));
+        let result = KolmogorovSmirnov.test(&samples(&Exponential::new(1.0).unwrap(), 1000), &Normal::new(1.0, 1.0).unwrap()).unwrap();
+        assert!(result.reject(0.001));
+    }
+
+    #[test]
+    fn test_kolmogorov_smirnov_statistic() {
+        let n = Uniform::new(0.0, 1.0).unwrap();
+        let result = KolmogorovSmirnov.test(&[0.9, 0.5, 0.1], &n).unwrap();
+        assert_almost_eq!(result.statistic, 0.2333333333333333, 1e-15);
+        assert!(KolmogorovSmirnov.test(&[], &n).is_err());
+        assert!(KolmogorovSmirnov.test(&[0.5, f64::NAN], &n).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared() {
+        let n = Poisson::new(4.0).unwrap();
+        let test = ChiSquaredTest::new(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+        let result = test.test(&samples(&n, 5000), &n).unwrap();
+        assert!(!result.reject(0.05));
+        let result = test.test(&samples(&n, 5000), &Poisson::new(4.3).unwrap()).unwrap();
+        assert!(result.reject(0.001));
+    }
+
+    #[test]
+    fn test_chi_squared_statistic() {
+        // 10 samples of Binomial(2, 0.5) with expected counts 2.5, 5 and 2.5
+        let n = Binomial::new(0.5, 2).unwrap();
+        let test = ChiSquaredTest::new(&[0.0, 1.0]).unwrap();
+        let data = [0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0];
+        let result = test.test(&data, &n).unwrap();
+        let statistic = 1.5 * 1.5 / 2.5 + 1.0 / 5.0 + 0.5 * 0.5 / 2.5;
+        assert_almost_eq!(result.statistic, statistic, 1e-12);
+        assert_almost_eq!(result.p_value, (-statistic / 2.0).exp(), 1e-12);
+        // a sample outside of the support can not come from the distribution
+        let test = ChiSquaredTest::new(&[-1.0, 0.0, 1.0]).unwrap();
+        let result = test.test(&[0.0, 1.0, -1.5], &n).unwrap();
+        assert_eq!(result.statistic, f64::INFINITY);
+        assert_eq!(result.p_value, 0.0);
+    }
+
+    #[test]
+    fn test_chi_squared_bad_input() {
+        assert!(ChiSquaredTest::new(&[]).is_err());
+        assert!(ChiSquaredTest::new(&[0.0, 0.0]).is_err());
+        assert!(ChiSquaredTest::new(&[0.0, f64::NAN]).is_err());
+        let n = Binomial::new(0.5, 2).unwrap();
+        let test = ChiSquaredTest::new(&[5.0]).unwrap();
+        assert!(test.test(&[1.0], &n).is_err());
+        let test = ChiSquaredTest::new(&[0.0]).unwrap();
+        assert!(test.test(&[], &n).is_err());
+        assert!(test.test(&[f64::NAN], &n).is_err());
+    }
+}
@@ -0,0 +1,338 @@
+//! Provides confidence intervals for estimated proportions and rates
+
+use distribution::{Beta, ChiSquared, InverseCDF, Normal};
+use {Result, StatsError};
+
+/// Computes the Clopper-Pearson exact confidence interval for the success
+/// probability of a binomial distribution given `successes` out of `trials`
+/// at the confidence `level`
+///
+/// # Panics
+///
+/// If `trials == 0`, `successes > trials` or `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::statistics::clopper_pearson;
+///
+/// # fn main() {
+/// let (lower, upper) = clopper_pearson(3, 10, 0.95);
+/// assert_almost_eq!(lower, 0.06673951117773447, 1e-10);
+/// assert_almost_eq!(upper, 0.6524528500599972, 1e-10);
+/// # }
+/// ```
+pub fn clopper_pearson(successes: u64, trials: u64, level: f64) -> (f64, f64) {
+    checked_clopper_pearson(successes, trials, level).unwrap()
+}
+
+/// Computes the Clopper-Pearson exact confidence interval for the success
+/// probability of a binomial distribution given `successes` out of `trials`
+/// at the confidence `level`
+///
+/// # Remarks
+///
+/// The bounds are the `(1 - level) / 2` quantile of `Beta(x, n - x + 1)` and
+/// the `(1 + level) / 2` quantile of `Beta(x + 1, n - x)` where `x` is the
+/// number of successes and `n` the number of trials. The lower bound is `0`
+/// when `x == 0` and the upper bound is `1` when `x == n`.
+///
+/// # Errors
+///
+/// If `trials == 0`, `successes > trials` or `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::checked_clopper_pearson;
+///
+/// assert_eq!(checked_clopper_pearson(0, 10, 0.95).unwrap().0, 0.0);
+/// assert!(checked_clopper_pearson(11, 10, 0.95).is_err());
+/// ```
+pub fn checked_clopper_pearson(successes: u64, trials: u64, level: f64) -> Result<(f64, f64)> {
+    check_proportion_args(successes, trials, level)?;
+    let alpha = 1.0 - level;
+    let x = successes as f64;
+    let n = trials as f64;
+    let lower = if successes == 0 {
+        0.0
+    } else {
+        Beta::new(x, n - x + 1.0)?.inverse_cdf(alpha / 2.0)
+    };
+    let upper = if successes == trials {
+        1.0
+    } else {
+        Beta::new(x + 1.0, n - x)?.inverse_cdf(1.0 - alpha / 2.0)
+    };
+    Ok((lower, upper))
+}
+
+/// Computes the Wilson score confidence interval for the success probability
+/// of a binomial distribution given `successes` out of `trials` at the
+/// confidence `level`
+///
+/// # Panics
+///
+/// If `trials == 0`, `successes > trials` or `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::statistics::wilson_score;
+///
+/// # fn main() {
+/// let (lower, upper) = wilson_score(3, 10, 0.95);
+/// assert_almost_eq!(lower, 0.10779126740630104, 1e-12);
+/// assert_almost_eq!(upper, 0.6032218525388546, 1e-12);
+/// # }
+/// ```
+pub fn wilson_score(successes: u64, trials: u64, level: f64) -> (f64, f64) {
+    checked_wilson_score(successes, trials, level).unwrap()
+}
+
+/// Computes the Wilson score confidence interval for the success probability
+/// of a binomial distribution given `successes` out of `trials` at the
+/// confidence `level`
+///
+/// # Remarks
+///
+/// The interval is centred on the adjusted proportion
+/// `(p + z^2 / 2n) / (1 + z^2 / n)` rather than the observed proportion `p`,
+/// where `z` is the `(1 + level) / 2` quantile of the standard normal
+/// distribution and `n` the number of trials. The bounds always lie within
+/// `[0, 1]`, the lower bound is `0` when `successes == 0` and the upper bound
+/// is `1` when `successes == trials`.
+///
+/// # Formula
+///
+/// ```ignore
+/// (p + z^2 / 2n ± z * sqrt(p(1 - p) / n + z^2 / 4n^2)) / (1 + z^2 / n)
+/// ```
+///
+/// # Errors
+///
+/// If `trials == 0`, `successes > trials` or `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::checked_wilson_score;
+///
+/// assert_eq!(checked_wilson_score(10, 10, 0.95).unwrap().1, 1.0);
+/// assert!(checked_wilson_score(1, 0, 0.95).is_err());
+/// ```
+pub fn checked_wilson_score(successes: u64, trials: u64, level: f64) -> Result<(f64, f64)> {
+    check_proportion_args(successes, trials, level)?;
+    let z = Normal::new(0.0, 1.0)?.inverse_cdf(0.5 + level / 2.0);
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2_n = z * z / n;
+    let center = (p + z2_n / 2.0) / (1.0 + z2_n);
+    let half_width = z / (1.0 + z2_n) * (p * (1.0 - p) / n + z2_n / (4.0 * n)).sqrt();
+    let lower = if successes == 0 {
+        0.0
+    } else {
+        (center - half_width).max(0.0)
+    };
+    let upper = if successes == trials {
+        1.0
+    } else {
+        (center + half_width).min(1.0)
+    };
+    Ok((lower, upper))
+}
+
+/// Computes the exact confidence interval for the mean of a poisson
+/// distribution given an observed `count` at the confidence `level`
+///
+/// # Panics
+///
+/// If `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::statistics::poisson_ci;
+///
+/// # fn main() {
+/// let (lower, upper) = poisson_ci(5, 0.95);
+/// assert_almost_eq!(lower, 1.623486390118421, 1e-9);
+/// assert_almost_eq!(upper, 11.668332079322668, 1e-9);
+/// # }
+/// ```
+pub fn poisson_ci(count: u64, level: f64) -> (f64, f64) {
+    checked_poisson_ci(count, level).unwrap()
+}
+
+/// Computes the exact confidence interval for the mean of a poisson
+/// distribution given an observed `count` at the confidence `level`
+///
+/// # Remarks
+///
+/// The lower bound is `0` when `count == 0`
+///
+/// # Formula
+///
+/// ```ignore
+/// (χ²(α / 2, 2k) / 2, χ²(1 - α / 2, 2k + 2) / 2)
+/// ```
+///
+/// where `χ²(p, v)` is the `p` quantile of the chi-squared distribution with
+/// `v` degrees of freedom, `α = 1 - level` and `k` is the count
+///
+/// # Errors
+///
+/// If `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::checked_poisson_ci;
+///
+/// assert_eq!(checked_poisson_ci(0, 0.95).unwrap().0, 0.0);
+/// assert!(checked_poisson_ci(5, 1.5).is_err());
+/// ```
+pub fn checked_poisson_ci(count: u64, level: f64) -> Result<(f64, f64)> {
+    check_level(level)?;
+    let alpha = 1.0 - level;
+    let k = count as f64;
+    let lower = if count == 0 {
+        0.0
+    } else {
+        ChiSquared::new(2.0 * k)?.inverse_cdf(alpha / 2.0) / 2.0
+    };
+    let upper = ChiSquared::new(2.0 * k + 2.0)?.inverse_cdf(1.0 - alpha / 2.0) / 2.0;
+    Ok((lower, upper))
+}
+
+fn check_proportion_args(successes: u64, trials: u64, level: f64) -> Result<()> {
+    if trials == 0 {
+        Err(StatsError::ArgMustBePositive("trials"))
+    } else if successes > trials {
+        Err(StatsError::ArgLteArg("successes", "trials"))
+    } else {
+        check_level(level)
+    }
+}
+
+fn check_level(level: f64) -> Result<()> {
+    if level.is_nan() || level <= 0.0 || level >= 1.0 {
+        Err(StatsError::ArgIntervalExcl("level", 0.0, 1.0))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use super::*;
+
+    #[test]
+    fn test_clopper_pearson() {
+        // reference values agree with R's binom.test
+        let (lower, upper) = clopper_pearson(3, 10, 0.95);
+        assert_almost_eq!(lower, 0.06673951117773447, 1e-10);
+        assert_almost_eq!(upper, 0.6524528500599972, 1e-10);
+        let (lower, upper) = clopper_pearson(50, 100, 0.99);
+        assert_almost_eq!(lower, 0.368861437358924, 1e-10);
+        assert_almost_eq!(upper, 0.631138562641076, 1e-10);
+    }
+
+    #[test]
+    fn test_clopper_pearson_edge_cases() {
+        let (lower, upper) = clopper_pearson(0, 10, 0.95);
+        assert_eq!(lower, 0.0);
+        // (alpha / 2)^(1 / n) is the closed form upper bound when x == 0
+        assert_almost_eq!(upper, 1.0 - 0.025f64.powf(0.1), 1e-12);
+        let (lower, upper) = clopper_pearson(10, 10, 0.95);
+        assert_almost_eq!(lower, 0.025f64.powf(0.1), 1e-12);
+        assert_eq!(upper, 1.0);
+    }
+
+    #[test]
+    fn test_checked_clopper_pearson_bad_args() {
+        assert!(checked_clopper_pearson(0, 0, 0.95).is_err());
+        assert!(checked_clopper_pearson(11, 10, 0.95).is_err());
+        assert!(checked_clopper_pearson(3, 10, 0.0).is_err());
+        assert!(checked_clopper_pearson(3, 10, 1.0).is_err());
+        assert!(checked_clopper_pearson(3, 10, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_wilson_score() {
+        let (lower, upper) = wilson_score(3, 10, 0.95);
+        assert_almost_eq!(lower, 0.10779126740630104, 1e-12);
+        assert_almost_eq!(upper, 0.6032218525388546, 1e-12);
+        let (lower, upper) = wilson_score(81, 263, 0.95);
+        assert_almost_eq!(lower, 0.2552885198782742, 1e-12);
+        assert_almost_eq!(upper, 0.36620957698280006, 1e-12);
+    }
+
+    #[test]
+    fn test_wilson_score_midpoint() {
+        // the interval is centred on the adjusted, not the raw, proportion
+        let (lower, upper) = wilson_score(3, 10, 0.95);
+        assert_almost_eq!((lower + upper) / 2.0, 0.35550655997257783, 1e-12);
+        assert!(((lower + upper) / 2.0 - 0.3).abs() > 0.05);
+    }
+
+    #[test]
+    fn test_wilson_score_edge_cases() {
+        let (lower, upper) = wilson_score(0, 10, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_almost_eq!(upper, 0.2775327998628892, 1e-12);
+        let (lower, upper) = wilson_score(10, 10, 0.95);
+        assert_almost_eq!(lower, 0.7224672001371108, 1e-12);
+        assert_eq!(upper, 1.0);
+        let (lower, upper) = wilson_score(1, 1, 0.999999);
+        assert!(lower >= 0.0 && upper <= 1.0);
+    }
+
+    #[test]
+    fn test_checked_wilson_score_bad_args() {
+        assert!(checked_wilson_score(0, 0, 0.95).is_err());
+        assert!(checked_wilson_score(11, 10, 0.95).is_err());
+        assert!(checked_wilson_score(3, 10, 0.0).is_err());
+        assert!(checked_wilson_score(3, 10, 1.0).is_err());
+        assert!(checked_wilson_score(3, 10, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_poisson_ci() {
+        let (lower, upper) = poisson_ci(5, 0.95);
+        assert_almost_eq!(lower, 1.623486390118421, 1e-9);
+        assert_almost_eq!(upper, 11.668332079322668, 1e-9);
+        let (lower, upper) = poisson_ci(10, 0.95);
+        assert_almost_eq!(lower, 4.795388696132434, 1e-9);
+        assert_almost_eq!(upper, 18.39035604201778, 1e-9);
+        let (lower, upper) = poisson_ci(1, 0.9);
+        assert_almost_eq!(lower, 0.05129329438755052, 1e-9);
+        assert_almost_eq!(upper, 4.743864518390579, 1e-9);
+    }
+
+    #[test]
+    fn test_poisson_ci_zero_count() {
+        let (lower, upper) = poisson_ci(0, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_almost_eq!(upper, -(0.025f64.ln()), 1e-9);
+    }
+
+    #[test]
+    fn test_checked_poisson_ci_bad_args() {
+        assert!(checked_poisson_ci(5, 0.0).is_err());
+        assert!(checked_poisson_ci(5, 1.0).is_err());
+        assert!(checked_poisson_ci(5, -0.5).is_err());
+        assert!(checked_poisson_ci(5, f64::NAN).is_err());
+    }
+}
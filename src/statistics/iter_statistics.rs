@@ -87,7 +87,12 @@ where
         let mut sum = 0.0;
         for x in self {
             i += 1.0;
-            sum += x.borrow().ln();
+
+            let borrow = *x.borrow();
+            if borrow <= 0f64 {
+                return f64::NAN;
+            }
+            sum += borrow.ln();
         }
         if i > 0.0 {
             (sum / i).exp()
@@ -103,7 +108,7 @@ where
             i += 1.0;
 
             let borrow = *x.borrow();
-            if borrow < 0f64 {
+            if borrow <= 0f64 {
                 return f64::NAN;
             }
             sum += 1.0 / borrow;
@@ -238,11 +243,73 @@ where
             f64::NAN
         }
     }
+
+    fn autocorrelation(self, max_lag: usize) -> Vec<f64> {
+        let data: Vec<f64> = self.into_iter().map(|x| *x.borrow()).collect();
+        let mean = (&data).mean();
+        let deviations: Vec<f64> = data.iter().map(|x| x - mean).collect();
+        let variance: f64 = deviations.iter().map(|d| d * d).sum();
+        if variance == 0.0 || !variance.is_finite() {
+            return vec![f64::NAN; max_lag + 1];
+        }
+
+        let mut acf = Vec::with_capacity(max_lag + 1);
+        acf.push(1.0);
+        for lag in 1..max_lag + 1 {
+            let covariance: f64 = deviations
+                .iter()
+                .zip(deviations.iter().skip(lag))
+                .map(|(a, b)| a * b)
+                .sum();
+            acf.push(covariance / variance);
+        }
+        acf
+    }
+
+    fn effective_sample_size(self) -> f64 {
+        let data: Vec<f64> = self.into_iter().map(|x| *x.borrow()).collect();
+        let n = data.len();
+        if n < 2 {
+            return f64::NAN;
+        }
+        let mean = (&data).mean();
+        let deviations: Vec<f64> = data.iter().map(|x| x - mean).collect();
+        let variance: f64 = deviations.iter().map(|d| d * d).sum();
+        if variance == 0.0 || !variance.is_finite() {
+            return f64::NAN;
+        }
+        let rho = |lag: usize| -> f64 {
+            deviations
+                .iter()
+                .zip(deviations.iter().skip(lag))
+                .map(|(a, b)| a * b)
+                .sum::<f64>()
+                / variance
+        };
+
+        // Geyer's initial monotone sequence estimator
+        let mut sum = 0.0;
+        let mut prev = f64::INFINITY;
+        let mut lag = 0;
+        while lag + 1 < n {
+            let pair = (rho(lag) + rho(lag + 1)).min(prev);
+            if pair <= 0.0 {
+                break;
+            }
+            sum += pair;
+            prev = pair;
+            lag += 2;
+        }
+        let n = n as f64;
+        let tau = (2.0 * sum - 1.0).max(1.0 / n.log10());
+        n / tau
+    }
 }
 
 #[cfg_attr(rustfmt, rustfmt_skip)]
 #[cfg(test)]
 mod test {
+    use std::f64;
     use std::f64::consts;
     use rand::rngs::StdRng;
     use rand::{SeedableRng};
@@ -251,6 +318,7 @@ mod test {
     use statistics::Statistics;
     use generate::{InfinitePeriodic, InfiniteSinusoidal};
     use testing;
+    use rng;
 
     #[test]
     fn test_mean() {
@@ -313,6 +381,110 @@ mod test {
         assert_eq!(data.max(), 10.0);
     }
 
+    #[test]
+    fn test_geometric_harmonic_mean() {
+        let data = [2.0, 8.0];
+        assert_almost_eq!(data.geometric_mean(), 4.0, 1e-15);
+        assert_almost_eq!(data.harmonic_mean(), 3.2, 1e-15);
+        let data = [1.0, 3.0, 9.0, 27.0, 81.0];
+        assert_almost_eq!(data.geometric_mean(), 9.0, 1e-14);
+        assert_almost_eq!(data.harmonic_mean(), 5.0 / (121.0 / 81.0), 1e-14);
+        assert!([1.0, 0.0, 2.0].geometric_mean().is_nan());
+        assert!([1.0, -1.0, 2.0].geometric_mean().is_nan());
+        assert!([1.0, 0.0, 2.0].harmonic_mean().is_nan());
+        assert!([1.0, -1.0, 2.0].harmonic_mean().is_nan());
+    }
+
+    #[test]
+    fn test_mean_inequality() {
+        let mut rng = rng::seeded(0);
+        let normal = Normal::new(10.0, 3.0).unwrap();
+        for _ in 0..20 {
+            let samples = (0..50).map(|_| normal.sample(&mut rng).abs() + 1e-3).collect::<Vec<f64>>();
+            let hm = (&samples).harmonic_mean();
+            let gm = (&samples).geometric_mean();
+            let am = (&samples).mean();
+            assert!(hm <= gm && gm <= am);
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation_white_noise() {
+        let mut rng = rng::seeded(0);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let samples = (0..10000).map(|_| normal.sample(&mut rng)).collect::<Vec<f64>>();
+        let acf = (&samples).autocorrelation(10);
+        assert_eq!(acf.len(), 11);
+        assert_eq!(acf[0], 1.0);
+        for r in &acf[1..] {
+            // roughly four standard errors of 1 / sqrt(n)
+            assert!(r.abs() < 0.04);
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation_ar1() {
+        let mut rng = rng::seeded(0);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let phi = 0.9;
+        let mut x = 0.0;
+        let samples = (0..20000).map(|_| {
+            x = phi * x + normal.sample(&mut rng);
+            x
+        }).collect::<Vec<f64>>();
+        let acf = (&samples).autocorrelation(5);
+        assert_eq!(acf[0], 1.0);
+        for (k, r) in acf.iter().enumerate() {
+            assert_almost_eq!(*r, phi.powi(k as i32), 0.05);
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation_degenerate() {
+        assert!([2.0, 2.0, 2.0].autocorrelation(1).iter().all(|r| r.is_nan()));
+        assert!([1.0, f64::NAN, 2.0].autocorrelation(1).iter().all(|r| r.is_nan()));
+        assert_eq!([1.0, 3.0].autocorrelation(3), [1.0, -0.5, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_effective_sample_size_independent() {
+        let mut rng = rng::seeded(0);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let samples = (0..5000).map(|_| normal.sample(&mut rng)).collect::<Vec<f64>>();
+        let ess = (&samples).effective_sample_size();
+        assert!(ess > 4000.0 && ess < 6000.0);
+    }
+
+    #[test]
+    fn test_effective_sample_size_correlated() {
+        let mut rng = rng::seeded(0);
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let phi = 0.9;
+        let mut x = 0.0;
+        let samples = (0..20000).map(|_| {
+            x = phi * x + normal.sample(&mut rng);
+            x
+        }).collect::<Vec<f64>>();
+        // the integrated autocorrelation time of an AR(1) process is
+        // (1 + phi) / (1 - phi)
+        let expected = 20000.0 * (1.0 - phi) / (1.0 + phi);
+        let ess = (&samples).effective_sample_size();
+        assert!(ess < 2000.0);
+        assert_almost_eq!(ess, expected, 0.3 * expected);
+    }
+
+    #[test]
+    fn test_effective_sample_size_degenerate() {
+        assert!([1.0].effective_sample_size().is_nan());
+        assert!([2.0, 2.0, 2.0].effective_sample_size().is_nan());
+        assert!([1.0, f64::NAN, 2.0].effective_sample_size().is_nan());
+        // perfectly alternating data must not produce an infinite estimate
+        let alternating: Vec<f64> = (0..100).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+        let ess = (&alternating).effective_sample_size();
+        assert!(ess.is_finite());
+        assert!(ess <= 100.0 * 2.0);
+    }
+
     #[test]
     fn test_mean_variance_stability() {
         let seed = [
@@ -1,12 +1,26 @@
 //! Provides traits for statistical computation
 
+pub use self::distance::*;
+pub use self::goodness_of_fit::*;
+pub use self::interval::*;
 pub use self::iter_statistics::*;
 pub use self::order_statistics::*;
+pub use self::pit::*;
+pub use self::qq::*;
+pub use self::sampling::*;
 pub use self::statistics::*;
+pub use self::tail_bounds::*;
 pub use self::traits::*;
 
+mod distance;
+mod goodness_of_fit;
+mod interval;
 mod iter_statistics;
 mod order_statistics;
+mod pit;
+mod qq;
+mod sampling;
 mod slice_statistics;
 mod statistics;
+mod tail_bounds;
 mod traits;
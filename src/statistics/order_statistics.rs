@@ -1,5 +1,21 @@
 use super::RankTieBreaker;
 
+/// The five-number summary of a data set, i.e. its minimum, lower quartile,
+/// median, upper quartile and maximum, as used e.g. for box plots
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiveNumberSummary<T> {
+    /// The minimum value
+    pub min: T,
+    /// The lower (first) quartile
+    pub q1: T,
+    /// The median
+    pub median: T,
+    /// The upper (third) quartile
+    pub q3: T,
+    /// The maximum value
+    pub max: T,
+}
+
 /// The `OrderStatistics` trait provides statistical utilities
 /// having to do with ordering. All the algorithms are in-place thus requiring
 /// a mutable borrow.
@@ -175,6 +191,108 @@ pub trait OrderStatistics<T> {
     /// ```
     fn interquartile_range(&mut self) -> T;
 
+    /// Computes the five-number summary of the data, i.e. the minimum,
+    /// lower quartile, median, upper quartile and maximum
+    ///
+    /// # Remarks
+    ///
+    /// The quartiles are estimated with the same method as `quantile`, so
+    /// `q1` and `q3` equal `lower_quartile` and `upper_quartile`. For data
+    /// of length `1` every entry of the summary is the single value, and for
+    /// data of length `2` the quartiles coincide with the minimum and
+    /// maximum. Every entry is `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::OrderStatistics;
+    ///
+    /// let mut x = [5.0, 1.0, 4.0, 2.0, 3.0];
+    /// let summary = x.five_number_summary();
+    /// assert_eq!(summary.min, 1.0);
+    /// assert_eq!(summary.q1, x.lower_quartile());
+    /// assert_eq!(summary.median, 3.0);
+    /// assert_eq!(summary.q3, x.upper_quartile());
+    /// assert_eq!(summary.max, 5.0);
+    /// ```
+    fn five_number_summary(&mut self) -> FiveNumberSummary<T>;
+
+    /// Estimates the median absolute deviation (MAD) from the data, i.e. the
+    /// median of the absolute deviations from the median
+    ///
+    /// # Remarks
+    ///
+    /// The MAD is not scaled. Multiply by `1.4826` to obtain a consistent
+    /// estimator of the standard deviation for normally distributed data.
+    /// Returns `f64::NAN` if data is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::OrderStatistics;
+    ///
+    /// let mut x: [f64; 0] = [];
+    /// assert!(x.median_abs_deviation().is_nan());
+    ///
+    /// let mut y = [1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0];
+    /// assert_eq!(y.median_abs_deviation(), 1.0);
+    /// ```
+    fn median_abs_deviation(&mut self) -> T;
+
+    /// Computes the trimmed mean of the data, i.e. the mean after dropping
+    /// the `proportion` smallest and the `proportion` largest values
+    ///
+    /// # Remarks
+    ///
+    /// `floor(proportion * N)` values are dropped from each tail of the
+    /// sorted data. `proportion` must be in `[0, 0.5)`, a `proportion` of
+    /// `0` gives the ordinary mean.
+    /// Returns `f64::NAN` if data is empty or `proportion` is outside the
+    /// range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::OrderStatistics;
+    ///
+    /// let mut x = [1.0, 2.0, 3.0, 4.0, 100.0];
+    /// assert_eq!(x.trimmed_mean(0.2), 3.0);
+    /// assert!(x.trimmed_mean(0.5).is_nan());
+    /// ```
+    fn trimmed_mean(&mut self, proportion: f64) -> T;
+
+    /// Computes the first `count` sample L-moments of the data from its
+    /// unbiased probability weighted moments
+    ///
+    /// # Remarks
+    ///
+    /// The first two entries are the L-location `λ1`, equal to the sample
+    /// mean, and the L-scale `λ2`. Every further entry `r` is the L-moment
+    /// ratio `τr = λr / λ2`, so the third and fourth entries are the
+    /// L-skewness and L-kurtosis. L-moments exist whenever the mean does and
+    /// are much less sensitive to outliers than conventional moments.
+    /// The `r`th L-moment needs at least `r` data points, entries that
+    /// cannot be estimated are `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::OrderStatistics;
+    ///
+    /// # fn main() {
+    /// let mut x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    /// let l = x.l_moments(4);
+    /// assert_almost_eq!(l[0], 5.5, 1e-14);
+    /// assert_almost_eq!(l[1], 11.0 / 6.0, 1e-14);
+    /// assert_almost_eq!(l[2], 0.0, 1e-14);
+    /// assert_almost_eq!(l[3], 0.0, 1e-14);
+    /// # }
+    /// ```
+    fn l_moments(&mut self, count: usize) -> Vec<T>;
+
     /// Evaluates the rank of each entry of the data.
     ///
     /// # Examples
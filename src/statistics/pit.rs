@@ -0,0 +1,118 @@
+//! Provides the probability integral transform of samples through a
+//! distribution
+
+use distribution::{Uniform, Univariate};
+use statistics::{GoodnessOfFit, KolmogorovSmirnov, TestResult};
+use Result;
+
+/// Returns the probability integral transform of `samples` through the
+/// distribution `dist`, i.e. `dist.cdf(x)` for each sample `x` in order
+///
+/// # Remarks
+///
+/// If the samples were drawn from a continuous `dist` the transformed
+/// values are uniformly distributed on `[0, 1]`. A distribution that is too
+/// narrow piles the values up near `0` and `1`, one that is too wide
+/// concentrates them around `0.5`, and a biased location skews them towards
+/// one end.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::pit;
+///
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let u = pit(&[0.0, -1.0, 2.0], &n);
+/// assert_eq!(u[0], 0.5);
+/// assert!(u[1] < 0.5 && u[2] > 0.5);
+/// ```
+pub fn pit<D: Univariate<f64, f64>>(samples: &[f64], dist: &D) -> Vec<f64> {
+    samples.iter().map(|&x| dist.cdf(x)).collect()
+}
+
+/// Tests whether the probability integral transform of `samples` through
+/// the distribution `dist` is uniform on `[0, 1]`, using the
+/// Kolmogorov-Smirnov test
+///
+/// # Errors
+///
+/// If `samples` is empty or contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::pit_uniformity_test;
+///
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let result = pit_uniformity_test(&[-1.2, -0.4, 0.1, 0.3, 1.5], &n).unwrap();
+/// assert!(!result.reject(0.05));
+/// ```
+pub fn pit_uniformity_test<D: Univariate<f64, f64>>(
+    samples: &[f64],
+    dist: &D,
+) -> Result<TestResult> {
+    let uniform = Uniform::new(0.0, 1.0)?;
+    KolmogorovSmirnov.test(&pit(samples, dist), &uniform)
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Exponential, Gamma, Normal, Univariate};
+    use rng;
+
+    fn samples<D: Distribution<f64>>(dist: &D, count: usize) -> Vec<f64> {
+        let mut r = rng::seeded(0);
+        (0..count).map(|_| dist.sample(&mut r)).collect()
+    }
+
+    #[test]
+    fn test_pit() {
+        let n = Exponential::new(2.0).unwrap();
+        let data = [0.5, -1.0, 3.0, 0.0];
+        let u = pit(&data, &n);
+        assert_eq!(u.len(), 4);
+        for (&x, &p) in data.iter().zip(u.iter()) {
+            assert_eq!(p, n.cdf(x));
+        }
+        assert_eq!(u[1], 0.0);
+        assert!(pit(&[], &n).is_empty());
+    }
+
+    #[test]
+    fn test_pit_calibrated() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let u = pit(&samples(&n, 2000), &n);
+        assert!(u.iter().all(|&x| x >= 0.0 && x <= 1.0));
+        assert_almost_eq!(u.iter().mean(), 0.5, 0.02);
+        assert_almost_eq!(u.iter().variance(), 1.0 / 12.0, 0.005);
+        // a calibrated sample is rejected at level α with probability α, so
+        // test at a small level
+        assert!(!pit_uniformity_test(&samples(&n, 2000), &n).unwrap().reject(0.01));
+
+        let g = Gamma::new(2.0, 3.0).unwrap();
+        assert!(!pit_uniformity_test(&samples(&g, 2000), &g).unwrap().reject(0.01));
+    }
+
+    #[test]
+    fn test_pit_misspecified() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let data = samples(&n, 2000);
+        // too narrow, too wide and shifted
+        assert!(pit_uniformity_test(&data, &Normal::new(1.0, 1.0).unwrap()).unwrap().reject(0.001));
+        assert!(pit_uniformity_test(&data, &Normal::new(1.0, 4.0).unwrap()).unwrap().reject(0.001));
+        assert!(pit_uniformity_test(&data, &Normal::new(1.5, 2.0).unwrap()).unwrap().reject(0.001));
+    }
+
+    #[test]
+    fn test_pit_uniformity_test_bad_input() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(pit_uniformity_test(&[], &n).is_err());
+        assert!(pit_uniformity_test(&[0.5, f64::NAN], &n).is_err());
+    }
+}
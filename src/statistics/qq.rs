@@ -0,0 +1,96 @@
+//! Provides quantile-quantile comparisons of samples against distributions
+
+use distribution::InverseCDF;
+use std::cmp::Ordering;
+
+/// Returns the points of a quantile-quantile plot of `samples` against the
+/// distribution `dist` as `(theoretical quantile, sample quantile)` pairs
+/// in ascending order
+///
+/// # Remarks
+///
+/// The `i`th smallest of `n` samples is paired with the quantile of `dist`
+/// at the plotting position `(i - 0.5) / n`. If the samples were drawn from
+/// `dist` the points lie close to the line `y = x`; a different location
+/// or scale shows up as a shifted or tilted line and a different shape as
+/// a curve. `NaN` samples compare equal to every value so their position
+/// in the output is unspecified.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::statistics::qq_points;
+///
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// let points = qq_points(&[0.9, -1.2, 0.1], &n);
+/// assert_eq!(points.len(), 3);
+/// assert_eq!(points[0].1, -1.2);
+/// assert_eq!(points[1], (0.0, 0.1));
+/// ```
+pub fn qq_points<D: InverseCDF<f64>>(samples: &[f64], dist: &D) -> Vec<(f64, f64)> {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let n = sorted.len() as f64;
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| (dist.inverse_cdf((i as f64 + 0.5) / n), x))
+        .collect()
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use rand::distributions::Distribution;
+    use statistics::*;
+    use distribution::{Exponential, InverseCDF, Normal};
+    use rng;
+
+    fn correlation(points: &[(f64, f64)]) -> f64 {
+        let xs: Vec<f64> = points.iter().map(|p| p.0).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p.1).collect();
+        (&xs).covariance(&ys) / ((&xs).std_dev() * (&ys).std_dev())
+    }
+
+    fn samples<D: Distribution<f64>>(dist: &D, count: usize) -> Vec<f64> {
+        let mut r = rng::seeded(0);
+        (0..count).map(|_| dist.sample(&mut r)).collect()
+    }
+
+    #[test]
+    fn test_qq_points() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        let points = qq_points(&[0.7, 0.1, 0.3, 0.9], &n);
+        let ys: Vec<f64> = points.iter().map(|p| p.1).collect();
+        assert_eq!(ys, vec![0.1, 0.3, 0.7, 0.9]);
+        for (i, &(x, _)) in points.iter().enumerate() {
+            assert_eq!(x, n.inverse_cdf((2.0 * i as f64 + 1.0) / 8.0));
+        }
+        assert_almost_eq!(points[0].0 + points[3].0, 2.0, 1e-9);
+        assert!(qq_points(&[], &n).is_empty());
+    }
+
+    #[test]
+    fn test_qq_points_matching_distribution() {
+        let n = Normal::new(2.0, 3.0).unwrap();
+        let points = qq_points(&samples(&n, 2000), &n);
+        assert!(correlation(&points) > 0.999);
+        // away from the extremes the sample quantiles track the theoretical ones
+        for &(x, y) in &points[100..1900] {
+            assert!((x - y).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_qq_points_mismatched_distribution() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        // shifted location, points lie below y = x
+        let shifted = qq_points(&samples(&n, 2000), &Normal::new(2.0, 1.0).unwrap());
+        let mean_diff = shifted.iter().map(|&(x, y)| y - x).sum::<f64>() / 2000.0;
+        assert_almost_eq!(mean_diff, -2.0, 0.1);
+        // skewed data against a symmetric reference bends away from the line
+        let skewed = qq_points(&samples(&Exponential::new(1.0).unwrap(), 2000), &n);
+        assert!(correlation(&skewed) < 0.95);
+    }
+}
@@ -0,0 +1,317 @@
+//! Provides sampling from streams and finite collections of items
+
+use rand::distributions::Open01;
+use rand::Rng;
+use std::cmp::Ordering;
+use {Result, StatsError};
+
+/// Maintains a uniform random sample of fixed size from a stream of items
+/// of unknown length
+///
+/// # Remarks
+///
+/// Implements Algorithm R: the first `k` items fill the reservoir and the
+/// `i`th item after that replaces a uniformly chosen item of the reservoir
+/// with probability `k / i`. After `n` items have been added every item is
+/// in the sample with probability `k / n`, or `1` if `n <= k`. The order of
+/// the items in the sample is not uniformly random.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate statrs;
+///
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::statistics::ReservoirSampler;
+///
+/// # fn main() {
+/// let mut r: StdRng = SeedableRng::from_seed([7; 32]);
+/// let mut sampler = ReservoirSampler::new(3);
+/// for x in 0..100 {
+///     sampler.add(x, &mut r);
+/// }
+/// assert_eq!(sampler.seen(), 100);
+/// let sample = sampler.into_sample();
+/// assert_eq!(sample.len(), 3);
+/// assert!(sample.iter().all(|&x| x < 100));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReservoirSampler<T> {
+    size: usize,
+    seen: usize,
+    reservoir: Vec<T>,
+}
+
+impl<T> ReservoirSampler<T> {
+    /// Constructs a new reservoir sampler that keeps a sample of at most
+    /// `size` items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::ReservoirSampler;
+    ///
+    /// let sampler: ReservoirSampler<f64> = ReservoirSampler::new(10);
+    /// assert_eq!(sampler.size(), 10);
+    /// assert_eq!(sampler.seen(), 0);
+    /// ```
+    pub fn new(size: usize) -> ReservoirSampler<T> {
+        ReservoirSampler {
+            size: size,
+            seen: 0,
+            reservoir: Vec::with_capacity(size),
+        }
+    }
+
+    /// Returns the maximum number of items kept in the sample
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of items added to the sampler so far
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// Returns the current sample, which holds `min(size, seen)` items
+    pub fn sample(&self) -> &[T] {
+        &self.reservoir
+    }
+
+    /// Adds `item` from the stream to the sampler, keeping it in the sample
+    /// with probability `size / seen`
+    pub fn add<R: Rng + ?Sized>(&mut self, item: T, r: &mut R) {
+        self.seen += 1;
+        if self.reservoir.len() < self.size {
+            self.reservoir.push(item);
+        } else {
+            let i = r.gen_range(0, self.seen);
+            if i < self.size {
+                self.reservoir[i] = item;
+            }
+        }
+    }
+
+    /// Consumes the sampler and returns the sample, which holds
+    /// `min(size, seen)` items
+    pub fn into_sample(self) -> Vec<T> {
+        self.reservoir
+    }
+}
+
+/// Draws `k` distinct items from `items` without replacement, where items
+/// are selected with probability proportional to their weight
+///
+/// # Remarks
+///
+/// Implements the A-Res algorithm of Efraimidis and Spirakis: each item
+/// draws the key `u^(1 / w)` for a uniform `u` and weight `w`, and the `k`
+/// items with the largest keys are selected. The sample is returned in
+/// decreasing order of key, which is the order in which successive weighted
+/// draws without replacement would select the items. Items with a weight of
+/// zero are only selected once all items with positive weight have been.
+///
+/// # Errors
+///
+/// If `items` and `weights` differ in length, if any weight is negative,
+/// `NaN` or infinite, or if `k > items.len()`
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate statrs;
+///
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::statistics::weighted_sample_without_replacement;
+///
+/// # fn main() {
+/// let mut r: StdRng = SeedableRng::from_seed([7; 32]);
+/// let items = ['a', 'b', 'c', 'd'];
+/// let sample = weighted_sample_without_replacement(&items, &[1.0, 0.0, 2.0, 1.0], 3, &mut r).unwrap();
+/// assert_eq!(sample.len(), 3);
+/// assert!(!sample.contains(&'b'));
+///
+/// assert!(weighted_sample_without_replacement(&items, &[1.0; 3], 2, &mut r).is_err());
+/// assert!(weighted_sample_without_replacement(&items, &[1.0; 4], 5, &mut r).is_err());
+/// # }
+/// ```
+pub fn weighted_sample_without_replacement<T: Clone, R: Rng + ?Sized>(
+    items: &[T],
+    weights: &[f64],
+    k: usize,
+    r: &mut R,
+) -> Result<Vec<T>> {
+    if items.len() != weights.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if weights.iter().any(|&w| !w.is_finite() || w < 0.0) {
+        return Err(StatsError::BadParams);
+    }
+    if k > items.len() {
+        return Err(StatsError::ArgLteArg("k", "items.len()"));
+    }
+
+    // ln(u) / w orders the items the same way as u^(1 / w) without
+    // underflowing for small weights
+    let mut keys: Vec<(f64, usize)> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| {
+            let u: f64 = r.sample(Open01);
+            (u.ln() / w, i)
+        })
+        .collect();
+    keys.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+    Ok(keys
+        .iter()
+        .take(k)
+        .map(|&(_, i)| items[i].clone())
+        .collect())
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use super::{weighted_sample_without_replacement, ReservoirSampler};
+    use rng;
+
+    #[test]
+    fn test_reservoir_short_stream() {
+        let mut r = rng::seeded(0);
+        let mut sampler = ReservoirSampler::new(5);
+        for x in 0..3 {
+            sampler.add(x, &mut r);
+        }
+        assert_eq!(sampler.seen(), 3);
+        assert_eq!(sampler.sample(), &[0, 1, 2]);
+        assert_eq!(sampler.into_sample(), vec![0, 1, 2]);
+
+        let mut sampler = ReservoirSampler::new(0);
+        sampler.add(1, &mut r);
+        assert_eq!(sampler.seen(), 1);
+        assert!(sampler.into_sample().is_empty());
+    }
+
+    #[test]
+    fn test_reservoir_distinct() {
+        let mut r = rng::seeded(0);
+        let mut sampler = ReservoirSampler::new(10);
+        for x in 0..1000 {
+            sampler.add(x, &mut r);
+        }
+        let mut sample = sampler.into_sample();
+        assert_eq!(sample.len(), 10);
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 10);
+    }
+
+    #[test]
+    fn test_reservoir_inclusion_probability() {
+        let mut r = rng::seeded(0);
+        let (k, n, trials) = (5, 20, 20000);
+        let mut counts = vec![0usize; n];
+        for _ in 0..trials {
+            let mut sampler = ReservoirSampler::new(k);
+            for x in 0..n {
+                sampler.add(x, &mut r);
+            }
+            for x in sampler.into_sample() {
+                counts[x] += 1;
+            }
+        }
+        // each item is included with probability k / n = 0.25, with a
+        // standard error of about 0.003 over the trials
+        for &c in &counts {
+            assert_almost_eq!(c as f64 / trials as f64, k as f64 / n as f64, 0.015);
+        }
+    }
+
+    #[test]
+    fn test_weighted_sample_bad_input() {
+        let mut r = rng::seeded(0);
+        let items = [1, 2, 3];
+        assert!(weighted_sample_without_replacement(&items, &[1.0, 1.0], 1, &mut r).is_err());
+        assert!(weighted_sample_without_replacement(&items, &[1.0, -1.0, 1.0], 1, &mut r).is_err());
+        assert!(weighted_sample_without_replacement(&items, &[1.0, f64::NAN, 1.0], 1, &mut r).is_err());
+        assert!(weighted_sample_without_replacement(&items, &[1.0, f64::INFINITY, 1.0], 1, &mut r).is_err());
+        assert!(weighted_sample_without_replacement(&items, &[1.0; 3], 4, &mut r).is_err());
+        assert!(weighted_sample_without_replacement(&items, &[1.0; 3], 0, &mut r).unwrap().is_empty());
+        let mut all = weighted_sample_without_replacement(&items, &[1.0; 3], 3, &mut r).unwrap();
+        all.sort();
+        assert_eq!(all, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_weighted_sample_zero_weights() {
+        let mut r = rng::seeded(0);
+        let items = [0, 1, 2, 3, 4];
+        for _ in 0..100 {
+            let mut sample = weighted_sample_without_replacement(&items, &[0.0, 2.0, 0.0, 1.0, 0.5], 3, &mut r).unwrap();
+            sample.sort();
+            assert_eq!(sample, vec![1, 3, 4]);
+            let sample = weighted_sample_without_replacement(&items, &[0.0, 2.0, 0.0, 0.0, 0.0], 2, &mut r).unwrap();
+            assert_eq!(sample[0], 1);
+        }
+    }
+
+    #[test]
+    fn test_weighted_sample_dominant_weight() {
+        let mut r = rng::seeded(0);
+        let items: Vec<usize> = (0..10).collect();
+        let mut weights = vec![1.0; 10];
+        weights[7] = 1e4;
+        let trials = 1000;
+        let mut hits = 0;
+        for _ in 0..trials {
+            let sample = weighted_sample_without_replacement(&items, &weights, 2, &mut r).unwrap();
+            if sample.contains(&7) {
+                hits += 1;
+            }
+        }
+        assert!(hits >= trials - 2);
+    }
+
+    #[test]
+    fn test_weighted_sample_uniform_weights() {
+        let mut r = rng::seeded(0);
+        let (k, n, trials) = (5, 20, 20000);
+        let items: Vec<usize> = (0..n).collect();
+        let weights = vec![2.5; n];
+        let mut counts = vec![0usize; n];
+        let mut first = vec![0usize; n];
+        for _ in 0..trials {
+            let sample = weighted_sample_without_replacement(&items, &weights, k, &mut r).unwrap();
+            first[sample[0]] += 1;
+            for x in sample {
+                counts[x] += 1;
+            }
+        }
+        for i in 0..n {
+            assert_almost_eq!(counts[i] as f64 / trials as f64, k as f64 / n as f64, 0.015);
+            assert_almost_eq!(first[i] as f64 / trials as f64, 1.0 / n as f64, 0.007);
+        }
+    }
+
+    #[test]
+    fn test_weighted_sample_proportional() {
+        // the first selected item is drawn with probability proportional
+        // to its weight
+        let mut r = rng::seeded(0);
+        let items = [0, 1, 2];
+        let weights = [1.0, 2.0, 5.0];
+        let trials = 20000;
+        let mut first = [0usize; 3];
+        for _ in 0..trials {
+            first[weighted_sample_without_replacement(&items, &weights, 2, &mut r).unwrap()[0]] += 1;
+        }
+        for i in 0..3 {
+            assert_almost_eq!(first[i] as f64 / trials as f64, weights[i] / 8.0, 0.015);
+        }
+    }
+}
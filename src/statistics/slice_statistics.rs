@@ -1,5 +1,7 @@
 use error::StatsError;
+use function::factorial;
 use statistics::*;
+use std::cmp::Ordering;
 use std::f64;
 
 impl OrderStatistics<f64> for [f64] {
@@ -58,6 +60,69 @@ impl OrderStatistics<f64> for [f64] {
         self.upper_quartile() - self.lower_quartile()
     }
 
+    fn five_number_summary(&mut self) -> FiveNumberSummary<f64> {
+        FiveNumberSummary {
+            min: self.order_statistic(1),
+            q1: self.lower_quartile(),
+            median: self.median(),
+            q3: self.upper_quartile(),
+            max: self.order_statistic(self.len()),
+        }
+    }
+
+    fn median_abs_deviation(&mut self) -> f64 {
+        let median = OrderStatistics::median(self);
+        let mut deviations: Vec<f64> = self.iter().map(|x| (x - median).abs()).collect();
+        OrderStatistics::median(&mut deviations[..])
+    }
+
+    fn trimmed_mean(&mut self, proportion: f64) -> f64 {
+        if !(0.0..0.5).contains(&proportion) {
+            return f64::NAN;
+        }
+        self.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let trim = (proportion * self.len() as f64).floor() as usize;
+        self[trim..self.len() - trim].iter().mean()
+    }
+
+    fn l_moments(&mut self, count: usize) -> Vec<f64> {
+        let n = self.len();
+        self.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        // unbiased probability weighted moments b_r, the average of the
+        // order statistics x_(i) weighted by C(i - 1, r) / C(n - 1, r)
+        let mut pwm = vec![f64::NAN; count];
+        for (r, b) in pwm.iter_mut().enumerate().take(n) {
+            let mut sum = 0.0;
+            for (i, x) in self.iter().enumerate().skip(r) {
+                let weight = (0..r).fold(1.0, |acc, j| acc * (i - j) as f64 / (n - 1 - j) as f64);
+                sum += weight * x;
+            }
+            *b = sum / n as f64;
+        }
+
+        // λ_(r + 1) = sum((-1)^(r - k) * C(r, k) * C(r + k, k) * b_k)
+        let mut lmom: Vec<f64> = (0..count)
+            .map(|r| {
+                (0..r + 1)
+                    .map(|k| {
+                        let sign = if (r - k) % 2 == 0 { 1.0 } else { -1.0 };
+                        sign * factorial::binomial(r as u64, k as u64)
+                            * factorial::binomial((r + k) as u64, k as u64)
+                            * pwm[k]
+                    })
+                    .sum()
+            })
+            .collect();
+        if count > 2 {
+            let scale = lmom[1];
+            for l in &mut lmom[2..] {
+                *l /= scale;
+            }
+        }
+        lmom
+    }
+
     fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<f64> {
         let n = self.len();
         let mut ranks: Vec<f64> = vec![0.0; n];
@@ -602,6 +667,7 @@ fn quick_sort_all(primary: &mut [f64], secondary: &mut [usize], left: usize, rig
 mod test {
     use std::f64;
     use statistics::*;
+    use rng;
 
     #[test]
     fn test_order_statistic_short() {
@@ -656,6 +722,117 @@ mod test {
         assert_eq!(ties.clone().ranks(RankTieBreaker::First), [1.0, 5.0, 8.0, 4.0, 2.0, 6.0, 7.0, 3.0]);
     }
 
+    #[test]
+    fn test_five_number_summary() {
+        let mut data = [7.0, 1.0, 5.0, 3.0, 9.0, 11.0, 2.0];
+        let summary = data.five_number_summary();
+        assert_eq!(summary.min, 1.0);
+        assert_almost_eq!(summary.q1, 13.0 / 6.0, 1e-15);
+        assert_eq!(summary.median, 5.0);
+        assert_almost_eq!(summary.q3, 26.0 / 3.0, 1e-14);
+        assert_eq!(summary.max, 11.0);
+        assert_almost_eq!(data.interquartile_range(), summary.q3 - summary.q1, 1e-15);
+    }
+
+    #[test]
+    fn test_five_number_summary_short() {
+        let mut empty: [f64; 0] = [];
+        let summary = empty.five_number_summary();
+        assert!(summary.min.is_nan());
+        assert!(summary.q1.is_nan());
+        assert!(summary.median.is_nan());
+        assert!(summary.q3.is_nan());
+        assert!(summary.max.is_nan());
+
+        let mut single = [4.0];
+        assert_eq!(single.five_number_summary(), FiveNumberSummary { min: 4.0, q1: 4.0, median: 4.0, q3: 4.0, max: 4.0 });
+
+        let mut pair = [3.0, 1.0];
+        assert_eq!(pair.five_number_summary(), FiveNumberSummary { min: 1.0, q1: 1.0, median: 2.0, q3: 3.0, max: 3.0 });
+    }
+
+    #[test]
+    fn test_median_abs_deviation() {
+        let mut data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(data.median_abs_deviation(), 0.5);
+        let mut single = [3.0];
+        assert_eq!(single.median_abs_deviation(), 0.0);
+        let mut empty: [f64; 0] = [];
+        assert!(empty.median_abs_deviation().is_nan());
+    }
+
+    #[test]
+    fn test_trimmed_mean() {
+        let mut data = [6.0, 1.0, 5.0, 2.0, 4.0, 3.0, 7.0, 8.0, 9.0, 10.0];
+        assert_almost_eq!(data.trimmed_mean(0.0), 5.5, 1e-15);
+        assert_almost_eq!(data.trimmed_mean(0.1), 5.5, 1e-15);
+        assert_almost_eq!(data.trimmed_mean(0.25), 5.5, 1e-15);
+        let mut skewed = [1.0, 2.0, 3.0, 4.0, 20.0];
+        assert_almost_eq!(skewed.trimmed_mean(0.2), 3.0, 1e-15);
+        assert_almost_eq!(skewed.trimmed_mean(0.1), 6.0, 1e-15);
+        assert!(data.trimmed_mean(0.5).is_nan());
+        assert!(data.trimmed_mean(-0.1).is_nan());
+        assert!(data.trimmed_mean(f64::NAN).is_nan());
+        let mut empty: [f64; 0] = [];
+        assert!(empty.trimmed_mean(0.1).is_nan());
+    }
+
+    #[test]
+    fn test_l_moments() {
+        let mut data = [3.1, 0.2, 7.7, 1.5, 2.2, 9.0, 4.4];
+        let mean = data.iter().mean();
+        let l = data.l_moments(4);
+        assert_eq!(l.len(), 4);
+        assert_almost_eq!(l[0], mean, 1e-14);
+        assert_almost_eq!(l[0], 4.014285714285714, 1e-14);
+        assert_almost_eq!(l[1], 1.9523809523809526, 1e-14);
+        assert_almost_eq!(l[2], 0.20195121951219547, 1e-13);
+        assert_almost_eq!(l[3], 0.029268292682930363, 1e-13);
+        assert!(data.l_moments(0).is_empty());
+        assert_eq!(data.l_moments(2), vec![l[0], l[1]]);
+    }
+
+    #[test]
+    fn test_l_moments_uniform() {
+        use rand::distributions::Distribution;
+        use distribution::Uniform;
+
+        // the L-moments of U(a, b) are (a + b) / 2, (b - a) / 6 and zero
+        // L-skewness and L-kurtosis
+        let mut r = rng::seeded(0);
+        let n = Uniform::new(2.0, 5.0).unwrap();
+        let mut data: Vec<f64> = (0..20000).map(|_| n.sample(&mut r)).collect();
+        let l = data.l_moments(4);
+        assert_almost_eq!(l[0], 3.5, 0.02);
+        assert_almost_eq!(l[1], 0.5, 0.01);
+        assert_almost_eq!(l[2], 0.0, 0.01);
+        assert_almost_eq!(l[3], 0.0, 0.01);
+    }
+
+    #[test]
+    fn test_l_moments_short_data() {
+        let mut data = [1.0, 4.0];
+        let l = data.l_moments(4);
+        assert_eq!(l[0], 2.5);
+        assert_eq!(l[1], 1.5);
+        assert!(l[2].is_nan());
+        assert!(l[3].is_nan());
+        let mut empty: [f64; 0] = [];
+        assert!(empty.l_moments(2).iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_robust_to_outlier() {
+        let clean = [9.8, 10.1, 9.9, 10.3, 10.0, 9.7, 10.2, 10.0, 9.9, 10.1];
+        let mut dirty = clean;
+        dirty[3] = 1000.0;
+        let clean_mean = clean.iter().mean();
+        let dirty_mean = dirty.iter().mean();
+        assert!(dirty_mean - clean_mean > 90.0);
+        assert!((dirty.clone().trimmed_mean(0.1) - clean.clone().trimmed_mean(0.1)).abs() < 0.05);
+        assert!((dirty.clone().median_abs_deviation() - clean.clone().median_abs_deviation()).abs() < 0.05);
+    }
+
     #[test]
     fn test_median_short() {
         let even = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
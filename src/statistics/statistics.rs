@@ -141,9 +141,11 @@ pub trait Statistics<T> {
     ///
     /// # Remarks
     ///
+    /// The geometric mean is only defined for positive data and is computed
+    /// as the exponential of the mean of the logarithms.
+    ///
     /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`.
-    /// Returns `f64::NAN` if an entry is less than `0`. Returns `0`
-    /// if no entry is less than `0` but there are entries equal to `0`.
+    /// Returns `f64::NAN` if any entry is less than or equal to `0`.
     ///
     /// # Examples
     ///
@@ -155,7 +157,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.geometric_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -165,7 +167,7 @@ pub trait Statistics<T> {
     /// assert!(z.geometric_mean().is_nan());
     ///
     /// z = [0.0, 3.0, 2.0];
-    /// assert_eq!(z.geometric_mean(), 0.0);
+    /// assert!(z.geometric_mean().is_nan());
     ///
     /// z = [1.0, 2.0, 3.0];
     /// // test value from online calculator, could be more accurate
@@ -178,11 +180,11 @@ pub trait Statistics<T> {
     ///
     /// # Remarks
     ///
+    /// The harmonic mean is computed as `N / sum(1 / x_i)` and is undefined
+    /// when an entry is `0`.
+    ///
     /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`, or if
-    /// any value
-    /// in data is less than `0`. Returns `0` if there are no values less than
-    /// `0` but
-    /// there exists values equal to `0`.
+    /// any value in data is less than or equal to `0`.
     ///
     /// # Examples
     ///
@@ -194,7 +196,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.harmonic_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -204,7 +206,7 @@ pub trait Statistics<T> {
     /// assert!(z.harmonic_mean().is_nan());
     ///
     /// z = [0.0, 3.0, 2.0];
-    /// assert_eq!(z.harmonic_mean(), 0.0);
+    /// assert!(z.harmonic_mean().is_nan());
     ///
     /// z = [1.0, 2.0, 3.0];
     /// // test value from online calculator, could be more accurate
@@ -424,4 +426,74 @@ pub trait Statistics<T> {
     /// # }
     /// ```
     fn quadratic_mean(self) -> T;
+
+    /// Estimates the autocorrelation of the data at lags `0` to `max_lag`
+    /// inclusive, e.g. for diagnosing serial correlation in time series or
+    /// MCMC output
+    ///
+    /// # Remarks
+    ///
+    /// Uses the standard (biased) estimator normalized by the lag `0`
+    /// autocovariance, so the lag `0` autocorrelation is exactly `1` and
+    /// lags not smaller than the length of the data are `0`.
+    /// Every entry is `f64::NAN` if data is empty, all entries are equal or
+    /// any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((x_t - μ) * (x_(t + k) - μ)) / sum((x_t - μ)^2)
+    /// ```
+    ///
+    /// where `μ` is the mean, `k` the lag and the numerator sums over the
+    /// `N - k` available pairs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x: [f64; 0] = [];
+    /// assert!(x.autocorrelation(1).iter().all(|r| r.is_nan()));
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(y.autocorrelation(2), [1.0, 0.25, -0.3]);
+    /// ```
+    fn autocorrelation(self, max_lag: usize) -> Vec<T>;
+
+    /// Estimates the effective sample size of correlated samples, e.g. the
+    /// draws of a Markov chain, i.e. the number of independent samples
+    /// carrying the same information about the mean
+    ///
+    /// # Remarks
+    ///
+    /// The integrated autocorrelation time `τ = 1 + 2 * sum(ρ_k)` is
+    /// estimated with Geyer's initial monotone sequence rule: the sums of
+    /// adjacent pairs of autocorrelations `ρ_(2m) + ρ_(2m + 1)` are
+    /// accumulated while they are positive, and each is capped by its
+    /// predecessor. To keep the estimate finite when the autocorrelation sum
+    /// approaches `-0.5`, `τ` is bounded below by `1 / log10(N)`, so the
+    /// effective sample size never exceeds `N * log10(N)`.
+    ///
+    /// Returns `f64::NAN` if data has less than two entries, all entries are
+    /// equal or any entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// N / τ
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x = [1.0];
+    /// assert!(x.effective_sample_size().is_nan());
+    ///
+    /// let y = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    /// assert!(y.effective_sample_size() < 8.0);
+    /// ```
+    fn effective_sample_size(self) -> T;
 }
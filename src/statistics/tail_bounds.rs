@@ -0,0 +1,68 @@
+//! Provides distribution-free bounds on tail probabilities
+
+use std::f64;
+
+/// Returns Hoeffding's bound on the probability that the mean of `n`
+/// independent random variables taking values in `[0, 1]` exceeds its
+/// expectation by at least `t`
+///
+/// # Remarks
+///
+/// The bound holds for any distribution of the variables, so it is
+/// conservative but requires neither sampling nor an exact cdf. For
+/// variables bounded in `[a, b]` pass `t / (b - a)`. The same bound applies
+/// to the lower tail by symmetry, and doubling it bounds the two-sided
+/// deviation. Returns `1` if `n == 0` or `t <= 0`, where the bound is not
+/// informative.
+///
+/// # Formula
+///
+/// ```ignore
+/// P(X̄ - E[X̄] >= t) <= e^(-2 * n * t^2)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::hoeffding_bound;
+///
+/// assert_eq!(hoeffding_bound(100, 0.0), 1.0);
+/// assert!((hoeffding_bound(100, 0.1) - (-2.0f64).exp()).abs() < 1e-15);
+/// ```
+pub fn hoeffding_bound(n: u64, t: f64) -> f64 {
+    if n == 0 || t <= 0.0 {
+        1.0
+    } else {
+        (-2.0 * n as f64 * t * t).exp()
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg(test)]
+mod test {
+    use std::f64;
+    use distribution::{Binomial, Univariate};
+
+    #[test]
+    fn test_hoeffding_bound() {
+        assert_almost_eq!(super::hoeffding_bound(100, 0.1), 0.1353352832366127, 1e-16);
+        assert_almost_eq!(super::hoeffding_bound(50, 0.2), 0.01831563888873418, 1e-17);
+        assert_eq!(super::hoeffding_bound(0, 0.5), 1.0);
+        assert_eq!(super::hoeffding_bound(10, 0.0), 1.0);
+        assert_eq!(super::hoeffding_bound(10, -0.5), 1.0);
+        assert_eq!(super::hoeffding_bound(10, f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_hoeffding_bound_exceeds_exact() {
+        // the mean of n Bernoulli(p) variables is Binomial(p, n) / n
+        for &(p, n) in &[(0.5f64, 10u64), (0.1, 50), (0.7, 200), (0.02, 1000)] {
+            let dist = Binomial::new(p, n).unwrap();
+            for k in 0..n + 1 {
+                let t = k as f64 / n as f64 - p;
+                let exact = if k == 0 { 1.0 } else { 1.0 - dist.cdf(k as f64 - 1.0) };
+                assert!(super::hoeffding_bound(n, t) >= exact - 1e-14);
+            }
+        }
+    }
+}
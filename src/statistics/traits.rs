@@ -1,3 +1,4 @@
+use std::f64;
 use Result;
 
 /// The `Min` trait specifies than an object has a minimum value
@@ -131,6 +132,63 @@ pub trait CheckedVariance<T>: CheckedMean<T> {
     fn checked_std_dev(&self) -> Result<T>;
 }
 
+/// The `Dispersion` trait provides measures of variability relative to the
+/// mean for any object with a closed form mean and variance
+///
+/// # Remarks
+///
+/// The provided methods evaluate `mean` and `variance`, so implementors
+/// whose moments may be undefined override them to return `NaN` from their
+/// checked moments instead of panicking
+pub trait Dispersion: Variance<f64> {
+    /// Returns the coefficient of variation, the ratio of the standard
+    /// deviation to the mean, or `NaN` if the mean is zero or not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Dispersion;
+    /// use statrs::distribution::{Normal, StudentsT, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 2.0).unwrap();
+    /// assert_eq!(n.coefficient_of_variation(), (1f64 / 3f64).sqrt());
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert!(n.coefficient_of_variation().is_nan());
+    /// let n = StudentsT::new(2.0, 1.0, 1.0).unwrap();
+    /// assert!(n.coefficient_of_variation().is_nan());
+    /// ```
+    fn coefficient_of_variation(&self) -> f64 {
+        let mean = self.mean();
+        if mean == 0.0 || !mean.is_finite() {
+            f64::NAN
+        } else {
+            self.std_dev() / mean
+        }
+    }
+
+    /// Returns the index of dispersion, the ratio of the variance to the
+    /// mean, or `NaN` if the mean is zero or not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Dispersion;
+    /// use statrs::distribution::Binomial;
+    ///
+    /// // underdispersed relative to a poisson distribution
+    /// let n = Binomial::new(0.25, 8).unwrap();
+    /// assert_eq!(n.index_of_dispersion(), 0.75);
+    /// ```
+    fn index_of_dispersion(&self) -> f64 {
+        let mean = self.mean();
+        if mean == 0.0 || !mean.is_finite() {
+            f64::NAN
+        } else {
+            self.variance() / mean
+        }
+    }
+}
+
 /// The `Entropy` trait specifies an object that has a closed form solution
 /// for its entropy
 pub trait Entropy<T> {
@@ -237,6 +295,31 @@ pub trait Mode<T> {
     fn mode(&self) -> T;
 }
 
+/// The `Modes` trait specifies that an object has a closed form solution
+/// for all of its modes, for distributions where the mode need not be
+/// unique
+///
+/// # Remarks
+///
+/// By convention, when a distribution implementing `Modes` has more than
+/// one mode its `Mode::mode` returns the smallest of them, i.e. the first
+/// element of `modes()`.
+pub trait Modes<T> {
+    /// Returns all modes in increasing order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Mode, Modes};
+    /// use statrs::distribution::Binomial;
+    ///
+    /// let n = Binomial::new(0.5, 3).unwrap();
+    /// assert_eq!(n.modes(), vec![1, 2]);
+    /// assert_eq!(n.mode(), 1);
+    /// ```
+    fn modes(&self) -> Vec<T>;
+}
+
 /// The `CheckedMode` trait specifies that an object has a closed form solution
 /// for its mode(s) with a possible failure mode
 pub trait CheckedMode<T> {